@@ -1,5 +1,9 @@
+// pyo3 0.22's `#[pyfunction]` expansion trips this lint on `PyResult` returns
+#![allow(clippy::useless_conversion)]
+
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyList};
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyBytes;
 use encoding_rs::Encoding;
 
 /// Encoding detection result
@@ -12,8 +16,16 @@ pub struct CharsetMatch {
     confidence: f64,
     #[pyo3(get)]
     language: String,
+    /// Share of suspicious characters (0.0 is clean text)
+    #[pyo3(get)]
+    chaos: f64,
+    /// Share of letters in the dominant script (1.0 is a single script)
+    #[pyo3(get)]
+    coherence: f64,
     decoded: String,
     raw: Vec<u8>,
+    /// Other encodings that decoded the bytes to the same text
+    alternatives: Vec<String>,
 }
 
 #[pymethods]
@@ -33,6 +45,15 @@ impl CharsetMatch {
     fn raw_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
         PyBytes::new_bound(py, &self.raw)
     }
+    
+    /// This match's encoding followed by every other encoding that
+    /// produced identical text
+    #[getter]
+    fn could_be_from_charset(&self) -> Vec<String> {
+        std::iter::once(self.encoding.clone())
+            .chain(self.alternatives.iter().cloned())
+            .collect()
+    }
 }
 
 /// Encoding detection results collection
@@ -113,20 +134,94 @@ fn get_encodings() -> Vec<(&'static Encoding, &'static str)> {
         (encoding_rs::EUC_KR, "euc-kr"),
         (encoding_rs::KOI8_R, "koi8-r"),
         (encoding_rs::KOI8_U, "koi8-u"),
+        (encoding_rs::WINDOWS_874, "windows-874"),
+        (encoding_rs::IBM866, "ibm866"),
+        (encoding_rs::MACINTOSH, "macintosh"),
+        (encoding_rs::X_MAC_CYRILLIC, "x-mac-cyrillic"),
         (encoding_rs::UTF_16LE, "utf-16-le"),
         (encoding_rs::UTF_16BE, "utf-16-be"),
     ]
 }
 
-fn calculate_confidence(bytes: &[u8], decoded: &str, had_errors: bool) -> f64 {
+/// Look up an encoding by WHATWG label or common Python codec name
+/// (e.g. "cp1252", "mac_roman", "cp874"). DOS codepages such as cp437
+/// aren't supported by encoding_rs and resolve to None.
+fn resolve_encoding(name: &str) -> Option<&'static Encoding> {
+    let label = name.trim().to_lowercase().replace('_', "-");
+    match label.as_str() {
+        "mac-roman" | "macroman" => Some(encoding_rs::MACINTOSH),
+        "mac-cyrillic" => Some(encoding_rs::X_MAC_CYRILLIC),
+        "cp874" => Some(encoding_rs::WINDOWS_874),
+        "utf-16-le" => Some(encoding_rs::UTF_16LE),
+        "utf-16-be" => Some(encoding_rs::UTF_16BE),
+        _ => Encoding::for_label(label.as_bytes()),
+    }
+}
+
+/// Name reported for an encoding: its label in `get_encodings`, falling
+/// back to the lowercased WHATWG name
+fn encoding_label(encoding: &'static Encoding) -> String {
+    get_encodings()
+        .into_iter()
+        .find(|(candidate, _)| *candidate == encoding)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or_else(|| encoding.name().to_lowercase())
+}
+
+/// Encodings to try, in order: `cp_isolation` (when given) replaces the
+/// default table and may name encodings outside it; `cp_exclusion` then
+/// removes entries. A name that doesn't resolve raises ValueError rather
+/// than silently leaving nothing to try.
+fn candidate_encodings(
+    cp_isolation: Option<&[String]>,
+    cp_exclusion: Option<&[String]>,
+) -> PyResult<Vec<&'static Encoding>> {
+    let mut candidates: Vec<&'static Encoding> = match cp_isolation {
+        Some(names) => {
+            let mut isolated = Vec::new();
+            for encoding in resolve_encodings(names)? {
+                if !isolated.contains(&encoding) {
+                    isolated.push(encoding);
+                }
+            }
+            isolated
+        }
+        None => get_encodings().into_iter().map(|(encoding, _)| encoding).collect(),
+    };
+    
+    if let Some(names) = cp_exclusion {
+        let excluded = resolve_encodings(names)?;
+        candidates.retain(|encoding| !excluded.contains(encoding));
+    }
+    
+    Ok(candidates)
+}
+
+/// `resolve_encoding` for each name, failing on the first unknown one
+fn resolve_encodings(names: &[String]) -> PyResult<Vec<&'static Encoding>> {
+    names
+        .iter()
+        .map(|name| {
+            resolve_encoding(name)
+                .ok_or_else(|| PyValueError::new_err(format!("unknown or unsupported encoding: {:?}", name)))
+        })
+        .collect()
+}
+
+/// How likely `decoded` is the right reading of `bytes`, scaled down by its
+/// `chaos` so that mojibake ranks below a clean decode of the same bytes
+fn calculate_confidence(bytes: &[u8], decoded: &str, had_errors: bool, chaos: f64) -> f64 {
     if had_errors {
         return 0.0;
     }
     
     let mut score = 1.0;
     
-    let ratio = decoded.len() as f64 / bytes.len().max(1) as f64;
-    if ratio < 0.5 || ratio > 2.0 {
+    // Measured in characters: UTF-8 byte counts would penalize scripts such
+    // as Thai whose letters take three bytes each
+    let char_count = decoded.chars().count();
+    let ratio = char_count as f64 / bytes.len().max(1) as f64;
+    if !(0.5..=2.0).contains(&ratio) {
         score *= 0.8;
     }
     
@@ -135,6 +230,15 @@ fn calculate_confidence(bytes: &[u8], decoded: &str, had_errors: bool) -> f64 {
         score *= 0.5_f64.powi(replacement_count.min(10) as i32);
     }
     
+    // NUL and other non-whitespace C0/C1 controls almost never occur in
+    // text; they come from binary data or UTF-16 read as a single-byte
+    // encoding, so even a modest share of them sinks the score
+    let control_count = decoded.chars().filter(|&c| c.is_control() && !c.is_whitespace()).count();
+    if control_count > 0 {
+        let control_ratio = control_count as f64 / char_count as f64;
+        score *= (1.0 - control_ratio).powi(8);
+    }
+    
     let printable = decoded.chars().filter(|c| c.is_ascii_graphic() || c.is_ascii_whitespace()).count();
     let printable_ratio = printable as f64 / decoded.len().max(1) as f64;
     score *= 0.5 + 0.5 * printable_ratio;
@@ -146,7 +250,91 @@ fn calculate_confidence(bytes: &[u8], decoded: &str, had_errors: bool) -> f64 {
         score *= 1.05;
     }
     
-    score.min(1.0)
+    (score * (1.0 - chaos)).min(1.0)
+}
+
+/// Coarse script of a letter, for spotting script mixing
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Hebrew,
+    Arabic,
+    /// Kana and ideographs, which Japanese mixes freely
+    Cjk,
+    Hangul,
+    Other,
+}
+
+fn script_of(c: char) -> Script {
+    match c as u32 {
+        0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F | 0x1E00..=0x1EFF => Script::Latin,
+        0x370..=0x3FF | 0x1F00..=0x1FFF => Script::Greek,
+        0x400..=0x52F => Script::Cyrillic,
+        0x590..=0x5FF => Script::Hebrew,
+        0x600..=0x6FF => Script::Arabic,
+        0x3040..=0x30FF | 0x4E00..=0x9FFF => Script::Cjk,
+        0xAC00..=0xD7AF | 0x1100..=0x11FF => Script::Hangul,
+        _ => Script::Other,
+    }
+}
+
+/// Mess ratio of decoded text: replacement characters, non-whitespace
+/// controls and improbable sequences per character, capped at 1.0
+///
+/// Improbable sequences are adjacent letters from different scripts,
+/// letters followed by a Latin-1 symbol or a box-drawing character, and a
+/// capital straight after a lowercase letter: the usual shapes of mojibake
+/// such as "Ã©", "â€™", "ф╖" or "РвЩв".
+fn chaos_ratio(decoded: &str) -> f64 {
+    let total = decoded.chars().count();
+    if total == 0 {
+        return 0.0;
+    }
+    
+    let mut suspicious = decoded
+        .chars()
+        .filter(|&c| c == '\u{FFFD}' || (c.is_control() && !c.is_whitespace()))
+        .count();
+    let chars: Vec<char> = decoded.chars().collect();
+    for pair in chars.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if !a.is_alphabetic() {
+            continue;
+        }
+        let script_switch = b.is_alphabetic() && script_of(a) != script_of(b);
+        // Latin-1 symbols and the cp1252 extras UTF-8 continuation bytes
+        // turn into; ordinary punctuation such as closing quotes is fine
+        let symbol_after_letter = matches!(b, '\u{A0}'..='\u{BF}' | '€' | '†' | '‡' | '‰' | '™' | '•');
+        // DOS and KOI8 codepages put box drawing where others have letters
+        let box_after_letter = matches!(b, '\u{2500}'..='\u{259F}');
+        let case_flip = a.is_lowercase() && b.is_uppercase();
+        if script_switch || symbol_after_letter || box_after_letter || case_flip {
+            suspicious += 1;
+        }
+    }
+    
+    (suspicious as f64 / total as f64).min(1.0)
+}
+
+/// Fraction of letters written in the text's dominant script; 0.0 when the
+/// text has no letters
+fn coherence_ratio(decoded: &str) -> f64 {
+    let mut counts: Vec<(Script, usize)> = Vec::new();
+    for script in decoded.chars().filter(|c| c.is_alphabetic()).map(script_of) {
+        match counts.iter_mut().find(|(s, _)| *s == script) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+    let letters: usize = counts.iter().map(|(_, count)| count).sum();
+    let dominant = counts.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    if letters == 0 {
+        0.0
+    } else {
+        dominant as f64 / letters as f64
+    }
 }
 
 fn detect_bom(bytes: &[u8]) -> Option<(&'static str, &'static Encoding)> {
@@ -165,54 +353,93 @@ fn is_valid_utf8(bytes: &[u8]) -> bool {
     std::str::from_utf8(bytes).is_ok()
 }
 
+/// Order matches by confidence, fold matches whose decoded text is identical
+/// into the first of them, and keep at most `max_matches`
+///
+/// The sort is stable, so among equal decodes the encoding that comes first
+/// in the candidate order (the more common one) is kept and the rest are
+/// listed in its `could_be_from_charset`.
+fn rank_matches(mut matches: Vec<CharsetMatch>, max_matches: Option<usize>) -> Vec<CharsetMatch> {
+    matches.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+    
+    let mut ranked: Vec<CharsetMatch> = Vec::with_capacity(matches.len());
+    for candidate in matches {
+        match ranked.iter_mut().find(|kept| kept.decoded == candidate.decoded) {
+            Some(kept) => kept.alternatives.push(candidate.encoding),
+            None => ranked.push(candidate),
+        }
+    }
+    if let Some(max) = max_matches {
+        ranked.truncate(max);
+    }
+    ranked
+}
+
+/// Detect the encoding of `byte_str`, best match first
+///
+/// `cp_isolation` restricts detection to (and can add) the named encodings;
+/// `cp_exclusion` removes encodings from the candidates; naming an encoding
+/// that isn't available raises ValueError. Encodings that decode to
+/// identical text are returned as one match, and at most `max_matches`
+/// matches are returned (None for all of them).
 #[pyfunction]
-#[pyo3(signature = (byte_str, _steps=5, _chunk_size=512, threshold=0.2, _cp_isolation=None, _cp_exclusion=None, _preemptive_behaviour=true, _explain=false, _language_threshold=0.1, _enable_fallback=true))]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (byte_str, _steps=5, _chunk_size=512, threshold=0.2, cp_isolation=None, cp_exclusion=None, _preemptive_behaviour=true, _explain=false, _language_threshold=0.1, _enable_fallback=true, max_matches=Some(5)))]
 fn from_bytes(
     _py: Python<'_>,
     byte_str: &Bound<'_, PyBytes>,
     _steps: usize,
     _chunk_size: usize,
     threshold: f64,
-    _cp_isolation: Option<&Bound<'_, PyList>>,
-    _cp_exclusion: Option<&Bound<'_, PyList>>,
+    cp_isolation: Option<Vec<String>>,
+    cp_exclusion: Option<Vec<String>>,
     _preemptive_behaviour: bool,
     _explain: bool,
     _language_threshold: f64,
     _enable_fallback: bool,
+    max_matches: Option<usize>,
 ) -> PyResult<CharsetMatches> {
     let bytes = byte_str.as_bytes();
     let mut matches = Vec::new();
+    let candidates = candidate_encodings(cp_isolation.as_deref(), cp_exclusion.as_deref())?;
     
     if bytes.is_empty() {
         return Ok(CharsetMatches { matches });
     }
     
     // Check BOM
-    if let Some((name, encoding)) = detect_bom(bytes) {
+    if let Some((name, encoding)) = detect_bom(bytes).filter(|(_, encoding)| candidates.contains(encoding)) {
         let (decoded, _, had_errors) = encoding.decode(bytes);
         if !had_errors {
             matches.push(CharsetMatch {
                 encoding: name.to_string(),
                 confidence: 1.0,
                 language: String::new(),
+                chaos: chaos_ratio(&decoded),
+                coherence: coherence_ratio(&decoded),
                 decoded: decoded.to_string(),
                 raw: bytes.to_vec(),
+                alternatives: Vec::new(),
             });
             return Ok(CharsetMatches { matches });
         }
     }
     
     // Fast path: UTF-8
-    if is_valid_utf8(bytes) {
+    if candidates.contains(&encoding_rs::UTF_8) && is_valid_utf8(bytes) {
         let decoded = unsafe { std::str::from_utf8_unchecked(bytes) };
-        let confidence = calculate_confidence(bytes, decoded, false);
+        let chaos = chaos_ratio(decoded);
+        let confidence = calculate_confidence(bytes, decoded, false, chaos);
         if confidence >= threshold {
             matches.push(CharsetMatch {
                 encoding: "utf-8".to_string(),
                 confidence,
                 language: String::new(),
+                chaos,
+                coherence: coherence_ratio(decoded),
                 decoded: decoded.to_string(),
                 raw: bytes.to_vec(),
+                alternatives: Vec::new(),
             });
             if confidence > 0.9 {
                 return Ok(CharsetMatches { matches });
@@ -221,50 +448,54 @@ fn from_bytes(
     }
     
     // Try other encodings
-    for (encoding, name) in get_encodings() {
-        if name == "utf-8" {
+    for encoding in candidates {
+        if encoding == encoding_rs::UTF_8 {
             continue;
         }
         
         let (decoded, _, had_errors) = encoding.decode(bytes);
-        let confidence = calculate_confidence(bytes, &decoded, had_errors);
+        let chaos = chaos_ratio(&decoded);
+        let confidence = calculate_confidence(bytes, &decoded, had_errors, chaos);
         
         if confidence >= threshold {
             matches.push(CharsetMatch {
-                encoding: name.to_string(),
+                encoding: encoding_label(encoding),
                 confidence,
                 language: String::new(),
+                chaos,
+                coherence: coherence_ratio(&decoded),
                 decoded: decoded.to_string(),
                 raw: bytes.to_vec(),
+                alternatives: Vec::new(),
             });
         }
     }
     
-    matches.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
-    
-    Ok(CharsetMatches { matches })
+    Ok(CharsetMatches { matches: rank_matches(matches, max_matches) })
 }
 
 #[pyfunction]
-#[pyo3(signature = (path, _steps=5, _chunk_size=512, threshold=0.2, _cp_isolation=None, _cp_exclusion=None, _preemptive_behaviour=true, _explain=false, _language_threshold=0.1, _enable_fallback=true))]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (path, _steps=5, _chunk_size=512, threshold=0.2, cp_isolation=None, cp_exclusion=None, _preemptive_behaviour=true, _explain=false, _language_threshold=0.1, _enable_fallback=true, max_matches=Some(5)))]
 fn from_path(
     py: Python<'_>,
     path: &str,
     _steps: usize,
     _chunk_size: usize,
     threshold: f64,
-    _cp_isolation: Option<&Bound<'_, PyList>>,
-    _cp_exclusion: Option<&Bound<'_, PyList>>,
+    cp_isolation: Option<Vec<String>>,
+    cp_exclusion: Option<Vec<String>>,
     _preemptive_behaviour: bool,
     _explain: bool,
     _language_threshold: f64,
     _enable_fallback: bool,
+    max_matches: Option<usize>,
 ) -> PyResult<CharsetMatches> {
     let bytes = std::fs::read(path)
         .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
     
     let py_bytes = PyBytes::new_bound(py, &bytes);
-    from_bytes(py, &py_bytes, 5, 512, threshold, None, None, true, false, 0.1, true)
+    from_bytes(py, &py_bytes, 5, 512, threshold, cp_isolation, cp_exclusion, true, false, 0.1, true, max_matches)
 }
 
 #[pyfunction]
@@ -0,0 +1,161 @@
+"""
+Tests for charset-normalizer-rs
+
+Verifies API compatibility with charset_normalizer
+"""
+
+import pytest
+
+try:
+    from charset_normalizer_rs import from_bytes
+except ImportError:
+    pytest.skip("charset_normalizer_rs not available", allow_module_level=True)
+
+
+class TestControlCharacters:
+    """NUL and control characters are penalized in confidence"""
+
+    def test_utf16le_without_bom(self):
+        data = "Привет мир, как дела?\n".encode("utf-16-le")
+        best = from_bytes(data).best()
+        assert best.encoding == "utf-16-le"
+        assert str(best) == "Привет мир, как дела?\n"
+
+    def test_single_byte_decodings_penalized(self):
+        data = "Hello world, this is a test.\n".encode("utf-16-le")
+        encodings = [m.encoding for m in from_bytes(data)]
+        assert encodings[0] == "utf-16-le"
+        assert "windows-1252" not in encodings
+
+    def test_whitespace_controls_not_penalized(self):
+        best = from_bytes(b"col\tvalue\r\nnext\tline\r\n").best()
+        assert best.encoding == "utf-8"
+        assert best.confidence == 1.0
+
+
+class TestMatchQuality:
+    """chaos and coherence ratios on each match"""
+
+    def test_clean_text(self):
+        best = from_bytes("Café déjà vu – “quotes” don’t".encode()).best()
+        assert best.encoding == "utf-8"
+        assert best.chaos == 0.0
+        assert best.coherence == 1.0
+
+    def test_mojibake_is_chaotic(self):
+        # The mojibake decode falls below the default threshold
+        data = "Café déjà vu – “quotes”".encode()
+        matches = {m.encoding: m for m in from_bytes(data, threshold=0.0, max_matches=None)}
+        assert matches["utf-8"].chaos < matches["windows-1251"].chaos
+        assert matches["utf-8"].coherence > matches["windows-1251"].coherence
+
+    def test_mixed_scripts_lower_coherence(self):
+        best = from_bytes("Hello мир".encode()).best()
+        assert best.coherence == pytest.approx(5 / 8)
+
+    def test_no_letters(self):
+        best = from_bytes(b"12345 !!!").best()
+        assert best.chaos == 0.0
+        assert best.coherence == 0.0
+
+    def test_ratios_in_range(self):
+        for match in from_bytes("Привет мир".encode("utf-16-le")):
+            assert 0.0 <= match.chaos <= 1.0
+            assert 0.0 <= match.coherence <= 1.0
+
+
+class TestDeduplication:
+    """Identical decodes collapse into one match and results are capped"""
+
+    DATA = "Prix: 25 § 3 °C".encode("latin-1")
+    ISO = ["iso-8859-2", "iso-8859-3", "iso-8859-4", "iso-8859-7"]
+
+    def test_identical_iso_decodes_collapse(self):
+        results = from_bytes(self.DATA, cp_isolation=self.ISO)
+        assert len(results) == 1
+        best = results.best()
+        assert best.encoding == "iso-8859-2"
+        assert best.could_be_from_charset == self.ISO
+        assert str(best) == "Prix: 25 § 3 °C"
+
+    def test_outputs_are_distinct(self):
+        outputs = [str(m) for m in from_bytes(self.DATA, max_matches=None)]
+        assert len(outputs) == len(set(outputs))
+
+    def test_cap(self):
+        assert len(from_bytes(self.DATA)) == 5
+        assert len(from_bytes(self.DATA, max_matches=2)) == 2
+        assert len(from_bytes(self.DATA, max_matches=None)) > 5
+
+    def test_cap_keeps_best(self):
+        everything = [m.encoding for m in from_bytes(self.DATA, max_matches=None)]
+        assert [m.encoding for m in from_bytes(self.DATA, max_matches=3)] == everything[:3]
+
+
+class TestLegacyCodepages:
+    """Mac Roman, Thai and DOS codepages are detected"""
+
+    def test_mac_roman(self):
+        data = "Café crème, déjà vu à côté".encode("mac_roman")
+        best = from_bytes(data).best()
+        assert best.encoding == "macintosh"
+        assert str(best) == "Café crème, déjà vu à côté"
+
+    def test_windows_874(self):
+        text = "ภาษาไทย เป็นภาษาราชการ ของประเทศไทย"
+        best = from_bytes(text.encode("cp874")).best()
+        assert best.encoding == "windows-874"
+        assert str(best) == text
+
+    def test_ibm866(self):
+        text = "Привет мир, как дела? Всё хорошо."
+        best = from_bytes(text.encode("cp866")).best()
+        assert best.encoding == "ibm866"
+        assert str(best) == text
+
+
+class TestCandidateSelection:
+    """cp_isolation and cp_exclusion narrow or extend the candidates"""
+
+    DATA = "Café crème, déjà vu à côté".encode("mac_roman")
+
+    def test_isolation_restricts(self):
+        encodings = [m.encoding for m in from_bytes(self.DATA, cp_isolation=["cp1252", "koi8-r"], max_matches=None)]
+        assert set(encodings) <= {"windows-1252", "koi8-r"}
+        assert "macintosh" not in encodings
+
+    def test_isolation_accepts_python_codec_names(self):
+        results = from_bytes(self.DATA, cp_isolation=["mac_roman"])
+        assert [m.encoding for m in results] == ["macintosh"]
+
+    def test_isolation_can_add_encodings(self):
+        results = from_bytes("Prix: 25 €".encode("iso-8859-15"), cp_isolation=["iso8859_15"])
+        assert [m.encoding for m in results] == ["iso-8859-15"]
+        assert str(results.best()) == "Prix: 25 €"
+
+    def test_exclusion(self):
+        results = from_bytes(self.DATA, cp_exclusion=["macintosh"], max_matches=None)
+        assert "macintosh" not in [m.encoding for m in results]
+        assert results.best().encoding != "macintosh"
+
+    def test_exclusion_applies_to_utf8(self):
+        results = from_bytes("héllo".encode(), cp_exclusion=["utf_8"], max_matches=None)
+        assert "utf-8" not in [m.encoding for m in results]
+
+    def test_exclusion_applies_after_isolation(self):
+        results = from_bytes(self.DATA, cp_isolation=["mac_roman", "cp1252"], cp_exclusion=["cp1252"])
+        assert [m.encoding for m in results] == ["macintosh"]
+
+    def test_unknown_isolation_name_raises(self):
+        with pytest.raises(ValueError):
+            from_bytes(self.DATA, cp_isolation=["cp437"])
+        with pytest.raises(ValueError):
+            from_bytes(self.DATA, cp_isolation=["macintosh", "no-such-codec"])
+
+    def test_unknown_exclusion_name_raises(self):
+        with pytest.raises(ValueError):
+            from_bytes(self.DATA, cp_exclusion=["cp437"])
+
+    def test_unknown_name_raises_for_empty_input(self):
+        with pytest.raises(ValueError):
+            from_bytes(b"", cp_isolation=["cp437"])
@@ -1,4 +1,10 @@
+// pyo3 0.22's `#[pyfunction]` expansion trips this lint on `PyResult` returns
+#![allow(clippy::useless_conversion)]
+
 use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use std::sync::Mutex;
+use unicode_width::UnicodeWidthChar;
 
 // ANSI escape code constants
 const CSI: &str = "\x1b[";
@@ -161,37 +167,89 @@ impl Style {
 #[derive(Clone)]
 pub struct Cursor;
 
+// Method names mirror colorama's upper-case `Cursor` API
+#[allow(non_snake_case)]
 #[pymethods]
 impl Cursor {
     /// Move cursor up n lines
     #[staticmethod]
+    #[pyo3(signature = (n=None))]
     fn UP(n: Option<u32>) -> String {
         format!("{}{}A", CSI, n.unwrap_or(1))
     }
     
     /// Move cursor down n lines
     #[staticmethod]
+    #[pyo3(signature = (n=None))]
     fn DOWN(n: Option<u32>) -> String {
         format!("{}{}B", CSI, n.unwrap_or(1))
     }
     
     /// Move cursor forward n columns
     #[staticmethod]
+    #[pyo3(signature = (n=None))]
     fn FORWARD(n: Option<u32>) -> String {
         format!("{}{}C", CSI, n.unwrap_or(1))
     }
     
     /// Move cursor back n columns
     #[staticmethod]
+    #[pyo3(signature = (n=None))]
     fn BACK(n: Option<u32>) -> String {
         format!("{}{}D", CSI, n.unwrap_or(1))
     }
     
     /// Move cursor to position (x, y)
     #[staticmethod]
+    #[pyo3(signature = (x=None, y=None))]
     fn POS(x: Option<u32>, y: Option<u32>) -> String {
         format!("{}{};{}H", CSI, y.unwrap_or(1), x.unwrap_or(1))
     }
+
+    /// Move cursor to column n of the current line
+    #[staticmethod]
+    #[pyo3(signature = (n=None))]
+    fn COLUMN(n: Option<u32>) -> String {
+        format!("{}{}G", CSI, n.unwrap_or(1))
+    }
+
+    /// Save cursor position (DECSC)
+    #[staticmethod]
+    fn SAVE() -> String {
+        "\x1b7".to_string()
+    }
+
+    /// Restore cursor position saved by SAVE (DECRC)
+    #[staticmethod]
+    fn RESTORE() -> String {
+        "\x1b8".to_string()
+    }
+
+    /// Hide the cursor
+    #[staticmethod]
+    fn HIDE() -> String {
+        format!("{}?25l", CSI)
+    }
+
+    /// Show the cursor
+    #[staticmethod]
+    fn SHOW() -> String {
+        format!("{}?25h", CSI)
+    }
+
+    /// Scroll the page up n lines
+    #[staticmethod]
+    #[pyo3(signature = (n=None))]
+    fn SCROLL_UP(n: Option<u32>) -> String {
+        format!("{}{}S", CSI, n.unwrap_or(1))
+    }
+
+    /// Scroll the page down n lines
+    #[staticmethod]
+    #[pyo3(signature = (n=None))]
+    fn SCROLL_DOWN(n: Option<u32>) -> String {
+        format!("{}{}T", CSI, n.unwrap_or(1))
+    }
 }
 
 /// ANSI code generation functions
@@ -206,11 +264,13 @@ fn set_title(title: &str) -> String {
 }
 
 #[pyfunction]
+#[pyo3(signature = (mode=None))]
 fn clear_screen(mode: Option<u32>) -> String {
     format!("{}{}J", CSI, mode.unwrap_or(2))
 }
 
 #[pyfunction]
+#[pyo3(signature = (mode=None))]
 fn clear_line(mode: Option<u32>) -> String {
     format!("{}{}K", CSI, mode.unwrap_or(2))
 }
@@ -239,14 +299,96 @@ fn back_rgb(r: u8, g: u8, b: u8) -> String {
     format!("{}48;2;{};{};{}m", CSI, r, g, b)
 }
 
+/// Linearly interpolate between two RGB colors, `t` in [0, 1]
+fn lerp_rgb(start: (u8, u8, u8), end: (u8, u8, u8), t: f64) -> (u8, u8, u8) {
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    (mix(start.0, end.0), mix(start.1, end.1), mix(start.2, end.2))
+}
+
+/// Convert a hue in degrees (full saturation and value) to RGB
+fn hue_to_rgb(hue: f64) -> (u8, u8, u8) {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = 1.0 - (h % 2.0 - 1.0).abs();
+    let (r, g, b) = match h as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    let scale = |v: f64| (v * 255.0).round() as u8;
+    (scale(r), scale(g), scale(b))
+}
+
+/// Color each character of `text` with the truecolor produced by `color_at`
+fn color_chars<F>(text: &str, skip_whitespace: bool, color_at: F) -> String
+where
+    F: Fn(usize, usize) -> (u8, u8, u8),
+{
+    let count = text.chars().count();
+    if count == 0 {
+        return String::new();
+    }
+
+    // Each colored char needs at most 19 bytes of escape sequence
+    let mut result = String::with_capacity(text.len() + count * 19 + STYLE_RESET_ALL.len());
+    for (i, c) in text.chars().enumerate() {
+        if !(skip_whitespace && c.is_whitespace()) {
+            let (r, g, b) = color_at(i, count);
+            result.push_str(&fore_rgb(r, g, b));
+        }
+        result.push(c);
+    }
+    result.push_str(STYLE_RESET_ALL);
+
+    result
+}
+
+/// Color text with a truecolor gradient between two RGB endpoints
+#[pyfunction]
+#[pyo3(signature = (text, start_rgb, end_rgb, skip_whitespace=false))]
+fn gradient(
+    text: &str,
+    start_rgb: (u8, u8, u8),
+    end_rgb: (u8, u8, u8),
+    skip_whitespace: bool,
+) -> String {
+    color_chars(text, skip_whitespace, |i, count| {
+        let t = if count > 1 { i as f64 / (count - 1) as f64 } else { 0.0 };
+        lerp_rgb(start_rgb, end_rgb, t)
+    })
+}
+
+/// Color text by cycling through the hue wheel once across its length
+#[pyfunction]
+#[pyo3(signature = (text, skip_whitespace=false))]
+fn rainbow(text: &str, skip_whitespace: bool) -> String {
+    color_chars(text, skip_whitespace, |i, count| {
+        hue_to_rgb(360.0 * i as f64 / count as f64)
+    })
+}
+
+/// A `style` argument: one code or a list of codes applied in order
+#[derive(FromPyObject)]
+enum StyleArg {
+    One(String),
+    Many(Vec<String>),
+}
+
 /// Colorize a string with foreground, background, and style
+///
+/// With `reset=False` the trailing `Style.RESET_ALL` is omitted, so the
+/// result can be used as a prefix for further text.
 #[pyfunction]
-#[pyo3(signature = (text, fore=None, back=None, style=None))]
-fn colorize(text: &str, fore: Option<&str>, back: Option<&str>, style: Option<&str>) -> String {
+#[pyo3(signature = (text, fore=None, back=None, style=None, reset=true))]
+fn colorize(text: &str, fore: Option<&str>, back: Option<&str>, style: Option<StyleArg>, reset: bool) -> String {
     let mut result = String::with_capacity(text.len() + 32);
     
-    if let Some(s) = style {
-        result.push_str(s);
+    match style {
+        Some(StyleArg::One(s)) => result.push_str(&s),
+        Some(StyleArg::Many(styles)) => styles.iter().for_each(|s| result.push_str(s)),
+        None => {}
     }
     if let Some(f) = fore {
         result.push_str(f);
@@ -256,67 +398,919 @@ fn colorize(text: &str, fore: Option<&str>, back: Option<&str>, style: Option<&s
     }
     
     result.push_str(text);
-    result.push_str(STYLE_RESET_ALL);
+    if reset {
+        result.push_str(STYLE_RESET_ALL);
+    }
     
     result
 }
 
+/// A piece of text that is either an escape sequence or a visible character
+enum Segment<'a> {
+    Escape(&'a str),
+    Char(char),
+}
+
+/// Split text into escape sequences and visible characters
+///
+/// CSI sequences run until their final byte and OSC sequences until BEL
+/// or the ST backslash; other two-character escapes such as DECSC `ESC 7`
+/// take the following character with them. A lone ESC is dropped on its own.
+fn ansi_segments(text: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::with_capacity(text.len());
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c != '\x1b' {
+            segments.push(Segment::Char(c));
+            continue;
+        }
+
+        let mut end = start + 1;
+        match chars.peek() {
+            Some(&(i, '[')) => {
+                chars.next();
+                end = i + 1;
+                // Skip parameter bytes (including private-mode `?`) until
+                // the final byte, e.g. the `l` in `?25l`
+                for (i, c) in chars.by_ref() {
+                    end = i + c.len_utf8();
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            Some(&(i, ']')) => {
+                chars.next();
+                end = i + 1;
+                // Skip until BEL or ST
+                for (i, c) in chars.by_ref() {
+                    end = i + c.len_utf8();
+                    if c == '\x07' || c == '\\' {
+                        break;
+                    }
+                }
+            }
+            Some(&(i, c)) if ('0'..='~').contains(&c) => {
+                chars.next();
+                end = i + 1;
+            }
+            _ => {}
+        }
+        segments.push(Segment::Escape(&text[start..end]));
+    }
+
+    segments
+}
+
 /// Strip ANSI escape codes from a string
 #[pyfunction]
 fn strip_ansi(text: &str) -> String {
-    let mut result = String::with_capacity(text.len());
-    let mut chars = text.chars().peekable();
-    
-    while let Some(c) = chars.next() {
-        if c == '\x1b' {
-            // Skip escape sequence
-            if let Some(&next) = chars.peek() {
-                if next == '[' {
-                    chars.next(); // consume '['
-                    // Skip until we hit a letter (end of sequence)
-                    while let Some(&c) = chars.peek() {
-                        chars.next();
-                        if c.is_ascii_alphabetic() {
-                            break;
-                        }
+    ansi_segments(text)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            Segment::Char(c) => Some(c),
+            Segment::Escape(_) => None,
+        })
+        .collect()
+}
+
+/// Track which SGR codes are in effect after an escape sequence
+fn update_sgr_state(active: &mut Vec<String>, escape: &str) {
+    let Some(params) = escape
+        .strip_prefix(CSI)
+        .and_then(|rest| rest.strip_suffix('m'))
+    else {
+        return;
+    };
+    if params.is_empty() || params.split(';').all(|p| p.parse() == Ok(0)) {
+        active.clear();
+    } else {
+        active.push(escape.to_string());
+    }
+}
+
+/// Accumulates wrapped lines, closing and reopening SGR state at breaks
+struct LineWriter {
+    lines: Vec<String>,
+    current: String,
+    current_width: usize,
+    active: Vec<String>,
+}
+
+impl LineWriter {
+    fn push_escape(&mut self, escape: &str) {
+        self.current.push_str(escape);
+        update_sgr_state(&mut self.active, escape);
+    }
+
+    fn push_char(&mut self, c: char) {
+        self.current.push(c);
+        self.current_width += c.width().unwrap_or(0);
+    }
+
+    fn break_line(&mut self) {
+        if !self.active.is_empty() {
+            self.current.push_str(STYLE_RESET_ALL);
+        }
+        let next = self.active.concat();
+        self.lines.push(std::mem::replace(&mut self.current, next));
+        self.current_width = 0;
+    }
+}
+
+/// Wrap a (possibly colored) paragraph to `width` display columns
+///
+/// Whitespace is collapsed as in `textwrap.wrap`, and words longer than
+/// `width` are split. Wide characters such as CJK count as two columns.
+/// Colors active at a line break are reset at the end of the line and
+/// re-emitted at the start of the next one, so each line can be printed
+/// on its own.
+#[pyfunction]
+fn wrap(text: &str, width: usize) -> PyResult<Vec<String>> {
+    if width == 0 {
+        return Err(PyValueError::new_err("invalid width 0 (must be > 0)"));
+    }
+
+    // Group segments into words; escapes between words stick to the next one
+    let mut words: Vec<Vec<Segment>> = Vec::new();
+    let mut word: Vec<Segment> = Vec::new();
+    let mut word_has_chars = false;
+    for segment in ansi_segments(text) {
+        match segment {
+            Segment::Char(c) if c.is_whitespace() => {
+                if word_has_chars {
+                    words.push(std::mem::take(&mut word));
+                    word_has_chars = false;
+                }
+            }
+            Segment::Char(_) => {
+                word_has_chars = true;
+                word.push(segment);
+            }
+            Segment::Escape(_) => word.push(segment),
+        }
+    }
+    if word_has_chars {
+        words.push(word);
+        word = Vec::new();
+    }
+
+    let mut writer = LineWriter {
+        lines: Vec::new(),
+        current: String::new(),
+        current_width: 0,
+        active: Vec::new(),
+    };
+    for word in &words {
+        let word_width: usize = word
+            .iter()
+            .map(|segment| match segment {
+                Segment::Char(c) => c.width().unwrap_or(0),
+                Segment::Escape(_) => 0,
+            })
+            .sum();
+        if writer.current_width > 0 {
+            if writer.current_width + 1 + word_width > width {
+                writer.break_line();
+            } else {
+                writer.push_char(' ');
+            }
+        }
+        for segment in word {
+            match *segment {
+                Segment::Escape(escape) => writer.push_escape(escape),
+                Segment::Char(c) => {
+                    // A character wider than `width` still gets a line of its own
+                    if writer.current_width > 0 && writer.current_width + c.width().unwrap_or(0) > width {
+                        writer.break_line();
                     }
-                } else if next == ']' {
-                    chars.next(); // consume ']'
-                    // Skip until BEL or ST
-                    while let Some(c) = chars.next() {
-                        if c == '\x07' || c == '\\' {
-                            break;
+                    writer.push_char(c);
+                }
+            }
+        }
+    }
+
+    // Escapes after the last word (e.g. a trailing reset) stay on the last line
+    for segment in word {
+        if let Segment::Escape(escape) = segment {
+            writer.push_escape(escape);
+        }
+    }
+    if writer.current_width > 0 {
+        writer.break_line();
+    }
+
+    Ok(writer.lines)
+}
+
+// xterm's RGB values for the 16 colors a legacy console can show
+const BASIC_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// RGB value of an entry in the 256-color palette
+fn palette_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => BASIC_PALETTE[index as usize],
+        16..=231 => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = index - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        _ => {
+            let grey = 8 + (index - 232) * 10;
+            (grey, grey, grey)
+        }
+    }
+}
+
+/// Index (0-15) of the basic color closest to `rgb`
+fn nearest_basic(rgb: (u8, u8, u8)) -> u8 {
+    let distance = |&(r, g, b): &(u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, rgb.0) + d(g, rgb.1) + d(b, rgb.2)
+    };
+    (0..16u8)
+        .min_by_key(|&i| distance(&BASIC_PALETTE[i as usize]))
+        .unwrap_or(0)
+}
+
+/// Rewrite SGR parameters as the subset a legacy console understands
+///
+/// 256-color and truecolor selections become the nearest basic color;
+/// attributes with no console equivalent (italic, underline, ...) are dropped.
+fn downgrade_sgr(params: &str) -> Vec<u32> {
+    let mut codes = Vec::new();
+    let mut values = params.split(';').map(|p| p.parse::<u32>().unwrap_or(0));
+    while let Some(code) = values.next() {
+        match code {
+            0..=2 | 22 | 30..=37 | 39 | 40..=47 | 49 | 90..=97 | 100..=107 => codes.push(code),
+            38 | 48 => {
+                let rgb = match values.next() {
+                    Some(5) => values.next().map(|i| palette_rgb(i.min(255) as u8)),
+                    Some(2) => {
+                        let mut channel = || values.next().map(|v| v.min(255) as u8);
+                        match (channel(), channel(), channel()) {
+                            (Some(r), Some(g), Some(b)) => Some((r, g, b)),
+                            _ => None,
                         }
                     }
+                    _ => None,
+                };
+                if let Some(rgb) = rgb {
+                    let basic = nearest_basic(rgb) as u32;
+                    let base = if code == 38 { 30 } else { 40 };
+                    codes.push(if basic < 8 { base + basic } else { base + 60 + basic - 8 });
+                }
+            }
+            _ => {}
+        }
+    }
+    codes
+}
+
+/// Convert one escape sequence for a legacy console, or drop it (None)
+fn convert_escape(escape: &str) -> Option<String> {
+    let body = escape.strip_prefix(CSI)?;
+    let final_byte = body.chars().last()?;
+    let params = &body[..body.len() - final_byte.len_utf8()];
+    if !params.chars().all(|c| c.is_ascii_digit() || c == ';') {
+        return None;
+    }
+    let param = |i: usize, default: u32| {
+        params
+            .split(';')
+            .nth(i)
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(default)
+    };
+
+    match final_byte {
+        'm' => {
+            let codes = downgrade_sgr(params);
+            if codes.is_empty() && !params.is_empty() {
+                return None;
+            }
+            let codes: Vec<String> = codes.iter().map(u32::to_string).collect();
+            Some(format!("{}{}m", CSI, codes.join(";")))
+        }
+        'A' | 'B' | 'C' | 'D' => Some(format!("{}{}{}", CSI, param(0, 1), final_byte)),
+        'H' | 'f' => Some(format!("{}{};{}H", CSI, param(0, 1), param(1, 1))),
+        'J' | 'K' => Some(format!("{}{}{}", CSI, param(0, 0), final_byte)),
+        _ => None,
+    }
+}
+
+/// Whether the attached terminal is known to interpret ANSI sequences
+fn ansi_supported() -> bool {
+    if !cfg!(windows) {
+        return true;
+    }
+    // Windows Terminal, ANSICON, ConEmu and MSYS-style terminals
+    std::env::var_os("WT_SESSION").is_some()
+        || std::env::var_os("ANSICON").is_some()
+        || std::env::var("ConEmuANSI").is_ok_and(|v| v == "ON")
+        || std::env::var_os("TERM").is_some()
+}
+
+/// Prepare text for a console without ANSI support, without wrapping a stream
+///
+/// With `convert=None` the text is returned unchanged when the terminal
+/// handles ANSI itself (anything but a legacy Windows console). Otherwise
+/// SGR sequences are reduced to the 16 colors and bright/dim/normal a
+/// legacy console can show, cursor movement and erase sequences are
+/// rewritten with explicit parameters (`ESC[H` becomes `ESC[1;1H`), and
+/// everything else - titles, private modes, scrolling - is removed.
+#[pyfunction]
+#[pyo3(signature = (text, convert=None))]
+fn ansi_to_win32(text: &str, convert: Option<bool>) -> String {
+    if !convert.unwrap_or_else(|| !ansi_supported()) {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    for segment in ansi_segments(text) {
+        match segment {
+            Segment::Char(c) => result.push(c),
+            Segment::Escape(escape) => {
+                if let Some(converted) = convert_escape(escape) {
+                    result.push_str(&converted);
                 }
             }
-        } else {
-            result.push(c);
         }
     }
-    
     result
 }
 
-/// Initialize colorama (no-op on Unix, placeholder for Windows)
+/// Win32 console API calls for consoles without VT processing
+#[cfg(windows)]
+mod win32 {
+    use std::ffi::c_void;
+
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const STD_ERROR_HANDLE: u32 = -12i32 as u32;
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+    const FOREGROUND_INTENSITY: u16 = 0x0008;
+    const BACKGROUND_INTENSITY: u16 = 0x0080;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Default)]
+    struct Coord {
+        x: i16,
+        y: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmallRect {
+        left: i16,
+        top: i16,
+        right: i16,
+        bottom: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct ScreenBufferInfo {
+        size: Coord,
+        cursor: Coord,
+        attributes: u16,
+        window: SmallRect,
+        max_window_size: Coord,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetStdHandle(std_handle: u32) -> *mut c_void;
+        fn GetConsoleMode(console: *mut c_void, mode: *mut u32) -> i32;
+        fn SetConsoleMode(console: *mut c_void, mode: u32) -> i32;
+        fn GetConsoleScreenBufferInfo(console: *mut c_void, info: *mut ScreenBufferInfo) -> i32;
+        fn SetConsoleTextAttribute(console: *mut c_void, attributes: u16) -> i32;
+        fn SetConsoleCursorPosition(console: *mut c_void, position: Coord) -> i32;
+        fn FillConsoleOutputCharacterW(console: *mut c_void, c: u16, len: u32, at: Coord, written: *mut u32) -> i32;
+        fn FillConsoleOutputAttribute(console: *mut c_void, attributes: u16, len: u32, at: Coord, written: *mut u32) -> i32;
+    }
+
+    /// ANSI color numbers order the channels red, green, blue from the low
+    /// bit; console attributes order them blue, green, red
+    fn console_color(ansi: u32) -> u16 {
+        let ansi = (ansi & 7) as u16;
+        (ansi & 1) << 2 | (ansi & 2) | (ansi & 4) >> 2
+    }
+
+    /// The screen buffer behind stdout or stderr
+    pub struct Console {
+        // Stored as an integer so the wrapper stays `Send`
+        handle: isize,
+        default_attributes: u16,
+    }
+
+    impl Console {
+        /// The console behind file descriptor 1 or 2, if it is one
+        pub fn for_fd(fd: i32) -> Option<Self> {
+            let std_handle = match fd {
+                1 => STD_OUTPUT_HANDLE,
+                2 => STD_ERROR_HANDLE,
+                _ => return None,
+            };
+            let handle = unsafe { GetStdHandle(std_handle) } as isize;
+            let mut console = Console { handle, default_attributes: 0 };
+            console.default_attributes = console.info()?.attributes;
+            Some(console)
+        }
+
+        fn info(&self) -> Option<ScreenBufferInfo> {
+            let mut info = ScreenBufferInfo::default();
+            let ok = unsafe { GetConsoleScreenBufferInfo(self.handle as *mut c_void, &mut info) };
+            (ok != 0).then_some(info)
+        }
+
+        /// Turn on VT processing; false on consoles that predate it
+        pub fn enable_vt(&self) -> bool {
+            let handle = self.handle as *mut c_void;
+            let mut mode = 0;
+            if unsafe { GetConsoleMode(handle, &mut mode) } == 0 {
+                return false;
+            }
+            mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0
+                || unsafe { SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) } != 0
+        }
+
+        /// Carry out an escape sequence as normalized by `convert_escape`
+        pub fn apply(&self, escape: &str) {
+            let Some(info) = self.info() else {
+                return;
+            };
+            let Some(body) = escape.strip_prefix(super::CSI) else {
+                return;
+            };
+            let Some(final_byte) = body.chars().last() else {
+                return;
+            };
+            let params: Vec<u32> = body[..body.len() - 1]
+                .split(';')
+                .map(|p| p.parse().unwrap_or(0))
+                .collect();
+            let handle = self.handle as *mut c_void;
+            let (cursor, size) = (info.cursor, info.size);
+            let clamp = |v: i32, len: i16| v.clamp(0, (len - 1).max(0) as i32) as i16;
+            let move_to = |x: i32, y: i32| {
+                let position = Coord { x: clamp(x, size.x), y: clamp(y, size.y) };
+                unsafe { SetConsoleCursorPosition(handle, position) };
+            };
+
+            match final_byte {
+                'm' => {
+                    let attributes = params.iter().fold(info.attributes, |attributes, &code| match code {
+                        0 => self.default_attributes,
+                        1 => attributes | FOREGROUND_INTENSITY,
+                        2 | 22 => attributes & !FOREGROUND_INTENSITY,
+                        30..=37 => attributes & !0x0f | console_color(code),
+                        90..=97 => attributes & !0x0f | console_color(code) | FOREGROUND_INTENSITY,
+                        39 => attributes & !0x0f | self.default_attributes & 0x0f,
+                        40..=47 => attributes & !0xf0 | console_color(code) << 4,
+                        100..=107 => attributes & !0xf0 | console_color(code) << 4 | BACKGROUND_INTENSITY,
+                        49 => attributes & !0xf0 | self.default_attributes & 0xf0,
+                        _ => attributes,
+                    });
+                    unsafe { SetConsoleTextAttribute(handle, attributes) };
+                }
+                'A' => move_to(cursor.x as i32, cursor.y as i32 - params[0] as i32),
+                'B' => move_to(cursor.x as i32, cursor.y as i32 + params[0] as i32),
+                'C' => move_to(cursor.x as i32 + params[0] as i32, cursor.y as i32),
+                'D' => move_to(cursor.x as i32 - params[0] as i32, cursor.y as i32),
+                'H' => {
+                    let row = info.window.top as i32 + params[0].max(1) as i32 - 1;
+                    let column = info.window.left as i32 + params.get(1).copied().unwrap_or(1).max(1) as i32 - 1;
+                    move_to(column, row);
+                }
+                'J' | 'K' => {
+                    let width = size.x as u32;
+                    let offset = cursor.y as u32 * width + cursor.x as u32;
+                    let (line_start, line_end) = (cursor.y as u32 * width, (cursor.y as u32 + 1) * width);
+                    let (start, end) = match (final_byte, params[0]) {
+                        ('J', 0) => (offset, width * size.y as u32),
+                        ('J', 1) => (0, offset + 1),
+                        ('J', _) => (0, width * size.y as u32),
+                        (_, 0) => (offset, line_end),
+                        (_, 1) => (line_start, offset + 1),
+                        _ => (line_start, line_end),
+                    };
+                    let at = Coord { x: (start % width.max(1)) as i16, y: (start / width.max(1)) as i16 };
+                    let mut written = 0;
+                    unsafe {
+                        FillConsoleOutputCharacterW(handle, b' ' as u16, end - start, at, &mut written);
+                        FillConsoleOutputAttribute(handle, info.attributes, end - start, at, &mut written);
+                    }
+                    if final_byte == 'J' && params[0] == 2 {
+                        move_to(0, 0);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Stand-in for platforms without Win32 consoles: there is never one to drive
+#[cfg(not(windows))]
+mod win32 {
+    pub enum Console {}
+
+    impl Console {
+        pub fn for_fd(_fd: i32) -> Option<Self> {
+            None
+        }
+
+        pub fn enable_vt(&self) -> bool {
+            match *self {}
+        }
+
+        pub fn apply(&self, _escape: &str) {
+            match *self {}
+        }
+    }
+}
+
+/// Stream wrapper that carries out ANSI sequences on a legacy Windows console
+///
+/// On a Windows console, VT processing is switched on where the console
+/// supports it and text then passes through untouched. On older consoles
+/// the wrapper converts instead: text is written up to each escape
+/// sequence, which is then carried out with the Win32 console API (colors,
+/// cursor movement, erasing) or dropped. With `strip`, escape sequences are
+/// removed rather than carried out. `convert` defaults to whether the
+/// console needs converting and `strip` to that or the stream not being a
+/// terminal, so a terminal elsewhere - including Unix - gets text passed
+/// through while a file or pipe gets it stripped; `convert=True` without a
+/// console to drive strips instead.
+#[pyclass(frozen)]
+struct AnsiToWin32 {
+    stream: PyObject,
+    console: Option<win32::Console>,
+    strip: bool,
+}
+
+impl AnsiToWin32 {
+    /// Whether the wrapper changes anything, i.e. is worth installing
+    fn should_wrap(&self) -> bool {
+        self.console.is_some() || self.strip
+    }
+}
+
+/// File descriptor of a Python stream, if it has one
+fn stream_fd(stream: &Bound<'_, PyAny>) -> Option<i32> {
+    stream.call_method0("fileno").ok()?.extract().ok()
+}
+
+/// Whether a Python stream is a terminal; streams without `isatty` aren't
+fn is_a_tty(stream: &Bound<'_, PyAny>) -> bool {
+    stream
+        .call_method0("isatty")
+        .and_then(|tty| tty.is_truthy())
+        .unwrap_or(false)
+}
+
+#[pymethods]
+impl AnsiToWin32 {
+    #[new]
+    #[pyo3(signature = (stream, convert=None, strip=None))]
+    fn new(py: Python<'_>, stream: PyObject, convert: Option<bool>, strip: Option<bool>) -> Self {
+        let console = stream_fd(stream.bind(py)).and_then(win32::Console::for_fd);
+        let legacy = console.as_ref().is_some_and(|console| !console.enable_vt());
+        let convert = convert.unwrap_or(legacy);
+        let strip = strip.unwrap_or_else(|| legacy || !is_a_tty(stream.bind(py))) || (convert && console.is_none());
+        Self {
+            stream,
+            console: console.filter(|_| convert),
+            strip,
+        }
+    }
+
+    /// The wrapped stream
+    #[getter]
+    fn stream(&self, py: Python<'_>) -> PyObject {
+        self.stream.clone_ref(py)
+    }
+
+    /// Whether escape sequences are carried out with the console API
+    #[getter]
+    fn convert(&self) -> bool {
+        self.console.is_some()
+    }
+
+    /// Whether escape sequences are removed from the text
+    #[getter]
+    fn strip(&self) -> bool {
+        self.strip
+    }
+
+    fn write(&self, py: Python<'_>, text: &str) -> PyResult<usize> {
+        let Some(console) = &self.console else {
+            let output = if self.strip { strip_ansi(text) } else { text.to_string() };
+            self.stream.call_method1(py, "write", (output,))?;
+            return Ok(text.chars().count());
+        };
+
+        let mut plain = String::with_capacity(text.len());
+        for segment in ansi_segments(text) {
+            match segment {
+                Segment::Char(c) => plain.push(c),
+                Segment::Escape(escape) => {
+                    // Text before the sequence has to reach the console first
+                    if !plain.is_empty() {
+                        self.stream.call_method1(py, "write", (std::mem::take(&mut plain),))?;
+                        self.stream.call_method0(py, "flush")?;
+                    }
+                    if let Some(converted) = convert_escape(escape) {
+                        console.apply(&converted);
+                    }
+                }
+            }
+        }
+        if !plain.is_empty() {
+            self.stream.call_method1(py, "write", (plain,))?;
+        }
+        Ok(text.chars().count())
+    }
+
+    fn flush(&self, py: Python<'_>) -> PyResult<()> {
+        self.stream.call_method0(py, "flush")?;
+        Ok(())
+    }
+
+    /// Restore the console's original colors when converting
+    fn reset(&self, py: Python<'_>) -> PyResult<()> {
+        if self.console.is_some() {
+            self.write(py, STYLE_RESET_ALL)?;
+        }
+        Ok(())
+    }
+
+    /// Delegate everything else (`isatty`, `encoding`, ...) to the stream
+    fn __getattr__(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        self.stream.getattr(py, name)
+    }
+}
+
+/// Stream wrapper that resets colors at the end of each line
+///
+/// Text is held until a newline (or `flush()`), so a line assembled from
+/// several writes - as `print` does with its arguments - keeps its colors.
+/// Writes without escape sequences while no colors are open go straight
+/// through.
+/// At each newline a reset is inserted only if an SGR state is still open,
+/// so lines that already end uncolored pass through unchanged. Leaving a
+/// `with` block, even through an exception, writes any pending text and
+/// resets.
+#[pyclass(frozen)]
+struct AutoResetStream {
+    stream: PyObject,
+    // Only locked while text is prepared, never across a call into Python
+    state: Mutex<LineState>,
+}
+
+/// Text held back until the end of its line, and the SGR codes it leaves open
+#[derive(Default)]
+struct LineState {
+    pending: String,
+    active: Vec<String>,
+}
+
+impl LineState {
+    /// Track SGR state across `text`, which is about to be written
+    fn track(&mut self, text: &str) {
+        for segment in ansi_segments(text) {
+            if let Segment::Escape(escape) = segment {
+                update_sgr_state(&mut self.active, escape);
+            }
+        }
+    }
+
+    /// Take the pending complete lines, each reset before its newline if
+    /// it left colors open
+    fn take_lines(&mut self) -> String {
+        let Some(end) = self.pending.rfind('\n') else {
+            return String::new();
+        };
+        let rest = self.pending.split_off(end + 1);
+        let complete = std::mem::replace(&mut self.pending, rest);
+
+        let mut output = String::with_capacity(complete.len());
+        for line in complete.split_inclusive('\n') {
+            let body = line.trim_end_matches(['\r', '\n']);
+            self.track(body);
+            output.push_str(body);
+            if !self.active.is_empty() {
+                output.push_str(STYLE_RESET_ALL);
+                self.active.clear();
+            }
+            output.push_str(&line[body.len()..]);
+        }
+        output
+    }
+
+    /// Take the pending partial line; its colors stay open
+    fn take_partial(&mut self) -> String {
+        let partial = std::mem::take(&mut self.pending);
+        self.track(&partial);
+        partial
+    }
+}
+
+impl AutoResetStream {
+    fn state(&self) -> std::sync::MutexGuard<'_, LineState> {
+        self.state.lock().unwrap()
+    }
+
+    fn emit(&self, py: Python<'_>, text: &str) -> PyResult<()> {
+        if !text.is_empty() {
+            self.stream.call_method1(py, "write", (text,))?;
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl AutoResetStream {
+    #[new]
+    fn new(stream: PyObject) -> Self {
+        Self {
+            stream,
+            state: Mutex::new(LineState::default()),
+        }
+    }
+
+    /// The wrapped stream
+    #[getter]
+    fn stream(&self, py: Python<'_>) -> PyObject {
+        self.stream.clone_ref(py)
+    }
+
+    fn write(&self, py: Python<'_>, text: &str) -> PyResult<usize> {
+        let output = {
+            let mut state = self.state();
+            // Uncolored text with no colors open can't need a reset
+            if state.pending.is_empty() && state.active.is_empty() && !text.contains('\x1b') {
+                text.to_string()
+            } else {
+                state.pending.push_str(text);
+                state.take_lines()
+            }
+        };
+        self.emit(py, &output)?;
+        Ok(text.chars().count())
+    }
+
+    /// Write out a partial line (colors stay open) and flush the stream
+    fn flush(&self, py: Python<'_>) -> PyResult<()> {
+        let partial = self.state().take_partial();
+        self.emit(py, &partial)?;
+        self.stream.call_method0(py, "flush")?;
+        Ok(())
+    }
+
+    /// Write any pending text and reset colors left open
+    fn reset(&self, py: Python<'_>) -> PyResult<()> {
+        let output = {
+            let mut state = self.state();
+            let mut output = state.take_partial();
+            if !state.active.is_empty() {
+                output.push_str(STYLE_RESET_ALL);
+                state.active.clear();
+            }
+            output
+        };
+        self.emit(py, &output)
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        py: Python<'_>,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        self.reset(py)?;
+        Ok(false)
+    }
+
+    /// Delegate everything else (`isatty`, `encoding`, ...) to the stream
+    fn __getattr__(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        self.stream.getattr(py, name)
+    }
+}
+
+/// `sys.stdout`/`sys.stderr` as found by `init` and the wrappers it installed
+struct InstalledStreams {
+    original: [PyObject; 2],
+    wrapped: [PyObject; 2],
+    active: bool,
+}
+
+static INSTALLED: Mutex<Option<InstalledStreams>> = Mutex::new(None);
+
+const STD_STREAMS: [&str; 2] = ["stdout", "stderr"];
+
+/// Initialize colorama
+///
+/// With `wrap`, `sys.stdout` and `sys.stderr` are replaced until `deinit()`
+/// by an `AnsiToWin32` where it has something to do - on Windows, a console
+/// without VT processing, or a stream to strip: `strip=True`, or with
+/// `strip=None` one that isn't a terminal - and by an `AutoResetStream`
+/// (around that) with `autoreset`. ANSI codes work directly on a Unix
+/// terminal, so there only `autoreset` does anything.
 #[pyfunction]
 #[pyo3(signature = (autoreset=false, convert=None, strip=None, wrap=true))]
-fn init(autoreset: bool, convert: Option<bool>, strip: Option<bool>, wrap: bool) {
-    // On Unix systems, colorama.init() is essentially a no-op
-    // The actual ANSI codes work directly
-    let _ = (autoreset, convert, strip, wrap);
+fn init(py: Python<'_>, autoreset: bool, convert: Option<bool>, strip: Option<bool>, wrap: bool) -> PyResult<()> {
+    deinit(py)?;
+    if !wrap {
+        return Ok(());
+    }
+
+    let sys = py.import_bound("sys")?;
+    let [stdout, stderr] = STD_STREAMS.map(|name| sys.getattr(name).map(Bound::unbind));
+    let original = [stdout?, stderr?];
+    let mut wrapped = [original[0].clone_ref(py), original[1].clone_ref(py)];
+    for stream in &mut wrapped {
+        let converter = AnsiToWin32::new(py, stream.clone_ref(py), convert, strip);
+        if converter.should_wrap() {
+            *stream = Py::new(py, converter)?.into_any();
+        }
+        if autoreset {
+            *stream = Py::new(py, AutoResetStream::new(stream.clone_ref(py)))?.into_any();
+        }
+    }
+    if wrapped.iter().zip(&original).all(|(wrapper, stream)| wrapper.is(stream)) {
+        return Ok(());
+    }
+    for (name, wrapper) in STD_STREAMS.iter().zip(&wrapped) {
+        sys.setattr(*name, wrapper)?;
+    }
+    // Whatever a previous `init` left is dropped once the lock is released
+    let previous = INSTALLED.lock().unwrap().replace(InstalledStreams { original, wrapped, active: true });
+    drop(previous);
+    Ok(())
 }
 
-/// Deinitialize colorama
+/// Restore the streams replaced by `init`, resetting any open colors
 #[pyfunction]
-fn deinit() {
-    // No-op on Unix
+fn deinit(py: Python<'_>) -> PyResult<()> {
+    // Python code runs below, so only hold the lock to flip `active`
+    let streams = {
+        let mut installed = INSTALLED.lock().unwrap();
+        let Some(streams) = installed.as_mut().filter(|s| s.active) else {
+            return Ok(());
+        };
+        streams.active = false;
+        [0, 1].map(|i| (streams.original[i].clone_ref(py), streams.wrapped[i].clone_ref(py)))
+    };
+    let sys = py.import_bound("sys")?;
+    for (name, (original, wrapper)) in STD_STREAMS.iter().zip(&streams) {
+        if !wrapper.is(original) {
+            wrapper.call_method0(py, "reset")?;
+        }
+        sys.setattr(*name, original)?;
+    }
+    Ok(())
 }
 
-/// Reinitialize colorama
+/// Reinstall the wrappers from the last `init` after a `deinit`
 #[pyfunction]
-fn reinit() {
-    // No-op on Unix
+fn reinit(py: Python<'_>) -> PyResult<()> {
+    let wrapped = {
+        let mut installed = INSTALLED.lock().unwrap();
+        let Some(streams) = installed.as_mut().filter(|s| !s.active) else {
+            return Ok(());
+        };
+        streams.active = true;
+        [0, 1].map(|i| streams.wrapped[i].clone_ref(py))
+    };
+    let sys = py.import_bound("sys")?;
+    for (name, wrapper) in STD_STREAMS.iter().zip(&wrapped) {
+        sys.setattr(*name, wrapper)?;
+    }
+    Ok(())
 }
 
 /// A Python module implemented in Rust
@@ -326,6 +1320,8 @@ fn colorama_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Back>()?;
     m.add_class::<Style>()?;
     m.add_class::<Cursor>()?;
+    m.add_class::<AutoResetStream>()?;
+    m.add_class::<AnsiToWin32>()?;
     
     m.add_function(wrap_pyfunction!(init, m)?)?;
     m.add_function(wrap_pyfunction!(deinit, m)?)?;
@@ -338,8 +1334,12 @@ fn colorama_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(back_256, m)?)?;
     m.add_function(wrap_pyfunction!(fore_rgb, m)?)?;
     m.add_function(wrap_pyfunction!(back_rgb, m)?)?;
+    m.add_function(wrap_pyfunction!(gradient, m)?)?;
+    m.add_function(wrap_pyfunction!(rainbow, m)?)?;
     m.add_function(wrap_pyfunction!(colorize, m)?)?;
     m.add_function(wrap_pyfunction!(strip_ansi, m)?)?;
+    m.add_function(wrap_pyfunction!(wrap, m)?)?;
+    m.add_function(wrap_pyfunction!(ansi_to_win32, m)?)?;
     
     Ok(())
 }
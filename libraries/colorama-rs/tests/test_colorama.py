@@ -0,0 +1,329 @@
+"""
+Tests for colorama-rs
+
+Verifies API compatibility with the colorama package
+"""
+
+import io
+import sys
+import threading
+
+import pytest
+
+try:
+    from colorama_rs import (
+        AnsiToWin32, AutoResetStream, Back, Cursor, Fore, Style, ansi_to_win32, colorize, deinit, fore_256, fore_rgb,
+        gradient, init, rainbow, reinit, set_title, wrap,
+    )
+except ImportError:
+    pytest.skip("colorama_rs not available", allow_module_level=True)
+
+
+class TTY(io.StringIO):
+    """An in-memory stream that claims to be a terminal"""
+
+    def isatty(self):
+        return True
+
+
+def write_from_threads(stream, text, threads=4, writes=200):
+    errors = []
+
+    def worker():
+        try:
+            for _ in range(writes):
+                stream.write(text)
+        except Exception as e:
+            errors.append(e)
+
+    workers = [threading.Thread(target=worker) for _ in range(threads)]
+    for worker_thread in workers:
+        worker_thread.start()
+    for worker_thread in workers:
+        worker_thread.join()
+    return errors
+
+
+class TestTruecolor:
+    """gradient and rainbow color each visible character"""
+
+    def test_gradient_endpoints_and_midpoint(self):
+        result = gradient("abc", (255, 0, 0), (0, 0, 255))
+        assert result == "\x1b[38;2;255;0;0ma\x1b[38;2;128;0;128mb\x1b[38;2;0;0;255mc" + Style.RESET_ALL
+
+    def test_gradient_single_char_uses_start(self):
+        assert gradient("a", (255, 0, 0), (0, 0, 255)) == "\x1b[38;2;255;0;0ma" + Style.RESET_ALL
+
+    def test_gradient_empty(self):
+        assert gradient("", (255, 0, 0), (0, 0, 255)) == ""
+
+    def test_gradient_skip_whitespace(self):
+        result = gradient("a c", (255, 0, 0), (0, 0, 255), skip_whitespace=True)
+        assert result == "\x1b[38;2;255;0;0ma \x1b[38;2;0;0;255mc" + Style.RESET_ALL
+
+    def test_rainbow(self):
+        assert rainbow("ab") == "\x1b[38;2;255;0;0ma\x1b[38;2;0;255;255mb" + Style.RESET_ALL
+
+    def test_rainbow_skip_whitespace(self):
+        result = rainbow("a b", skip_whitespace=True)
+        assert result == "\x1b[38;2;255;0;0ma \x1b[38;2;0;0;255mb" + Style.RESET_ALL
+
+
+class TestWrap:
+    """wrap counts display columns and carries colors across lines"""
+
+    def test_plain(self):
+        assert wrap("the quick brown fox", 10) == ["the quick", "brown fox"]
+
+    def test_colors_reopened_on_each_line(self):
+        lines = wrap(Fore.RED + "hello world foo" + Style.RESET_ALL, 5)
+        assert lines == [Fore.RED + "hello" + Style.RESET_ALL, Fore.RED + "world" + Style.RESET_ALL, Fore.RED + "foo" + Style.RESET_ALL]
+
+    def test_long_word_split(self):
+        assert wrap("abcdefghij", 4) == ["abcd", "efgh", "ij"]
+
+    def test_escapes_not_counted(self):
+        assert wrap(Fore.GREEN + "ab" + Style.RESET_ALL + " cd", 5) == [Fore.GREEN + "ab" + Style.RESET_ALL + " cd"]
+
+    def test_wide_characters(self):
+        lines = wrap(Fore.RED + "漢字漢字漢字 abc def" + Style.RESET_ALL, 4)
+        assert lines == [Fore.RED + text + Style.RESET_ALL for text in ["漢字", "漢字", "漢字", "abc", "def"]]
+
+    def test_wide_character_wider_than_width(self):
+        assert wrap("漢a", 1) == ["漢", "a"]
+
+    def test_zero_width(self):
+        with pytest.raises(ValueError):
+            wrap("text", 0)
+
+
+class TestCursor:
+    """Cursor movement, save/restore, visibility and scrolling sequences"""
+
+    def test_movement_defaults_to_one(self):
+        assert Cursor.UP() == "\x1b[1A"
+        assert Cursor.DOWN() == "\x1b[1B"
+        assert Cursor.BACK() == "\x1b[1D"
+
+    def test_movement(self):
+        assert Cursor.UP(3) == "\x1b[3A"
+        assert Cursor.FORWARD(2) == "\x1b[2C"
+
+    def test_position(self):
+        assert Cursor.POS(2, 5) == "\x1b[5;2H"
+        assert Cursor.POS() == "\x1b[1;1H"
+
+    def test_save_restore(self):
+        assert Cursor.SAVE() == "\x1b7"
+        assert Cursor.RESTORE() == "\x1b8"
+
+    def test_visibility(self):
+        assert Cursor.HIDE() == "\x1b[?25l"
+        assert Cursor.SHOW() == "\x1b[?25h"
+
+    def test_column_and_scroll(self):
+        assert Cursor.COLUMN(10) == "\x1b[10G"
+        assert Cursor.SCROLL_UP(2) == "\x1b[2S"
+        assert Cursor.SCROLL_DOWN() == "\x1b[1T"
+
+
+class TestColorize:
+    """colorize with single and listed styles and an optional reset"""
+
+    def test_fore_and_back(self):
+        assert colorize("x", fore=Fore.RED, back=Back.BLUE) == Fore.RED + Back.BLUE + "x" + Style.RESET_ALL
+
+    def test_style_list(self):
+        result = colorize("x", fore=Fore.RED, style=[Style.BRIGHT, Style.DIM])
+        assert result == Style.BRIGHT + Style.DIM + Fore.RED + "x" + Style.RESET_ALL
+
+    def test_style_list_with_raw_code(self):
+        assert colorize("x", style=[Style.BRIGHT, "\x1b[4m"]) == "\x1b[1m\x1b[4mx\x1b[0m"
+
+    def test_single_style(self):
+        assert colorize("x", style=Style.BRIGHT) == Style.BRIGHT + "x" + Style.RESET_ALL
+
+    def test_no_reset(self):
+        prefix = colorize("> ", fore=Fore.CYAN, reset=False)
+        assert prefix == Fore.CYAN + "> "
+        assert colorize("x", style=[Style.BRIGHT, Style.DIM], reset=False) == Style.BRIGHT + Style.DIM + "x"
+
+
+class TestAnsiToWin32Text:
+    """ansi_to_win32 reduces text to what a legacy console understands"""
+
+    def test_unchanged_without_convert_off_windows(self):
+        if sys.platform == "win32":
+            pytest.skip("depends on the console")
+        text = fore_rgb(250, 10, 10) + "x"
+        assert ansi_to_win32(text) == text
+
+    def test_truecolor_and_256_to_basic(self):
+        assert ansi_to_win32(fore_rgb(250, 10, 10) + "error", convert=True) == "\x1b[91merror"
+        assert ansi_to_win32(fore_256(196) + "error", convert=True) == "\x1b[91merror"
+
+    def test_mixed_sgr(self):
+        assert ansi_to_win32("\x1b[1;38;5;21;48;2;0;0;0m", convert=True) == "\x1b[1;34;40m"
+
+    def test_unsupported_attributes_dropped(self):
+        assert ansi_to_win32("\x1b[3;4mx", convert=True) == "x"
+
+    def test_cursor_and_erase_made_explicit(self):
+        assert ansi_to_win32("\x1b[H\x1b[J\x1b[2A", convert=True) == "\x1b[1;1H\x1b[0J\x1b[2A"
+
+    def test_other_sequences_stripped(self):
+        assert ansi_to_win32(set_title("t") + "x" + Cursor.HIDE(), convert=True) == "x"
+
+    def test_basic_colors_kept(self):
+        assert ansi_to_win32(Fore.RED + "x" + Style.RESET_ALL, convert=True) == Fore.RED + "x" + Style.RESET_ALL
+
+    def test_convert_false(self):
+        text = fore_rgb(1, 2, 3) + "x"
+        assert ansi_to_win32(text, convert=False) == text
+
+
+class TestAnsiToWin32:
+    """AnsiToWin32 strips when the stream isn't a terminal"""
+
+    def test_strips_non_tty(self):
+        out = io.StringIO()
+        stream = AnsiToWin32(out)
+        stream.write(Fore.RED + "error" + Style.RESET_ALL + "\n")
+        assert stream.strip and not stream.convert
+        assert out.getvalue() == "error\n"
+
+    def test_passes_tty_through(self):
+        out = TTY()
+        stream = AnsiToWin32(out)
+        stream.write(Fore.RED + "error")
+        assert not stream.strip
+        assert out.getvalue() == Fore.RED + "error"
+
+    def test_explicit_strip(self):
+        out = TTY()
+        AnsiToWin32(out, strip=True).write(Fore.RED + "x")
+        assert out.getvalue() == "x"
+        out = io.StringIO()
+        AnsiToWin32(out, strip=False).write(Fore.RED + "x")
+        assert out.getvalue() == Fore.RED + "x"
+
+    def test_convert_without_console_strips(self):
+        out = TTY()
+        stream = AnsiToWin32(out, convert=True)
+        stream.write(Fore.RED + "x" + Cursor.UP())
+        assert not stream.convert and stream.strip
+        assert out.getvalue() == "x"
+
+    def test_reset_without_console(self):
+        out = TTY()
+        AnsiToWin32(out).reset()
+        assert out.getvalue() == ""
+
+    def test_init_installs_wrappers(self):
+        original = sys.stdout
+        init(convert=True)
+        try:
+            assert isinstance(sys.stdout, AnsiToWin32)
+            assert sys.stdout.stream is original
+        finally:
+            deinit()
+        assert sys.stdout is original
+
+    def test_init_autoreset_wraps_converter(self):
+        init(autoreset=True, strip=True)
+        try:
+            assert isinstance(sys.stdout, AutoResetStream)
+            assert isinstance(sys.stdout.stream, AnsiToWin32)
+        finally:
+            deinit()
+
+    def test_write_returns_length(self):
+        assert AnsiToWin32(io.StringIO()).write(Fore.RED + "ab") == 7
+
+    def test_delegates_to_stream(self):
+        out = io.StringIO()
+        stream = AnsiToWin32(out)
+        assert stream.stream is out
+        assert stream.getvalue == out.getvalue
+
+    def test_threads(self):
+        out = io.StringIO()
+        assert write_from_threads(AnsiToWin32(out), Fore.GREEN + "ok\n") == []
+        assert out.getvalue() == "ok\n" * 800
+
+
+class TestAutoReset:
+    """AutoResetStream resets before newlines that leave colors open"""
+
+    def test_plain_text_untouched(self):
+        out = io.StringIO()
+        AutoResetStream(out).write("plain\n")
+        assert out.getvalue() == "plain\n"
+
+    def test_line_from_several_writes(self):
+        out = io.StringIO()
+        stream = AutoResetStream(out)
+        stream.write(Fore.RED + "a")
+        stream.write(" ")
+        stream.write(Style.BRIGHT + "b")
+        assert out.getvalue() == ""
+        stream.write("\n")
+        assert out.getvalue() == Fore.RED + "a " + Style.BRIGHT + "b" + Style.RESET_ALL + "\n"
+
+    def test_partial_line_stays_buffered(self):
+        out = io.StringIO()
+        stream = AutoResetStream(out)
+        stream.write(Fore.GREEN + "loading")
+        stream.write("...")
+        assert out.getvalue() == ""
+        stream.write(" done\nnext")
+        assert out.getvalue() == Fore.GREEN + "loading... done" + Style.RESET_ALL + "\n"
+
+    def test_write_ending_with_color_open(self):
+        out = io.StringIO()
+        stream = AutoResetStream(out)
+        stream.write("x\n" + Fore.RED)
+        assert out.getvalue() == "x\n"
+        stream.write("y\n")
+        assert out.getvalue() == "x\n" + Fore.RED + "y" + Style.RESET_ALL + "\n"
+
+    def test_flush_keeps_colors_open(self):
+        out = io.StringIO()
+        stream = AutoResetStream(out)
+        stream.write(Fore.RED + "a")
+        stream.flush()
+        assert out.getvalue() == Fore.RED + "a"
+        stream.write("b\n")
+        assert out.getvalue() == Fore.RED + "ab" + Style.RESET_ALL + "\n"
+
+    def test_already_reset_line_unchanged(self):
+        out = io.StringIO()
+        AutoResetStream(out).write(Fore.RED + "a" + Style.RESET_ALL + "\n")
+        assert out.getvalue() == Fore.RED + "a" + Style.RESET_ALL + "\n"
+
+    def test_context_manager_resets(self):
+        out = io.StringIO()
+        with pytest.raises(KeyError):
+            with AutoResetStream(out) as stream:
+                stream.write(Fore.YELLOW + "warning: ")
+                raise KeyError
+        assert out.getvalue() == Fore.YELLOW + "warning: " + Style.RESET_ALL
+
+    def test_threads(self):
+        out = io.StringIO()
+        assert write_from_threads(AutoResetStream(out), Fore.RED + "x\n") == []
+        assert out.getvalue() == (Fore.RED + "x" + Style.RESET_ALL + "\n") * 800
+
+    def test_init_deinit_reinit(self):
+        original = sys.stdout
+        init(autoreset=True, strip=False)
+        try:
+            assert isinstance(sys.stdout, AutoResetStream)
+            assert sys.stdout.stream is original
+            deinit()
+            assert sys.stdout is original
+            reinit()
+            assert isinstance(sys.stdout, AutoResetStream)
+        finally:
+            deinit()
+        assert sys.stdout is original
@@ -1,3 +1,6 @@
+// pyo3 0.22's `#[pyfunction]` expansion trips this lint on `PyResult` returns
+#![allow(clippy::useless_conversion)]
+
 use chrono::{Datelike, Local};
 use once_cell::sync::Lazy;
 use pyo3::prelude::*;
@@ -39,9 +42,26 @@ static TZOFFSETS: Lazy<HashMap<&'static str, i32>> = Lazy::new(|| {
     m
 });
 
+// IANA zone names mapped to their standard-time abbreviation in TZOFFSETS,
+// used by `gettz` when no tz database is available
+static IANA_FALLBACK: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
+    let mut m = HashMap::new();
+    m.insert("utc", "utc"); m.insert("etc/utc", "utc"); m.insert("etc/gmt", "gmt");
+    m.insert("gmt", "gmt"); m.insert("europe/london", "gmt");
+    m.insert("america/new_york", "est"); m.insert("us/eastern", "est");
+    m.insert("america/detroit", "est"); m.insert("america/toronto", "est");
+    m.insert("america/chicago", "cst"); m.insert("us/central", "cst");
+    m.insert("america/winnipeg", "cst");
+    m.insert("america/denver", "mst"); m.insert("us/mountain", "mst");
+    m.insert("america/phoenix", "mst"); m.insert("america/edmonton", "mst");
+    m.insert("america/los_angeles", "pst"); m.insert("us/pacific", "pst");
+    m.insert("america/vancouver", "pst");
+    m
+});
+
 // Pre-compiled regex patterns
 static ISO_DATETIME: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^(\d{4})-(\d{2})-(\d{2})[T ](\d{2}):(\d{2}):(\d{2})(?:\.(\d+))?(?:Z|([+-])(\d{2}):?(\d{2}))?$").unwrap()
+    Regex::new(r"^(\d{4})-(\d{2})-(\d{2})[T ](\d{2}):(\d{2})(?::(\d{2})(?:\.(\d+))?)?(?:Z|([+-])(\d{2}):?(\d{2}))?$").unwrap()
 });
 
 static ISO_DATE: Lazy<Regex> = Lazy::new(|| {
@@ -56,31 +76,54 @@ static EU_DATE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"^(\d{1,2})\.(\d{1,2})\.(\d{2,4})$").unwrap()
 });
 
+// Minutes are optional when a marker is present, so "3pm" and "3 PM" read
+// as 15:00
 static TIME_12H: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(\d{1,2}):(\d{2})(?::(\d{2}))?\s*(am|pm|AM|PM)").unwrap()
+    Regex::new(r"(?i)\b(\d{1,2})(?::(\d{2})(?::(\d{2}))?)?\s*(am|pm)\b").unwrap()
+});
+
+// A 12-hour time on its own, optionally introduced by "at"
+static BARE_TIME_12H: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?:at\s+)?(\d{1,2})(?::(\d{2})(?::(\d{2}))?)?\s*(am|pm)$").unwrap()
 });
 
 static TIME_24H: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(\d{1,2}):(\d{2})(?::(\d{2}))?(?:\.(\d+))?").unwrap()
 });
 
+// Month-name forms take an optional comma before the year and two- or
+// four-digit years. The year must be followed by a separator or the end, so
+// the hour of "Jan 5 10:30" is never read as a year.
 static MONTH_DAY_YEAR: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)([a-z]+)\s+(\d{1,2})(?:st|nd|rd|th)?,?\s+(\d{4})").unwrap()
+    Regex::new(r"(?i)\b([a-z]+)\.?\s+(\d{1,2})(?:st|nd|rd|th)?(?:,\s*|\s+)(\d{4}|\d{2})(?:[\s,]|$)").unwrap()
 });
 
 static DAY_MONTH_YEAR: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)(\d{1,2})(?:st|nd|rd|th)?\s+([a-z]+),?\s+(\d{4})").unwrap()
+    Regex::new(r"(?i)\b(\d{1,2})(?:st|nd|rd|th)?\s+([a-z]+)\.?(?:,\s*|\s+)(\d{4}|\d{2})(?:[\s,]|$)").unwrap()
 });
 
-static TIMEZONE_OFFSET: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"([+-])(\d{2}):?(\d{2})$").unwrap()
+// Anchored so that "May 12" or a full date never parses as month and year
+static MONTH_YEAR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^([a-z]+)\.?,?\s+(\d{4})$").unwrap()
 });
 
-fn parse_year(s: &str) -> Option<i32> {
+/// How two-digit years are expanded
+///
+/// With a `century` base (e.g. 1800) every two-digit year lands in that
+/// century; otherwise years at or above `pivot` are 19xx and the rest 20xx.
+#[derive(Clone, Copy)]
+struct YearRule {
+    pivot: i32,
+    century: Option<i32>,
+}
+
+fn parse_year(s: &str, rule: YearRule) -> Option<i32> {
     let year: i32 = s.parse().ok()?;
     if year < 100 {
         // Two-digit year
-        if year >= 69 {
+        if let Some(century) = rule.century {
+            Some(century + year)
+        } else if year >= rule.pivot {
             Some(1900 + year)
         } else {
             Some(2000 + year)
@@ -94,6 +137,19 @@ fn parse_month_name(s: &str) -> Option<u32> {
     MONTHS.get(s.to_lowercase().as_str()).copied()
 }
 
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
 struct ParsedDateTime {
     year: i32,
     month: u32,
@@ -121,18 +177,19 @@ impl ParsedDateTime {
     }
 }
 
-fn parse_datetime_str(s: &str, dayfirst: bool, _yearfirst: bool) -> Option<ParsedDateTime> {
-    let s = s.trim();
+/// Parse ISO 8601 dates and datetimes only, with no surrounding whitespace
+fn parse_iso_str(s: &str) -> Option<ParsedDateTime> {
     let mut result = ParsedDateTime::new();
     
-    // Try ISO format first (most common)
     if let Some(caps) = ISO_DATETIME.captures(s) {
         result.year = caps.get(1)?.as_str().parse().ok()?;
         result.month = caps.get(2)?.as_str().parse().ok()?;
         result.day = caps.get(3)?.as_str().parse().ok()?;
         result.hour = caps.get(4)?.as_str().parse().ok()?;
         result.minute = caps.get(5)?.as_str().parse().ok()?;
-        result.second = caps.get(6)?.as_str().parse().ok()?;
+        if let Some(sec) = caps.get(6) {
+            result.second = sec.as_str().parse().ok()?;
+        }
         
         if let Some(frac) = caps.get(7) {
             let frac_str = frac.as_str();
@@ -153,7 +210,7 @@ fn parse_datetime_str(s: &str, dayfirst: bool, _yearfirst: bool) -> Option<Parse
         return Some(result);
     }
     
-    // Try ISO date only
+    // Date only
     if let Some(caps) = ISO_DATE.captures(s) {
         result.year = caps.get(1)?.as_str().parse().ok()?;
         result.month = caps.get(2)?.as_str().parse().ok()?;
@@ -161,11 +218,120 @@ fn parse_datetime_str(s: &str, dayfirst: bool, _yearfirst: bool) -> Option<Parse
         return Some(result);
     }
     
+    None
+}
+
+/// Fill in the time from a `TIME_12H` or `BARE_TIME_12H` match
+///
+/// Missing minutes and seconds are zero; 12am is midnight and 12pm noon.
+/// Hours outside 1-12 don't match.
+fn apply_time_12h(caps: &regex::Captures<'_>, result: &mut ParsedDateTime) -> Option<()> {
+    let hour: u32 = caps.get(1)?.as_str().parse().ok()?;
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+    result.minute = match caps.get(2) {
+        Some(min) => min.as_str().parse().ok()?,
+        None => 0,
+    };
+    if let Some(sec) = caps.get(3) {
+        result.second = sec.as_str().parse().ok()?;
+    }
+    let pm = caps.get(4)?.as_str().eq_ignore_ascii_case("pm");
+    result.hour = match (hour, pm) {
+        (12, false) => 0,
+        (12, true) => 12,
+        (h, true) => h + 12,
+        (h, false) => h,
+    };
+    Some(())
+}
+
+/// Fill in the time from a 12- or 24-hour time in `remaining`, the text
+/// after a written-out date; with no time, midnight is assumed
+fn apply_trailing_time(remaining: &str, result: &mut ParsedDateTime, report: &mut ParseReport) -> Option<()> {
+    if let Some(time_caps) = TIME_12H.captures(remaining) {
+        apply_time_12h(&time_caps, result)?;
+    } else if let Some(time_caps) = TIME_24H.captures(remaining) {
+        result.hour = time_caps.get(1)?.as_str().parse().ok()?;
+        result.minute = time_caps.get(2)?.as_str().parse().ok()?;
+        if let Some(sec) = time_caps.get(3) {
+            result.second = sec.as_str().parse().ok()?;
+        }
+    } else {
+        report.assume("time 00:00:00");
+    }
+    Some(())
+}
+
+/// How `parse_datetime_str` read a string: the matcher that fired and
+/// what it assumed for components the string didn't give
+struct ParseReport {
+    matcher: &'static str,
+    assumed: Vec<String>,
+}
+
+impl ParseReport {
+    fn new(matcher: &'static str) -> Self {
+        Self { matcher, assumed: Vec::new() }
+    }
+
+    fn assume(&mut self, note: impl Into<String>) {
+        self.assumed.push(note.into());
+    }
+
+    /// Note a two-digit year's expansion
+    fn year(&mut self, raw: &str, year: i32) {
+        if raw.len() <= 2 {
+            self.assume(format!("two-digit year {} read as {}", raw, year));
+        }
+    }
+
+    /// Note how an all-numeric date's first two fields were ordered
+    fn order(&mut self, dayfirst: bool) {
+        self.assume(if dayfirst {
+            "day before month (dayfirst=True)"
+        } else {
+            "month before day (dayfirst=False)"
+        });
+    }
+}
+
+/// Parse any supported format. `default` is the caller's default date as
+/// (year, month, day): its day fills in month-and-year strings such as
+/// "January 2023" (1 when not given) and the whole date backs a bare time
+/// such as "3pm" (today when not given). `years` expands two-digit years.
+fn parse_datetime_str(
+    s: &str,
+    dayfirst: bool,
+    _yearfirst: bool,
+    default: Option<(i32, u32, u32)>,
+    years: YearRule,
+) -> Option<(ParsedDateTime, ParseReport)> {
+    let s = s.trim();
+    
+    // Try ISO format first (most common)
+    if let Some(result) = parse_iso_str(s) {
+        let report = if ISO_DATE.is_match(s) {
+            let mut report = ParseReport::new("iso-date");
+            report.assume("time 00:00:00");
+            report
+        } else {
+            ParseReport::new("iso-datetime")
+        };
+        return Some((result, report));
+    }
+    
+    let mut result = ParsedDateTime::new();
+    
     // Try US format MM/DD/YYYY
     if let Some(caps) = US_DATE.captures(s) {
+        let mut report = ParseReport::new("us-date");
         let first: u32 = caps.get(1)?.as_str().parse().ok()?;
         let second: u32 = caps.get(2)?.as_str().parse().ok()?;
-        result.year = parse_year(caps.get(3)?.as_str())?;
+        let raw_year = caps.get(3)?.as_str();
+        result.year = parse_year(raw_year, years)?;
+        report.year(raw_year, result.year);
         
         if dayfirst {
             result.day = first;
@@ -174,14 +340,19 @@ fn parse_datetime_str(s: &str, dayfirst: bool, _yearfirst: bool) -> Option<Parse
             result.month = first;
             result.day = second;
         }
-        return Some(result);
+        report.order(dayfirst);
+        report.assume("time 00:00:00");
+        return Some((result, report));
     }
     
     // Try European format DD.MM.YYYY
     if let Some(caps) = EU_DATE.captures(s) {
+        let mut report = ParseReport::new("eu-date");
         let first: u32 = caps.get(1)?.as_str().parse().ok()?;
         let second: u32 = caps.get(2)?.as_str().parse().ok()?;
-        result.year = parse_year(caps.get(3)?.as_str())?;
+        let raw_year = caps.get(3)?.as_str();
+        result.year = parse_year(raw_year, years)?;
+        report.year(raw_year, result.year);
         
         if dayfirst {
             result.day = first;
@@ -190,46 +361,68 @@ fn parse_datetime_str(s: &str, dayfirst: bool, _yearfirst: bool) -> Option<Parse
             result.month = first;
             result.day = second;
         }
-        return Some(result);
+        report.order(dayfirst);
+        report.assume("time 00:00:00");
+        return Some((result, report));
     }
     
     // Try "Month Day, Year" format
     if let Some(caps) = MONTH_DAY_YEAR.captures(s) {
-        result.month = parse_month_name(caps.get(1)?.as_str())?;
-        result.day = caps.get(2)?.as_str().parse().ok()?;
-        result.year = caps.get(3)?.as_str().parse().ok()?;
-        
-        // Check for time portion
-        let remaining = &s[caps.get(0)?.end()..];
-        if let Some(time_caps) = TIME_12H.captures(remaining) {
-            result.hour = time_caps.get(1)?.as_str().parse().ok()?;
-            result.minute = time_caps.get(2)?.as_str().parse().ok()?;
-            if let Some(sec) = time_caps.get(3) {
-                result.second = sec.as_str().parse().ok()?;
-            }
-            let ampm = time_caps.get(4)?.as_str().to_lowercase();
-            if ampm == "pm" && result.hour != 12 {
-                result.hour += 12;
-            } else if ampm == "am" && result.hour == 12 {
-                result.hour = 0;
-            }
-        } else if let Some(time_caps) = TIME_24H.captures(remaining) {
-            result.hour = time_caps.get(1)?.as_str().parse().ok()?;
-            result.minute = time_caps.get(2)?.as_str().parse().ok()?;
-            if let Some(sec) = time_caps.get(3) {
-                result.second = sec.as_str().parse().ok()?;
-            }
+        if let Some(month) = parse_month_name(caps.get(1)?.as_str()) {
+            let mut report = ParseReport::new("month-day-year");
+            result.month = month;
+            result.day = caps.get(2)?.as_str().parse().ok()?;
+            let raw_year = caps.get(3)?.as_str();
+            result.year = parse_year(raw_year, years)?;
+            report.year(raw_year, result.year);
+            
+            apply_trailing_time(&s[caps.get(0)?.end()..], &mut result, &mut report)?;
+            return Some((result, report));
         }
-        
-        return Some(result);
     }
     
     // Try "Day Month Year" format
     if let Some(caps) = DAY_MONTH_YEAR.captures(s) {
-        result.day = caps.get(1)?.as_str().parse().ok()?;
-        result.month = parse_month_name(caps.get(2)?.as_str())?;
-        result.year = caps.get(3)?.as_str().parse().ok()?;
-        return Some(result);
+        if let Some(month) = parse_month_name(caps.get(2)?.as_str()) {
+            let mut report = ParseReport::new("day-month-year");
+            result.day = caps.get(1)?.as_str().parse().ok()?;
+            result.month = month;
+            let raw_year = caps.get(3)?.as_str();
+            result.year = parse_year(raw_year, years)?;
+            report.year(raw_year, result.year);
+            apply_trailing_time(&s[caps.get(0)?.end()..], &mut result, &mut report)?;
+            return Some((result, report));
+        }
+    }
+    
+    // Try "Month Year" format
+    if let Some(caps) = MONTH_YEAR.captures(s) {
+        let mut report = ParseReport::new("month-year");
+        result.month = parse_month_name(caps.get(1)?.as_str())?;
+        result.year = caps.get(2)?.as_str().parse().ok()?;
+        result.day = default.map_or(1, |(_, _, day)| day);
+        report.assume(match default {
+            Some((_, _, day)) => format!("day {} (from default)", day),
+            None => "day 1".to_string(),
+        });
+        report.assume("time 00:00:00");
+        return Some((result, report));
+    }
+    
+    // Try a bare 12-hour time such as "3pm" or "at 9:30 am"
+    if let Some(caps) = BARE_TIME_12H.captures(s) {
+        let mut report = ParseReport::new("time-12h");
+        apply_time_12h(&caps, &mut result)?;
+        match default {
+            Some((year, month, day)) => {
+                result.year = year;
+                result.month = month;
+                result.day = day;
+                report.assume("date from default");
+            }
+            None => report.assume("date today"),
+        }
+        return Some((result, report));
     }
     
     None
@@ -237,8 +430,19 @@ fn parse_datetime_str(s: &str, dayfirst: bool, _yearfirst: bool) -> Option<Parse
 
 /// Parse a datetime string into a Python datetime object
 /// dateutil.parser.parse("2023-01-15 14:30:00") -> datetime(2023, 1, 15, 14, 30, 0)
+///
+/// Two-digit years at or above `yearpivot` (default 69) are 19xx and the
+/// rest 20xx; `century` (a base year such as 1800) overrides the pivot and
+/// puts every two-digit year in that century.
+///
+/// With `verbose=True` the result is `(datetime, report)`, where `report`
+/// is a dict naming the `matcher` that fired, the components it
+/// `assumed`, the UTC `offset` in seconds found in the string (or None)
+/// and the `timezone` outcome: "none" when the string had no offset,
+/// "dropped" when it had one that the naive result leaves out.
 #[pyfunction]
-#[pyo3(signature = (timestr, parserinfo=None, dayfirst=false, yearfirst=false, fuzzy=false, fuzzy_with_tokens=false, default=None, ignoretz=false, tzinfos=None))]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (timestr, parserinfo=None, dayfirst=false, yearfirst=false, fuzzy=false, fuzzy_with_tokens=false, default=None, ignoretz=false, tzinfos=None, *, yearpivot=69, century=None, verbose=false))]
 fn parse(
     py: Python<'_>,
     timestr: &str,
@@ -250,20 +454,62 @@ fn parse(
     default: Option<&Bound<'_, PyAny>>,
     ignoretz: bool,
     tzinfos: Option<&Bound<'_, PyAny>>,
+    yearpivot: i32,
+    century: Option<i32>,
+    verbose: bool,
 ) -> PyResult<PyObject> {
-    let _ = (parserinfo, fuzzy, fuzzy_with_tokens, default, ignoretz, tzinfos); // TODO: implement these
+    let _ = (parserinfo, fuzzy, fuzzy_with_tokens, ignoretz, tzinfos); // TODO: implement these
+
+    if !(0..=100).contains(&yearpivot) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "yearpivot must be between 0 and 100",
+        ));
+    }
+    if century.is_some_and(|c| c % 100 != 0) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "century must be a multiple of 100, e.g. 1800",
+        ));
+    }
+    let years = YearRule { pivot: yearpivot, century };
 
-    let parsed = parse_datetime_str(timestr, dayfirst, yearfirst)
+    let default_date = default
+        .map(|d| -> PyResult<(i32, u32, u32)> {
+            Ok((d.getattr("year")?.extract()?, d.getattr("month")?.extract()?, d.getattr("day")?.extract()?))
+        })
+        .transpose()?;
+    let (parsed, report) = parse_datetime_str(timestr, dayfirst, yearfirst, default_date, years)
         .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
             format!("Unable to parse datetime string: {}", timestr)
         ))?;
 
+    let dt = build_datetime(py, &parsed, None)?;
+    if !verbose {
+        return Ok(dt);
+    }
+
+    let info = pyo3::types::PyDict::new_bound(py);
+    info.set_item("matcher", report.matcher)?;
+    info.set_item("assumed", report.assumed)?;
+    info.set_item("offset", parsed.tz_offset)?;
+    info.set_item("timezone", if parsed.tz_offset.is_some() { "dropped" } else { "none" })?;
+    Ok((dt, info).into_py(py))
+}
+
+/// Validate parsed fields and construct a `datetime.datetime`
+fn build_datetime(
+    py: Python<'_>,
+    parsed: &ParsedDateTime,
+    tzinfo: Option<Bound<'_, PyAny>>,
+) -> PyResult<PyObject> {
     // Validate
     if parsed.month < 1 || parsed.month > 12 {
         return Err(pyo3::exceptions::PyValueError::new_err("Invalid month"));
     }
-    if parsed.day < 1 || parsed.day > 31 {
-        return Err(pyo3::exceptions::PyValueError::new_err("Invalid day"));
+    if parsed.day < 1 || parsed.day > days_in_month(parsed.year, parsed.month) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "day {} is out of range for month {}",
+            parsed.day, parsed.month
+        )));
     }
 
     // Create Python datetime using the datetime module
@@ -278,15 +524,527 @@ fn parse(
         parsed.minute,
         parsed.second,
         parsed.microsecond,
+        tzinfo,
     ))?;
 
     Ok(dt.into())
 }
 
-/// Parse an ISO format datetime string (fast path)
+/// Parse a strict ISO 8601 string (fast path)
+///
+/// Only `YYYY-MM-DD` and `YYYY-MM-DD[T ]HH:MM[:SS[.ffffff]]` with an
+/// optional `Z` or `±HH[:]MM` offset are accepted; anything `parse` would
+/// handle heuristically (e.g. "01/02/2003") raises ValueError. Offsets
+/// produce an aware datetime.
 #[pyfunction]
 fn isoparse(py: Python<'_>, timestr: &str) -> PyResult<PyObject> {
-    parse(py, timestr, None, false, false, false, false, None, false, None)
+    let parsed = parse_iso_str(timestr).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid isoformat string: '{}'",
+            timestr
+        ))
+    })?;
+
+    let tzinfo = match parsed.tz_offset {
+        Some(0) => Some(tzutc(py)?),
+        Some(seconds) => Some(fixed_offset(py, None, &seconds.into_py(py).into_bound(py))?),
+        None => None,
+    };
+    build_datetime(py, &parsed, tzinfo)
+}
+
+/// Build a fixed-offset `datetime.timezone`
+fn fixed_offset<'py>(
+    py: Python<'py>,
+    name: Option<&str>,
+    offset: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let datetime_mod = py.import_bound("datetime")?;
+    let delta = if offset.is_instance(&datetime_mod.getattr("timedelta")?)? {
+        offset.clone()
+    } else {
+        let seconds: i32 = offset.extract()?;
+        datetime_mod.getattr("timedelta")?.call1((0, seconds))?
+    };
+
+    let timezone = datetime_mod.getattr("timezone")?;
+    match name {
+        Some(name) => timezone.call1((delta, name)),
+        None => timezone.call1((delta,)),
+    }
+}
+
+/// Fixed-offset tzinfo for an abbreviation in TZOFFSETS
+fn abbreviation_tz<'py>(py: Python<'py>, abbr: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let key = abbr.to_lowercase();
+    match TZOFFSETS.get(key.as_str()) {
+        Some(0) => tzutc(py).map(Some),
+        Some(&seconds) => {
+            let offset = seconds.into_py(py).into_bound(py);
+            fixed_offset(py, Some(&key.to_uppercase()), &offset).map(Some)
+        }
+        None => Ok(None),
+    }
+}
+
+/// Return the UTC tzinfo
+/// dateutil.tz.tzutc() -> datetime.timezone.utc
+#[pyfunction]
+fn tzutc(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    py.import_bound("datetime")?.getattr("timezone")?.getattr("utc")
+}
+
+/// Return a fixed-offset tzinfo; `offset` is seconds east of UTC or a timedelta
+/// dateutil.tz.tzoffset("BRST", -10800)
+#[pyfunction]
+#[pyo3(signature = (name, offset))]
+fn tzoffset<'py>(
+    py: Python<'py>,
+    name: Option<&str>,
+    offset: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    fixed_offset(py, name, offset)
+}
+
+/// Look up a tzinfo by abbreviation or IANA name, or the local zone if no
+/// name is given. Returns None for unknown names.
+///
+/// IANA names resolve through `zoneinfo` when a tz database is available.
+/// Otherwise a few common zones fall back to a fixed offset taken from their
+/// standard-time abbreviation, so daylight saving time is not applied.
+#[pyfunction]
+#[pyo3(signature = (name=None))]
+fn gettz<'py>(py: Python<'py>, name: Option<&str>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let name = match name.map(str::trim) {
+        Some(name) if !name.is_empty() => name,
+        _ => {
+            let datetime_cls = py.import_bound("datetime")?.getattr("datetime")?;
+            let now = datetime_cls.call_method0("now")?.call_method0("astimezone")?;
+            return now.getattr("tzinfo").map(Some);
+        }
+    };
+
+    if let Some(tz) = abbreviation_tz(py, name)? {
+        return Ok(Some(tz));
+    }
+
+    let zone = py
+        .import_bound("zoneinfo")
+        .and_then(|zoneinfo| zoneinfo.getattr("ZoneInfo")?.call1((name,)));
+    if let Ok(zone) = zone {
+        return Ok(Some(zone));
+    }
+
+    match IANA_FALLBACK.get(name.to_lowercase().as_str()) {
+        Some(abbr) => abbreviation_tz(py, abbr),
+        None => Ok(None),
+    }
+}
+
+/// Two-letter day names, Monday first like `date.weekday()`
+const WEEKDAY_NAMES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// A day of the week with an optional ordinal, as in dateutil's MO..SU
+///
+/// In a `relativedelta`, `FR` or `FR(+1)` is the next Friday (the same day
+/// if already Friday), `FR(+2)` the one after, and `FR(-1)` the previous
+/// Friday (again the same day if already Friday).
+#[pyclass(frozen, name = "weekday")]
+#[derive(Clone, Copy)]
+struct Weekday {
+    #[pyo3(get)]
+    weekday: u8,
+    #[pyo3(get)]
+    n: Option<i32>,
+}
+
+impl Weekday {
+    /// The ordinal as applied: no `n` counts as +1
+    fn nth(&self) -> i32 {
+        self.n.unwrap_or(1)
+    }
+}
+
+// Equal when they pick the same day, so relativedelta(weekday=FR) equals
+// relativedelta(weekday=FR(+1)); `weekday.__eq__` itself compares `n` as given
+impl PartialEq for Weekday {
+    fn eq(&self, other: &Self) -> bool {
+        self.weekday == other.weekday && self.nth() == other.nth()
+    }
+}
+
+#[pymethods]
+impl Weekday {
+    #[new]
+    #[pyo3(signature = (weekday, n=None))]
+    fn new(weekday: u8, n: Option<i32>) -> PyResult<Self> {
+        if weekday > 6 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "weekday must be between 0 (Monday) and 6 (Sunday)",
+            ));
+        }
+        if n == Some(0) {
+            return Err(pyo3::exceptions::PyValueError::new_err("Can't create weekday with n==0"));
+        }
+        Ok(Self { weekday, n })
+    }
+
+    /// The same day with ordinal `n`, e.g. `FR(-1)`
+    #[pyo3(signature = (n=None))]
+    fn __call__(&self, n: Option<i32>) -> PyResult<Self> {
+        Self::new(self.weekday, n)
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        other
+            .downcast::<Weekday>()
+            .is_ok_and(|other| other.get().weekday == self.weekday && other.get().n == self.n)
+    }
+
+    fn __hash__(&self) -> u64 {
+        (self.weekday as u64) << 32 | self.n.unwrap_or(0) as u32 as u64
+    }
+
+    fn __repr__(&self) -> String {
+        let name = WEEKDAY_NAMES[self.weekday as usize];
+        match self.n {
+            Some(n) => format!("{}({:+})", name, n),
+            None => name.to_string(),
+        }
+    }
+}
+
+/// Carry the part of `value` beyond `size` into `next`, keeping the sign
+fn carry(value: &mut i64, next: &mut i64, size: i64) {
+    if value.abs() >= size {
+        let sign = value.signum();
+        *next += (*value * sign) / size * sign;
+        *value = (*value * sign) % size * sign;
+    }
+}
+
+/// Relative and absolute date arithmetic, like dateutil's relativedelta
+///
+/// Plural fields (`months=+1`) are added; singular fields (`day=1`)
+/// replace. Applied to a date, the absolute fields are set first, then
+/// years and months are added (clamping the day to the month's length),
+/// then days, weeks and smaller units, and finally `weekday` moves to the
+/// requested day. So `relativedelta(months=+1, day=1, weekday=MO(+1))` is
+/// the first Monday of next month. Only integer values are supported.
+#[pyclass(frozen, name = "relativedelta")]
+#[derive(Clone, Default, PartialEq)]
+struct RelativeDelta {
+    #[pyo3(get)]
+    years: i64,
+    #[pyo3(get)]
+    months: i64,
+    #[pyo3(get)]
+    days: i64,
+    #[pyo3(get)]
+    leapdays: i64,
+    #[pyo3(get)]
+    hours: i64,
+    #[pyo3(get)]
+    minutes: i64,
+    #[pyo3(get)]
+    seconds: i64,
+    #[pyo3(get)]
+    microseconds: i64,
+    #[pyo3(get)]
+    year: Option<i64>,
+    #[pyo3(get)]
+    month: Option<u32>,
+    #[pyo3(get)]
+    day: Option<u32>,
+    #[pyo3(get)]
+    hour: Option<u32>,
+    #[pyo3(get)]
+    minute: Option<u32>,
+    #[pyo3(get)]
+    second: Option<u32>,
+    #[pyo3(get)]
+    microsecond: Option<u32>,
+    #[pyo3(get)]
+    weekday: Option<Weekday>,
+}
+
+impl RelativeDelta {
+    /// Normalize units into the next larger one, as dateutil does
+    fn fix(mut self) -> Self {
+        carry(&mut self.microseconds, &mut self.seconds, 1_000_000);
+        carry(&mut self.seconds, &mut self.minutes, 60);
+        carry(&mut self.minutes, &mut self.hours, 60);
+        carry(&mut self.hours, &mut self.days, 24);
+        carry(&mut self.months, &mut self.years, 12);
+        self
+    }
+
+    /// The relative fields negated; like dateutil, `leapdays` is kept
+    fn negated(&self) -> Self {
+        Self {
+            years: -self.years,
+            months: -self.months,
+            days: -self.days,
+            hours: -self.hours,
+            minutes: -self.minutes,
+            seconds: -self.seconds,
+            microseconds: -self.microseconds,
+            ..self.clone()
+        }
+    }
+
+    /// Sum of two deltas; `other`'s absolute fields and `leapdays` win
+    /// where both are set
+    fn combined(&self, other: &Self) -> Self {
+        Self {
+            years: self.years + other.years,
+            months: self.months + other.months,
+            days: self.days + other.days,
+            leapdays: if other.leapdays != 0 { other.leapdays } else { self.leapdays },
+            hours: self.hours + other.hours,
+            minutes: self.minutes + other.minutes,
+            seconds: self.seconds + other.seconds,
+            microseconds: self.microseconds + other.microseconds,
+            year: other.year.or(self.year),
+            month: other.month.or(self.month),
+            day: other.day.or(self.day),
+            hour: other.hour.or(self.hour),
+            minute: other.minute.or(self.minute),
+            second: other.second.or(self.second),
+            microsecond: other.microsecond.or(self.microsecond),
+            weekday: other.weekday.or(self.weekday),
+        }
+        .fix()
+    }
+
+    /// Whether any time-of-day field is set, relative or absolute
+    fn has_time(&self) -> bool {
+        self.hours != 0
+            || self.minutes != 0
+            || self.seconds != 0
+            || self.microseconds != 0
+            || self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.microsecond.is_some()
+    }
+
+    /// Apply to a `date` or `datetime`; a plain `date` becomes a midnight
+    /// `datetime` when the delta has time fields
+    fn apply<'py>(&self, date: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = date.py();
+        let datetime_mod = py.import_bound("datetime")?;
+        let datetime_cls = datetime_mod.getattr("datetime")?;
+        let promoted;
+        let date = if self.has_time() && !date.is_instance(&datetime_cls)? {
+            promoted = datetime_cls.call_method1("fromordinal", (date.call_method0("toordinal")?,))?;
+            &promoted
+        } else {
+            date
+        };
+        let mut year = self.year.unwrap_or(date.getattr("year")?.extract()?) + self.years;
+        let mut month = self.month.unwrap_or(date.getattr("month")?.extract()?) as i64;
+        if self.months != 0 {
+            month += self.months;
+            if month > 12 {
+                year += 1;
+                month -= 12;
+            } else if month < 1 {
+                year -= 1;
+                month += 12;
+            }
+        }
+        if !(1..=9999).contains(&year) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "year {} is out of range",
+                year
+            )));
+        }
+        let (year, month) = (year as i32, month as u32);
+        let day = self.day.unwrap_or(date.getattr("day")?.extract()?).min(days_in_month(year, month));
+
+        let repl = pyo3::types::PyDict::new_bound(py);
+        repl.set_item("year", year)?;
+        repl.set_item("month", month)?;
+        repl.set_item("day", day)?;
+        for (name, value) in [
+            ("hour", self.hour),
+            ("minute", self.minute),
+            ("second", self.second),
+            ("microsecond", self.microsecond),
+        ] {
+            if let Some(value) = value {
+                repl.set_item(name, value)?;
+            }
+        }
+
+        let mut days = self.days;
+        if self.leapdays != 0 && month > 2 && is_leap_year(year) {
+            days += self.leapdays;
+        }
+        let timedelta = datetime_mod.getattr("timedelta")?;
+        let shift = pyo3::types::PyDict::new_bound(py);
+        shift.set_item("days", days)?;
+        shift.set_item("hours", self.hours)?;
+        shift.set_item("minutes", self.minutes)?;
+        shift.set_item("seconds", self.seconds)?;
+        shift.set_item("microseconds", self.microseconds)?;
+        let mut result = date
+            .call_method("replace", (), Some(&repl))?
+            .add(timedelta.call((), Some(&shift))?)?;
+
+        if let Some(target) = self.weekday {
+            let current: i64 = result.call_method0("weekday")?.extract()?;
+            let (weekday, nth) = (target.weekday as i64, target.nth());
+            let mut jump = (nth.abs() as i64 - 1) * 7;
+            if nth > 0 {
+                jump += (7 - current + weekday).rem_euclid(7);
+            } else {
+                jump += (current - weekday).rem_euclid(7);
+                jump = -jump;
+            }
+            result = result.add(timedelta.call1((jump,))?)?;
+        }
+        Ok(result)
+    }
+}
+
+#[pymethods]
+impl RelativeDelta {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (*, years=0, months=0, days=0, leapdays=0, weeks=0, hours=0, minutes=0, seconds=0, microseconds=0, year=None, month=None, day=None, weekday=None, hour=None, minute=None, second=None, microsecond=None))]
+    fn new(
+        years: i64,
+        months: i64,
+        days: i64,
+        leapdays: i64,
+        weeks: i64,
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        microseconds: i64,
+        year: Option<i64>,
+        month: Option<u32>,
+        day: Option<u32>,
+        weekday: Option<&Bound<'_, PyAny>>,
+        hour: Option<u32>,
+        minute: Option<u32>,
+        second: Option<u32>,
+        microsecond: Option<u32>,
+    ) -> PyResult<Self> {
+        if month.is_some_and(|m| !(1..=12).contains(&m)) {
+            return Err(pyo3::exceptions::PyValueError::new_err("month must be in 1..12"));
+        }
+        if day.is_some_and(|d| !(1..=31).contains(&d)) {
+            return Err(pyo3::exceptions::PyValueError::new_err("day must be in 1..31"));
+        }
+        let weekday = match weekday {
+            None => None,
+            Some(value) => match value.downcast::<Weekday>() {
+                Ok(weekday) => Some(*weekday.get()),
+                Err(_) => Some(Weekday::new(value.extract()?, None)?),
+            },
+        };
+        Ok(Self {
+            years,
+            months,
+            days: days + weeks * 7,
+            leapdays,
+            hours,
+            minutes,
+            seconds,
+            microseconds,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            microsecond,
+            weekday,
+        }
+        .fix())
+    }
+
+    /// Whole weeks in `days`, truncated toward zero
+    #[getter]
+    fn weeks(&self) -> i64 {
+        self.days / 7
+    }
+
+    fn __add__<'py>(&self, other: &Bound<'py, PyAny>) -> PyResult<PyObject> {
+        let py = other.py();
+        if let Ok(other) = other.downcast::<RelativeDelta>() {
+            return Ok(self.combined(other.get()).into_py(py));
+        }
+        if other.is_instance(&py.import_bound("datetime")?.getattr("date")?)? {
+            return Ok(self.apply(other)?.unbind());
+        }
+        Ok(py.NotImplemented())
+    }
+
+    fn __radd__<'py>(&self, other: &Bound<'py, PyAny>) -> PyResult<PyObject> {
+        self.__add__(other)
+    }
+
+    fn __sub__<'py>(&self, other: &Bound<'py, PyAny>) -> PyResult<PyObject> {
+        match other.downcast::<RelativeDelta>() {
+            Ok(other) => Ok(other.get().negated().combined(self).into_py(other.py())),
+            Err(_) => Ok(other.py().NotImplemented()),
+        }
+    }
+
+    fn __rsub__<'py>(&self, other: &Bound<'py, PyAny>) -> PyResult<PyObject> {
+        self.negated().__add__(other)
+    }
+
+    fn __neg__(&self) -> Self {
+        self.negated()
+    }
+
+    fn __bool__(&self) -> bool {
+        *self != Self::default()
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        other.downcast::<RelativeDelta>().is_ok_and(|other| other.get() == self)
+    }
+
+    fn __repr__(&self) -> String {
+        let relative = [
+            ("years", self.years),
+            ("months", self.months),
+            ("days", self.days),
+            ("leapdays", self.leapdays),
+            ("hours", self.hours),
+            ("minutes", self.minutes),
+            ("seconds", self.seconds),
+            ("microseconds", self.microseconds),
+        ];
+        let mut parts: Vec<String> = relative
+            .iter()
+            .filter(|(_, value)| *value != 0)
+            .map(|(name, value)| format!("{}={:+}", name, value))
+            .collect();
+        let absolute = [
+            ("year", self.year),
+            ("month", self.month.map(i64::from)),
+            ("day", self.day.map(i64::from)),
+        ];
+        parts.extend(absolute.iter().filter_map(|(name, value)| value.map(|v| format!("{}={}", name, v))));
+        if let Some(weekday) = self.weekday {
+            parts.push(format!("weekday={}", weekday.__repr__()));
+        }
+        let time = [
+            ("hour", self.hour),
+            ("minute", self.minute),
+            ("second", self.second),
+            ("microsecond", self.microsecond),
+        ];
+        parts.extend(time.iter().filter_map(|(name, value)| value.map(|v| format!("{}={}", name, v))));
+        format!("relativedelta({})", parts.join(", "))
+    }
 }
 
 /// A Python module implemented in Rust
@@ -294,5 +1052,13 @@ fn isoparse(py: Python<'_>, timestr: &str) -> PyResult<PyObject> {
 fn dateutil_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(parse, m)?)?;
     m.add_function(wrap_pyfunction!(isoparse, m)?)?;
+    m.add_function(wrap_pyfunction!(gettz, m)?)?;
+    m.add_function(wrap_pyfunction!(tzoffset, m)?)?;
+    m.add_function(wrap_pyfunction!(tzutc, m)?)?;
+    m.add_class::<RelativeDelta>()?;
+    m.add_class::<Weekday>()?;
+    for (weekday, name) in WEEKDAY_NAMES.iter().enumerate() {
+        m.add(*name, Weekday { weekday: weekday as u8, n: None })?;
+    }
     Ok(())
 }
@@ -0,0 +1,387 @@
+"""
+Tests for dateutil-rs
+
+Verifies API compatibility with dateutil.parser
+"""
+
+import sys
+from datetime import date, datetime, timedelta, timezone
+
+import pytest
+
+try:
+    from dateutil_rs import FR, MO, SU, TH, gettz, isoparse, parse, relativedelta, tzoffset, tzutc
+except ImportError:
+    pytest.skip("dateutil_rs not available", allow_module_level=True)
+
+
+class TestDayOfMonth:
+    """Days are validated against the length of the month"""
+
+    def test_feb_29_leap_year(self):
+        assert parse("2024-02-29") == datetime(2024, 2, 29)
+        assert parse("2000-02-29") == datetime(2000, 2, 29)
+
+    def test_feb_29_non_leap_year(self):
+        with pytest.raises(ValueError, match="day 29 is out of range for month 2"):
+            parse("2023-02-29")
+        with pytest.raises(ValueError, match="day 29 is out of range for month 2"):
+            parse("1900-02-29")
+
+    def test_april_31(self):
+        with pytest.raises(ValueError, match="day 31 is out of range for month 4"):
+            parse("2023-04-31")
+
+    def test_month_name_format(self):
+        with pytest.raises(ValueError, match="day 30 is out of range for month 2"):
+            parse("Feb 30, 2023")
+
+    def test_valid_boundaries(self):
+        assert parse("2023-02-28") == datetime(2023, 2, 28)
+        assert parse("2023-04-30") == datetime(2023, 4, 30)
+        assert parse("2023-12-31") == datetime(2023, 12, 31)
+        assert parse("2023-01-01") == datetime(2023, 1, 1)
+
+    def test_day_zero(self):
+        with pytest.raises(ValueError):
+            parse("2023-01-00")
+
+
+class TestMonthNames:
+    """Month-name forms with optional commas, suffixes and short years"""
+
+    def test_month_day_year(self):
+        assert parse("January 1st, 2023") == datetime(2023, 1, 1)
+        assert parse("Jan 1 2023") == datetime(2023, 1, 1)
+        assert parse("Jan 1,2023") == datetime(2023, 1, 1)
+        assert parse("Sept 5 99") == datetime(1999, 9, 5)
+
+    def test_day_month_year(self):
+        assert parse("1 Jan 23") == datetime(2023, 1, 1)
+        assert parse("1st January, 2023") == datetime(2023, 1, 1)
+
+    def test_weekday_prefix(self):
+        assert parse("Monday, Jan 2 2023") == datetime(2023, 1, 2)
+        assert parse("Monday 2 Jan 2023") == datetime(2023, 1, 2)
+
+    def test_time_after_date(self):
+        assert parse("March 3rd 2024 3:15pm") == datetime(2024, 3, 3, 15, 15)
+
+    def test_month_year(self):
+        assert parse("January 2023") == datetime(2023, 1, 1)
+        assert parse("Jan. 2023") == datetime(2023, 1, 1)
+        assert parse("January 2023", default=datetime(2000, 6, 15)) == datetime(2023, 1, 15)
+
+    def test_no_over_matching(self):
+        for value in ("Jan 5 10:30", "May 12", "January 20234"):
+            with pytest.raises(ValueError):
+                parse(value)
+
+
+class TestTwoDigitYears:
+    """Pivot and century for two-digit years"""
+
+    def test_default_pivot(self):
+        assert parse("1/1/50") == datetime(2050, 1, 1)
+        assert parse("1/1/69") == datetime(1969, 1, 1)
+
+    def test_yearpivot(self):
+        assert parse("1/1/50", yearpivot=50) == datetime(1950, 1, 1)
+        assert parse("1/1/49", yearpivot=50) == datetime(2049, 1, 1)
+        assert parse("15 Jan 70", yearpivot=80) == datetime(2070, 1, 15)
+
+    def test_century(self):
+        assert parse("1/1/50", century=1800) == datetime(1850, 1, 1)
+        assert parse("1/1/1950", century=1800) == datetime(1950, 1, 1)
+
+    def test_invalid(self):
+        with pytest.raises(ValueError):
+            parse("1/1/50", yearpivot=101)
+        with pytest.raises(ValueError):
+            parse("1/1/50", century=1850)
+
+
+class TestVerbose:
+    """verbose=True reports how the string was read"""
+
+    def test_default_is_single_value(self):
+        assert parse("2023-01-15") == datetime(2023, 1, 15)
+
+    def test_iso_date(self):
+        dt, report = parse("2023-01-15", verbose=True)
+        assert dt == datetime(2023, 1, 15)
+        assert report["matcher"] == "iso-date"
+        assert report["assumed"] == ["time 00:00:00"]
+        assert report["offset"] is None
+        assert report["timezone"] == "none"
+
+    def test_numeric_order_and_year(self):
+        _, report = parse("01/02/23", verbose=True)
+        assert report["matcher"] == "us-date"
+        assert "two-digit year 23 read as 2023" in report["assumed"]
+        assert "month before day (dayfirst=False)" in report["assumed"]
+        _, report = parse("15.03.2023", dayfirst=True, verbose=True)
+        assert report["matcher"] == "eu-date"
+        assert "day before month (dayfirst=True)" in report["assumed"]
+
+    def test_month_names(self):
+        _, report = parse("March 5, 2023 3:30 PM", verbose=True)
+        assert report["matcher"] == "month-day-year"
+        assert report["assumed"] == []
+        _, report = parse("January 2023", default=datetime(2020, 6, 20), verbose=True)
+        assert report["matcher"] == "month-year"
+        assert "day 20 (from default)" in report["assumed"]
+
+    def test_offset_dropped(self):
+        dt, report = parse("2023-01-15T10:30:00+02:00", verbose=True)
+        assert dt.tzinfo is None
+        assert report["matcher"] == "iso-datetime"
+        assert report["offset"] == 7200
+        assert report["timezone"] == "dropped"
+
+
+class TestTwelveHourTimes:
+    """12-hour times with an AM/PM marker and optional minutes"""
+
+    def test_hour_only_after_date(self):
+        assert parse("January 5, 2023 3pm") == datetime(2023, 1, 5, 15, 0)
+        assert parse("Jan 5 2023 9 AM") == datetime(2023, 1, 5, 9, 0)
+
+    def test_time_after_day_first_date(self):
+        assert parse("5 January 2023 3pm") == datetime(2023, 1, 5, 15, 0)
+        assert parse("5th Jan, 2023 9:30 am") == datetime(2023, 1, 5, 9, 30)
+        dt, report = parse("5 January 2023 3pm", verbose=True)
+        assert report["matcher"] == "day-month-year"
+        assert "time 00:00:00" not in report["assumed"]
+
+    def test_24h_time_after_day_first_date(self):
+        assert parse("5 Jan 2023 14:30") == datetime(2023, 1, 5, 14, 30)
+        assert parse("5 Jan 2023 14:30:15") == datetime(2023, 1, 5, 14, 30, 15)
+
+    def test_day_first_date_without_time(self):
+        dt, report = parse("5 January 2023", verbose=True)
+        assert dt == datetime(2023, 1, 5)
+        assert report["assumed"] == ["time 00:00:00"]
+
+    def test_bare_time_uses_default_date(self):
+        default = datetime(2020, 6, 20)
+        assert parse("3pm", default=default) == datetime(2020, 6, 20, 15, 0)
+        assert parse("3 PM", default=default) == datetime(2020, 6, 20, 15, 0)
+        assert parse("at 9am", default=default) == datetime(2020, 6, 20, 9, 0)
+        assert parse("at 9:30 am", default=default) == datetime(2020, 6, 20, 9, 30)
+
+    def test_bare_time_defaults_to_today(self):
+        dt, report = parse("3pm", verbose=True)
+        assert dt.date() == datetime.now().date()
+        assert (dt.hour, dt.minute, dt.second) == (15, 0, 0)
+        assert report["matcher"] == "time-12h"
+        assert report["assumed"] == ["date today"]
+
+    def test_midnight_and_noon(self):
+        default = datetime(2020, 6, 20)
+        assert parse("12am", default=default) == datetime(2020, 6, 20, 0, 0)
+        assert parse("12pm", default=default) == datetime(2020, 6, 20, 12, 0)
+        assert parse("Jan 5, 2023 12am") == datetime(2023, 1, 5, 0, 0)
+
+    def test_hour_out_of_range(self):
+        for text in ("13pm", "0am"):
+            with pytest.raises(ValueError):
+                parse(text)
+
+
+class TestIsoparse:
+    """isoparse only accepts ISO 8601"""
+
+    def test_date(self):
+        assert isoparse("2023-01-15") == datetime(2023, 1, 15)
+
+    def test_datetime(self):
+        assert isoparse("2023-01-15T14:30:05") == datetime(2023, 1, 15, 14, 30, 5)
+        assert isoparse("2023-01-15 14:30") == datetime(2023, 1, 15, 14, 30)
+        assert isoparse("2023-01-15T14:30:05.25") == datetime(2023, 1, 15, 14, 30, 5, 250000)
+
+    def test_utc(self):
+        assert isoparse("2023-01-15T14:30:00Z").tzinfo == timezone.utc
+
+    def test_offset(self):
+        dt = isoparse("2023-01-15T14:30:00+05:30")
+        assert dt.utcoffset() == timedelta(hours=5, minutes=30)
+        dt = isoparse("2023-01-15T14:30:00-0800")
+        assert dt.utcoffset() == timedelta(hours=-8)
+
+    @pytest.mark.parametrize("value", ["01/02/2003", "15.01.2023", "Jan 15, 2023", " 2023-01-15", "2023-1-5"])
+    def test_rejects_non_iso(self, value):
+        with pytest.raises(ValueError):
+            isoparse(value)
+
+
+class TestRelativedeltaWeekday:
+    """weekday targeting and its order relative to the absolute fields"""
+
+    WEDNESDAY = date(2024, 1, 17)
+    FRIDAY = date(2024, 1, 19)
+
+    def test_next_friday(self):
+        assert self.WEDNESDAY + relativedelta(weekday=FR) == date(2024, 1, 19)
+        assert self.WEDNESDAY + relativedelta(weekday=FR(+1)) == date(2024, 1, 19)
+        assert self.WEDNESDAY + relativedelta(weekday=FR(+2)) == date(2024, 1, 26)
+
+    def test_previous_friday(self):
+        assert self.WEDNESDAY + relativedelta(weekday=FR(-1)) == date(2024, 1, 12)
+        assert self.WEDNESDAY + relativedelta(weekday=FR(-2)) == date(2024, 1, 5)
+
+    def test_stays_on_target_day(self):
+        assert self.FRIDAY + relativedelta(weekday=FR) == self.FRIDAY
+        assert self.FRIDAY + relativedelta(weekday=FR(-1)) == self.FRIDAY
+        assert self.FRIDAY + relativedelta(days=+1, weekday=FR) == date(2024, 1, 26)
+
+    def test_first_monday_of_next_month(self):
+        delta = relativedelta(day=1, months=+1, weekday=MO(+1))
+        assert self.WEDNESDAY + delta == date(2024, 2, 5)
+        assert date(2024, 3, 20) + delta == date(2024, 4, 1)
+
+    def test_last_friday_of_month(self):
+        assert self.WEDNESDAY + relativedelta(day=31, weekday=FR(-1)) == date(2024, 1, 26)
+        assert date(2024, 2, 10) + relativedelta(day=31, weekday=FR(-1)) == date(2024, 2, 23)
+
+    def test_fourth_thursday_of_november(self):
+        delta = relativedelta(month=11, day=1, weekday=TH(+4))
+        assert date(2024, 1, 1) + delta == date(2024, 11, 28)
+        assert date(2023, 6, 1) + delta == date(2023, 11, 23)
+
+    def test_weekday_as_int_and_subtraction(self):
+        assert self.WEDNESDAY + relativedelta(weekday=4) == self.FRIDAY
+        assert self.WEDNESDAY - relativedelta(weekday=SU(-1)) == date(2024, 1, 14)
+
+    def test_datetime_keeps_time(self):
+        start = datetime(2024, 1, 17, 9, 30)
+        assert start + relativedelta(weekday=FR, hour=17, minute=0) == datetime(2024, 1, 19, 17, 0)
+
+    def test_weekday_objects(self):
+        assert repr(FR) == "FR"
+        assert repr(FR(-1)) == "FR(-1)"
+        assert FR(+1).n == 1 and FR.n is None
+        assert MO.weekday == 0
+        with pytest.raises(ValueError):
+            FR(0)
+
+
+class TestRelativedeltaArithmetic:
+    """Relative and absolute fields, matching dateutil"""
+
+    def test_month_end_clamping(self):
+        assert date(2024, 1, 31) + relativedelta(months=+1) == date(2024, 2, 29)
+        assert date(2023, 1, 31) + relativedelta(months=+1) == date(2023, 2, 28)
+        assert date(2024, 3, 31) - relativedelta(months=1) == date(2024, 2, 29)
+
+    def test_normalization_and_repr(self):
+        assert repr(relativedelta(months=14, hours=25)) == "relativedelta(years=+1, months=+2, days=+1, hours=+1)"
+        assert repr(relativedelta(months=+1, day=1, weekday=MO(+1))) == "relativedelta(months=+1, day=1, weekday=MO(+1))"
+        assert relativedelta(weeks=2).days == 14
+
+    def test_time_fields_promote_date(self):
+        assert date(2024, 1, 1) + relativedelta(hours=+6) == datetime(2024, 1, 1, 6)
+
+    def test_combine(self):
+        assert relativedelta(months=1) + relativedelta(days=2) == relativedelta(months=1, days=2)
+        assert -relativedelta(months=1, day=3) == relativedelta(months=-1, day=3)
+        assert not relativedelta()
+        assert relativedelta(day=1)
+
+
+class TestTzFactories:
+    """tzutc and tzoffset build datetime.timezone objects"""
+
+    def test_tzutc(self):
+        assert tzutc() is timezone.utc
+
+    def test_tzoffset_seconds(self):
+        tz = tzoffset("BRST", -10800)
+        assert tz.utcoffset(None) == timedelta(hours=-3)
+        assert tz.tzname(None) == "BRST"
+
+    def test_tzoffset_timedelta(self):
+        tz = tzoffset("IST", timedelta(hours=5, minutes=30))
+        assert tz.utcoffset(None) == timedelta(hours=5, minutes=30)
+
+    def test_tzoffset_without_name(self):
+        tz = tzoffset(None, 3600)
+        assert tz == timezone(timedelta(hours=1))
+        assert tz.tzname(None) == "UTC+01:00"
+
+
+class TestGettz:
+    """Abbreviations first, then zoneinfo, then the local zone for no name"""
+
+    SUMMER = datetime(2024, 7, 1, 12)
+    WINTER = datetime(2024, 1, 1, 12)
+
+    def test_abbreviation(self):
+        tz = gettz("EST")
+        assert tz.utcoffset(self.SUMMER) == timedelta(hours=-5)
+        assert tz.tzname(None) == "EST"
+        assert gettz("pdt").utcoffset(None) == timedelta(hours=-7)
+
+    def test_utc_abbreviations(self):
+        assert gettz("UTC") is timezone.utc
+        assert gettz("z") is timezone.utc
+
+    def test_abbreviation_before_zoneinfo(self):
+        # zoneinfo also has an "EST" zone; the abbreviation table wins
+        assert type(gettz("EST")) is timezone
+
+    def test_iana_name_uses_zoneinfo(self):
+        zoneinfo = pytest.importorskip("zoneinfo")
+        try:
+            zoneinfo.ZoneInfo("America/New_York")
+        except zoneinfo.ZoneInfoNotFoundError:
+            pytest.skip("no tz database available")
+        tz = gettz("America/New_York")
+        assert isinstance(tz, zoneinfo.ZoneInfo)
+        assert tz.utcoffset(self.WINTER) == timedelta(hours=-5)
+        assert tz.utcoffset(self.SUMMER) == timedelta(hours=-4)
+
+    def test_unknown(self):
+        assert gettz("Not/AZone") is None
+
+    def test_local_zone(self):
+        for name in (None, "", "  "):
+            tz = gettz(name)
+            assert tz is not None
+            assert tz.utcoffset(datetime.now()) is not None
+
+
+class TestGettzFallback:
+    """Without zoneinfo, common IANA names fall back to a fixed offset"""
+
+    def setup_method(self):
+        self.saved = sys.modules.get("zoneinfo")
+        # A None entry makes `import zoneinfo` raise ImportError
+        sys.modules["zoneinfo"] = None
+
+    def teardown_method(self):
+        if self.saved is None:
+            del sys.modules["zoneinfo"]
+        else:
+            sys.modules["zoneinfo"] = self.saved
+
+    def test_standard_time_offset(self):
+        tz = gettz("America/New_York")
+        assert type(tz) is timezone
+        assert tz.tzname(None) == "EST"
+        # No daylight saving time without a tz database
+        assert tz.utcoffset(datetime(2024, 7, 1)) == timedelta(hours=-5)
+
+    def test_case_insensitive(self):
+        assert gettz("america/los_angeles").utcoffset(None) == timedelta(hours=-8)
+        assert gettz("US/Central").tzname(None) == "CST"
+
+    def test_utc_names(self):
+        assert gettz("Etc/UTC") is timezone.utc
+        assert gettz("Europe/London") is timezone.utc
+
+    def test_abbreviations_still_work(self):
+        assert gettz("MDT").utcoffset(None) == timedelta(hours=-6)
+
+    def test_unlisted_zone(self):
+        assert gettz("Europe/Paris") is None
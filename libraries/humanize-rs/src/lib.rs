@@ -1,26 +1,109 @@
+// pyo3 0.22's `#[pyfunction]` expansion trips this lint on `PyResult` returns
+#![allow(clippy::useless_conversion)]
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use num_format::{Locale, ToFormattedString};
+use pyo3::types::{PyDict, PyLong};
+use num_format::{CustomFormat, Locale, ToFormattedString};
+
+/// Look up a locale by name, accepting POSIX spellings such as "de_DE" and
+/// falling back to the language ("pt_BR" -> "pt" if needed)
+fn find_locale(name: &str) -> Option<Locale> {
+    let name = name.split('.').next().unwrap_or(name).replace('_', "-");
+    let language = name.split('-').next().unwrap_or(&name);
+    Locale::from_name(name.as_str())
+        .or_else(|_| Locale::from_name(language))
+        .ok()
+}
 
 /// Format a number with comma separators
 /// humanize.intcomma(1000000) -> "1,000,000"
+///
+/// `locale` (e.g. "de_DE" -> "1.000.000", "fr" -> "1 000 000") picks the
+/// grouping separator and style from that locale; `separator` overrides
+/// the separator, and covers locales that aren't known.
 #[pyfunction]
-#[pyo3(signature = (value, ndigits=None))]
-fn intcomma(value: i64, ndigits: Option<i32>) -> String {
-    match ndigits {
+#[pyo3(signature = (value, ndigits=None, *, locale=None, separator=None))]
+fn intcomma(value: i64, ndigits: Option<i32>, locale: Option<&str>, separator: Option<&str>) -> PyResult<String> {
+    let value = match ndigits {
         Some(n) if n > 0 => {
             let factor = 10_f64.powi(n);
-            let rounded = (value as f64 / factor).round() * factor;
-            (rounded as i64).to_formatted_string(&Locale::en)
+            ((value as f64 / factor).round() * factor) as i64
         }
-        _ => value.to_formatted_string(&Locale::en),
+        _ => value,
+    };
+
+    let base = match locale {
+        Some(name) => match (find_locale(name), separator) {
+            (Some(found), _) => found,
+            (None, Some(_)) => Locale::en,
+            (None, None) => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown locale {:?}; pass separator= to group it explicitly",
+                    name
+                )))
+            }
+        },
+        None => Locale::en,
+    };
+    let format = CustomFormat::builder()
+        .grouping(base.grouping())
+        .separator(separator.unwrap_or(base.separator()))
+        .minus_sign(base.minus_sign())
+        .build()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    Ok(value.to_formatted_string(&format))
+}
+
+/// Spelled-out ordinals for 1 through 31, enough for days of the month
+const ORDINAL_WORDS: [&str; 31] = [
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth",
+    "ninth", "tenth", "eleventh", "twelfth", "thirteenth", "fourteenth", "fifteenth",
+    "sixteenth", "seventeenth", "eighteenth", "nineteenth", "twentieth",
+    "twenty-first", "twenty-second", "twenty-third", "twenty-fourth", "twenty-fifth",
+    "twenty-sixth", "twenty-seventh", "twenty-eighth", "twenty-ninth", "thirtieth",
+    "thirty-first",
+];
+
+/// `int(value)` for ints of any size, floats and numeric strings; None when
+/// Python can't convert it
+fn as_python_int<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = value.py();
+    match py.get_type_bound::<PyLong>().call1((value,)) {
+        Ok(int) => Ok(Some(int)),
+        Err(e) if e.is_instance_of::<PyValueError>(py) || e.is_instance_of::<PyTypeError>(py) => Ok(None),
+        Err(e) => Err(e),
     }
 }
 
 /// Convert a number to its ordinal form
 /// humanize.ordinal(3) -> "3rd"
+///
+/// With `words=True`, values from 1 up to `limit` (at most 31) are spelled
+/// out ("third"); anything else falls back to the numeric form. Integers of
+/// any size and numeric strings are accepted; other values are returned as
+/// their string. `gender` is accepted for compatibility with humanize and
+/// has no effect on English ordinals.
 #[pyfunction]
-fn ordinal(value: i64) -> String {
-    let suffix = match (value % 10, value % 100) {
+#[pyo3(signature = (value, words=false, limit=31, gender="male"))]
+fn ordinal(value: &Bound<'_, PyAny>, words: bool, limit: i64, gender: &str) -> PyResult<String> {
+    let _ = gender;
+    let Some(value) = as_python_int(value)? else {
+        return Ok(value.str()?.to_string());
+    };
+    if let Ok(small) = value.extract::<i64>() {
+        if words && (1..=limit).contains(&small) {
+            if let Some(word) = ORDINAL_WORDS.get(small as usize - 1) {
+                return Ok(word.to_string());
+            }
+        }
+    }
+
+    // Python's modulo, so negative values get humanize's suffixes
+    let ones: i64 = value.rem(10)?.extract()?;
+    let tens: i64 = value.rem(100)?.extract()?;
+    let suffix = match (ones, tens) {
         (1, 11) => "th",
         (2, 12) => "th",
         (3, 13) => "th",
@@ -29,81 +112,129 @@ fn ordinal(value: i64) -> String {
         (3, _) => "rd",
         _ => "th",
     };
-    format!("{}{}", value, suffix)
+    Ok(format!("{}{}", value.str()?, suffix))
 }
 
+/// Scale words used by `intword`, as powers of ten
+const INTWORD_SCALES: &[(usize, &str)] = &[
+    (6, "million"),
+    (9, "billion"),
+    (12, "trillion"),
+    (15, "quadrillion"),
+    (18, "quintillion"),
+    (21, "sextillion"),
+    (24, "septillion"),
+    (27, "octillion"),
+    (30, "nonillion"),
+    (33, "decillion"),
+    (100, "googol"),
+];
+
 /// Convert a number to its word form
 /// humanize.intword(1_000_000) -> "1.0 million"
+///
+/// Integers of any size, floats and numeric strings are accepted; the
+/// scale is chosen from the decimal digits, so values past `i64` keep
+/// going through quintillion up to decillion, then googol. A value that
+/// rounds up to 1000 of one scale moves to the next ("1.0 quintillion"
+/// rather than "1000.0 quadrillion"). Values Python can't convert to
+/// `int` are returned as their string.
 #[pyfunction]
 #[pyo3(signature = (value, format_str=None))]
-fn intword(value: i64, format_str: Option<&str>) -> String {
+fn intword(value: &Bound<'_, PyAny>, format_str: Option<&str>) -> PyResult<String> {
     let fmt = format_str.unwrap_or("%.1f");
-    
-    let (divisor, suffix): (f64, &str) = if value.abs() >= 1_000_000_000_000_000 {
-        (1_000_000_000_000_000.0, "quadrillion")
-    } else if value.abs() >= 1_000_000_000_000 {
-        (1_000_000_000_000.0, "trillion")
-    } else if value.abs() >= 1_000_000_000 {
-        (1_000_000_000.0, "billion")
-    } else if value.abs() >= 1_000_000 {
-        (1_000_000.0, "million")
-    } else {
-        return value.to_formatted_string(&Locale::en);
+    let Some(value) = as_python_int(value)? else {
+        return Ok(value.str()?.to_string());
     };
-    
-    let num = value as f64 / divisor;
-    
-    // Parse format string for precision
-    let precision = if fmt.contains('.') {
-        fmt.chars()
-            .skip_while(|c| *c != '.')
-            .skip(1)
-            .take_while(|c| c.is_ascii_digit())
-            .collect::<String>()
-            .parse::<usize>()
-            .unwrap_or(1)
-    } else {
-        1
+    let text = value.str()?.to_string();
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", text.as_str()),
     };
-    
-    format!("{:.prec$} {}", num, suffix, prec = precision)
+
+    let Some(mut index) = INTWORD_SCALES.iter().rposition(|&(power, _)| digits.len() > power) else {
+        let small: i64 = value.extract()?;
+        return Ok(small.to_formatted_string(&Locale::en));
+    };
+    let precision = format_precision(fmt);
+    // Split the digits at the scale instead of dividing, so huge values
+    // keep their leading digits exactly
+    let split = digits.len() - INTWORD_SCALES[index].0;
+    let mut num: f64 = format!("{}.{}", &digits[..split], &digits[split..])
+        .parse()
+        .map_err(|_| PyValueError::new_err(format!("cannot scale {}", text)))?;
+    let mut shown = format!("{:.prec$}", num, prec = precision);
+    if let Some(&(next, _)) = INTWORD_SCALES.get(index + 1) {
+        if next - INTWORD_SCALES[index].0 == 3 && shown.parse::<f64>().is_ok_and(|n| n >= 1000.0) {
+            index += 1;
+            num /= 1000.0;
+            shown = format!("{:.prec$}", num, prec = precision);
+        }
+    }
+
+    Ok(format!("{}{} {}", sign, shown, INTWORD_SCALES[index].1))
 }
 
-const SUFFIXES: &[&str] = &["Bytes", "KB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
-const BINARY_SUFFIXES: &[&str] = &["Bytes", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
+const SUFFIXES: &[&str] = &["kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB", "RB", "QB"];
+const BINARY_SUFFIXES: &[&str] = &["KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB", "RiB", "QiB"];
+const GNU_SUFFIXES: &[&str] = &["K", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
 
 /// Convert a file size to human readable form
 /// humanize.naturalsize(1048576) -> "1.0 MB"
+///
+/// Matches humanize: values below the base are whole bytes ("1 Byte",
+/// "999 Bytes", "1023B" for gnu), gnu sizes use a base of 1024, and the
+/// sign is kept on negative sizes ("-1.5 kB").
 #[pyfunction]
 #[pyo3(signature = (value, binary=false, gnu=false, format_str=None))]
-fn naturalsize(value: i64, binary: bool, gnu: bool, format_str: Option<&str>) -> String {
+fn naturalsize(value: f64, binary: bool, gnu: bool, format_str: Option<&str>) -> String {
     let fmt = format_str.unwrap_or("%.1f");
-    let base: f64 = if binary { 1024.0 } else { 1000.0 };
-    let suffixes = if binary { BINARY_SUFFIXES } else { SUFFIXES };
+    let base: f64 = if binary || gnu { 1024.0 } else { 1000.0 };
+    let suffixes = if gnu {
+        GNU_SUFFIXES
+    } else if binary {
+        BINARY_SUFFIXES
+    } else {
+        SUFFIXES
+    };
     
-    let abs_value = value.abs() as f64;
+    let abs_value = value.abs();
     
+    // Whole bytes are truncated toward zero like Python's "%d", so -0.0
+    // and fractional sizes never render as "-0". Only exactly one byte is
+    // singular: 1.5 is "1 Bytes", as in humanize
+    let whole_bytes = value.trunc() as i64;
     if abs_value < base {
         if gnu {
-            return format!("{}B", value);
+            return format!("{}B", whole_bytes);
+        }
+        if abs_value == 1.0 {
+            return format!("{} Byte", whole_bytes);
         }
-        return format!("{} Bytes", value);
+        return format!("{} Bytes", whole_bytes);
     }
     
+    // Pick the largest unit not exceeding the value, capped at the last suffix
     let mut unit_idx = 0;
-    let mut size = abs_value;
-    
-    while size >= base && unit_idx < suffixes.len() - 1 {
-        size /= base;
+    let mut unit = base;
+    while unit_idx < suffixes.len() - 1 && abs_value >= unit * base {
+        unit *= base;
         unit_idx += 1;
     }
     
-    if value < 0 {
-        size = -size;
-    }
+    let precision = format_precision(fmt);
+    let size = value / unit;
     
-    // Parse format string for precision
-    let precision = if fmt.contains('.') {
+    if gnu {
+        format!("{:.prec$}{}", size, suffixes[unit_idx], prec = precision)
+    } else {
+        format!("{:.prec$} {}", size, suffixes[unit_idx], prec = precision)
+    }
+}
+
+/// Precision of a printf-style float format such as "%.1f" (defaults to 1)
+fn format_precision(fmt: &str) -> usize {
+    if fmt.contains('.') {
         fmt.chars()
             .skip_while(|c| *c != '.')
             .skip(1)
@@ -113,18 +244,6 @@ fn naturalsize(value: i64, binary: bool, gnu: bool, format_str: Option<&str>) ->
             .unwrap_or(1)
     } else {
         1
-    };
-    
-    let suffix = if gnu {
-        &suffixes[unit_idx][..1] // Just the first letter for GNU style
-    } else {
-        suffixes[unit_idx]
-    };
-    
-    if gnu {
-        format!("{:.prec$}{}", size, suffix, prec = precision)
-    } else {
-        format!("{:.prec$} {}", size, suffix, prec = precision)
     }
 }
 
@@ -176,10 +295,12 @@ fn fractional(value: f64) -> String {
     }
 }
 
-/// Convert a boolean to "yes" or "no"
+/// Spell out 0 through 9 per the AP style guide; other values use digits
+/// humanize.apnumber(5) -> "five"
 #[pyfunction]
 fn apnumber(value: i64) -> String {
     match value {
+        0 => "zero".to_string(),
         1 => "one".to_string(),
         2 => "two".to_string(),
         3 => "three".to_string(),
@@ -193,14 +314,374 @@ fn apnumber(value: i64) -> String {
     }
 }
 
+/// `value` rounded to `sigfigs` significant figures, as its sign, digits
+/// and the power of ten of the first digit
+///
+/// 0.0004567 to 3 figures is (false, "457", -4). Rounding is done on the
+/// exact binary value, so 999.5 to 3 figures carries into ("100", 3).
+fn significant_digits(value: f64, sigfigs: usize) -> (bool, String, i32) {
+    let formatted = format!("{:.*e}", sigfigs.saturating_sub(1), value.abs());
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+    let digits = mantissa.replace('.', "");
+    (value.is_sign_negative() && value != 0.0, digits, exponent.parse().unwrap_or(0))
+}
+
+/// Place the decimal point in `digits` whose first digit is 10^`exponent`,
+/// keeping trailing zeros since they are significant
+fn positional(negative: bool, digits: &str, exponent: i32) -> String {
+    let sign = if negative { "-" } else { "" };
+    let n = digits.len() as i32;
+    if exponent >= n - 1 {
+        format!("{}{}{}", sign, digits, "0".repeat((exponent - n + 1) as usize))
+    } else if exponent >= 0 {
+        let (int_part, frac_part) = digits.split_at(exponent as usize + 1);
+        format!("{}{}.{}", sign, int_part, frac_part)
+    } else {
+        format!("{}0.{}{}", sign, "0".repeat((-exponent - 1) as usize), digits)
+    }
+}
+
+/// humanize's spelling of NaN and infinities
+fn format_not_finite(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value > 0.0 {
+        "+Inf".to_string()
+    } else {
+        "-Inf".to_string()
+    }
+}
+
+/// Format a number to a number of significant figures
+/// humanize.format_number(0.0004567, 3) -> "0.000457"
+#[pyfunction]
+#[pyo3(signature = (value, sigfigs=3))]
+fn format_number(value: f64, sigfigs: usize) -> PyResult<String> {
+    if sigfigs == 0 {
+        return Err(PyValueError::new_err("sigfigs must be at least 1"));
+    }
+    if !value.is_finite() {
+        return Ok(format_not_finite(value));
+    }
+    let (negative, digits, exponent) = significant_digits(value, sigfigs);
+    Ok(positional(negative, &digits, exponent))
+}
+
 /// Convert scientific notation to decimal
+/// humanize.scientific(500) -> "5.00 x 10²"
+///
+/// `precision` is the number of digits after the mantissa's decimal point.
+#[pyfunction]
+#[pyo3(signature = (value, precision=2))]
+fn scientific(value: f64, precision: usize) -> String {
+    if !value.is_finite() {
+        return format_not_finite(value);
+    }
+    let (negative, digits, exponent) = significant_digits(value, precision + 1);
+    let superscript: String = exponent
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            d => ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'][d as usize - '0' as usize],
+        })
+        .collect();
+    format!("{} x 10{}", positional(negative, &digits, 0), superscript)
+}
+
+/// Format a number with an SI prefix
+/// humanize.metric(1500, "V") -> "1.50 kV"
+///
+/// `precision` is the number of significant figures. Values outside the
+/// quecto..quetta range fall back to `scientific`.
+#[pyfunction]
+#[pyo3(signature = (value, unit="", precision=3))]
+fn metric(value: f64, unit: &str, precision: usize) -> PyResult<String> {
+    if precision == 0 {
+        return Err(PyValueError::new_err("precision must be at least 1"));
+    }
+    if !value.is_finite() {
+        return Ok(format_not_finite(value));
+    }
+
+    // Round first so that e.g. 999.9 becomes "1.00 k" rather than "1000"
+    let (negative, digits, exponent) = significant_digits(value, precision);
+    if !(-30..33).contains(&exponent) {
+        return Ok(format!("{}{}", scientific(value, precision - 1), unit));
+    }
+    let group = exponent.div_euclid(3);
+    let prefix = match group {
+        0 => "",
+        1.. => ["k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"][group as usize - 1],
+        _ => ["m", "μ", "n", "p", "f", "a", "z", "y", "r", "q"][(-group) as usize - 1],
+    };
+    let space = if (unit.is_empty() && prefix.is_empty()) || ["°", "′", "″"].contains(&unit) {
+        ""
+    } else {
+        " "
+    };
+    Ok(format!(
+        "{}{}{}{}",
+        positional(negative, &digits, exponent - group * 3),
+        space,
+        prefix,
+        unit
+    ))
+}
+
+/// `value` as a `timedelta`, treating numbers as seconds
+fn to_timedelta<'py>(py: Python<'py>, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    if value.hasattr("days")? {
+        return Ok(value.clone());
+    }
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item("seconds", value)?;
+    py.import_bound("datetime")?
+        .getattr("timedelta")?
+        .call((), Some(&kwargs))
+}
+
+/// Describe an absolute timedelta the way humanize's `naturaldelta` does:
+/// seconds up to 59, then "a minute" up to 119 seconds, minutes up to an
+/// hour, "an hour" up to two, then hours, days, 30.5-day months and
+/// 365-day years
+fn describe_delta(days: i64, seconds: i64, months: bool) -> String {
+    let years = days / 365;
+    let days = days % 365;
+    let num_months = (days as f64 / 30.5) as i64;
+
+    match years {
+        0 if days == 0 => match seconds {
+            0 => "a moment".to_string(),
+            1 => "a second".to_string(),
+            2..=59 => format!("{} seconds", seconds),
+            60..=119 => "a minute".to_string(),
+            120..=3599 => format!("{} minutes", seconds / 60),
+            3600..=7199 => "an hour".to_string(),
+            _ => format!("{} hours", seconds / 3600),
+        },
+        0 => match num_months {
+            _ if days == 1 => "a day".to_string(),
+            0 => format!("{} days", days),
+            _ if !months => format!("{} days", days),
+            1 => "a month".to_string(),
+            _ => format!("{} months", num_months),
+        },
+        1 => match num_months {
+            _ if days == 0 => "a year".to_string(),
+            0 if days == 1 => "1 year, 1 day".to_string(),
+            _ if num_months == 0 || !months => format!("1 year, {} days", days),
+            1 => "1 year, 1 month".to_string(),
+            _ => format!("1 year, {} months", num_months),
+        },
+        _ => format!("{} years", years.to_formatted_string(&Locale::en)),
+    }
+}
+
+/// Describe a timedelta (or seconds) without tense
+/// humanize.naturaldelta(timedelta(minutes=90)) -> "an hour"
+#[pyfunction]
+#[pyo3(signature = (value, months=true))]
+fn naturaldelta(py: Python<'_>, value: &Bound<'_, PyAny>, months: bool) -> PyResult<String> {
+    let delta = to_timedelta(py, value)?.call_method0("__abs__")?;
+    let days: i64 = delta.getattr("days")?.extract()?;
+    let seconds: i64 = delta.getattr("seconds")?.extract()?;
+    Ok(describe_delta(days, seconds, months))
+}
+
+/// Describe a time relative to now, e.g. "an hour ago" or "in 3 days"
+///
+/// `value` may be a `datetime` (compared with `when`, default now), a
+/// `timedelta`, or a number of seconds. Positive deltas are in the past and
+/// negative ones in the future; `future=True` puts a number of seconds in
+/// the future regardless of sign.
+#[pyfunction]
+#[pyo3(signature = (value, future=false, months=true, when=None))]
+fn naturaltime(
+    py: Python<'_>,
+    value: &Bound<'_, PyAny>,
+    future: bool,
+    months: bool,
+    when: Option<&Bound<'_, PyAny>>,
+) -> PyResult<String> {
+    let datetime = py.import_bound("datetime")?;
+    let (delta, is_future) = if value.is_instance(&datetime.getattr("datetime")?)? {
+        let now = match when {
+            Some(when) => when.clone(),
+            None => datetime
+                .getattr("datetime")?
+                .call_method1("now", (value.getattr("tzinfo")?,))?,
+        };
+        let delta = now.call_method1("__sub__", (value,))?;
+        let is_future = delta.getattr("days")?.extract::<i64>()? < 0;
+        (delta, is_future)
+    } else if value.is_instance(&datetime.getattr("timedelta")?)? {
+        (value.clone(), value.getattr("days")?.extract::<i64>()? < 0)
+    } else if let Ok(seconds) = value.extract::<f64>() {
+        (to_timedelta(py, value)?, future || seconds < 0.0)
+    } else {
+        return Ok(value.str()?.to_string());
+    };
+
+    let text = naturaldelta(py, &delta, months)?;
+    Ok(match text.as_str() {
+        "a moment" => "now".to_string(),
+        _ if is_future => format!("in {}", text),
+        _ => format!("{} ago", text),
+    })
+}
+
+/// Units for `precisedelta`, smallest first
+const DELTA_UNITS: [(&str, &str); 8] = [
+    ("microsecond", "microseconds"),
+    ("millisecond", "milliseconds"),
+    ("second", "seconds"),
+    ("minute", "minutes"),
+    ("hour", "hours"),
+    ("day", "days"),
+    ("month", "months"),
+    ("year", "years"),
+];
+
+/// How many of each unit make up the next larger one; a month is 30.5
+/// days, so days never carry into months
+const DELTA_UNIT_SIZES: [Option<f64>; 7] = [
+    Some(1000.0),
+    Some(1000.0),
+    Some(60.0),
+    Some(60.0),
+    Some(24.0),
+    None,
+    Some(12.0),
+];
+
+fn delta_unit(name: &str) -> PyResult<usize> {
+    let name = name.to_lowercase();
+    DELTA_UNITS
+        .iter()
+        .position(|(_, plural)| *plural == name)
+        .ok_or_else(|| PyValueError::new_err(format!("Invalid unit: '{}'", name)))
+}
+
+/// Next unit above `unit` that isn't suppressed, with how many `unit`s it
+/// holds; None when there is none or the chain passes through days->months
+fn next_retained_unit(unit: usize, suppressed: &[bool; 8]) -> Option<(usize, f64)> {
+    let mut factor = 1.0;
+    for larger in unit + 1..DELTA_UNITS.len() {
+        factor *= DELTA_UNIT_SIZES[larger - 1]?;
+        if !suppressed[larger] {
+            return Some((larger, factor));
+        }
+    }
+    None
+}
+
+/// Describe a timedelta (or seconds) precisely, e.g.
+/// humanize.precisedelta(timedelta(days=1, hours=2)) -> "1 day and 2 hours"
+///
+/// Units below `minimum_unit` are folded into it as a fraction rendered
+/// with `format`. Suppressed units are skipped and their quantity carries
+/// into the next smaller retained unit, so suppressing "days" gives hours
+/// beyond 24. If `minimum_unit` is suppressed, the next larger retained
+/// unit is used instead.
 #[pyfunction]
-fn scientific(value: f64, precision: Option<usize>) -> String {
-    let prec = precision.unwrap_or(2);
-    format!("{:.prec$e}", value, prec = prec)
-        .replace("e", " x 10^")
-        .replace("x 10^0", "")
-        .replace("x 10^+", "x 10^")
+#[pyo3(signature = (value, minimum_unit="seconds", suppress=None, format="%0.2f"))]
+fn precisedelta(
+    py: Python<'_>,
+    value: &Bound<'_, PyAny>,
+    minimum_unit: &str,
+    suppress: Option<Vec<String>>,
+    format: &str,
+) -> PyResult<String> {
+    let delta = to_timedelta(py, value)?.call_method0("__abs__")?;
+    let days: i64 = delta.getattr("days")?.extract()?;
+    let seconds: i64 = delta.getattr("seconds")?.extract()?;
+    let microseconds: i64 = delta.getattr("microseconds")?.extract()?;
+
+    let mut suppressed = [false; 8];
+    for name in suppress.unwrap_or_default() {
+        suppressed[delta_unit(&name)?] = true;
+    }
+    let mut min_unit = delta_unit(minimum_unit)?;
+    if suppressed[min_unit] {
+        min_unit = (min_unit + 1..DELTA_UNITS.len())
+            .find(|&unit| !suppressed[unit])
+            .ok_or_else(|| {
+                PyValueError::new_err("Minimum unit is suppressed and no suitable replacement was found")
+            })?;
+    }
+    for hidden in suppressed.iter_mut().take(min_unit) {
+        *hidden = true;
+    }
+
+    // Split `value` into whole `unit`s and a remainder in the unit below;
+    // the minimum unit keeps the fraction and suppressed units pass it all on
+    let split = |value: f64, divisor: f64, unit: usize| -> (f64, f64) {
+        if unit == min_unit {
+            (value / divisor, 0.0)
+        } else if suppressed[unit] {
+            (0.0, value)
+        } else {
+            let quotient = (value / divisor).floor();
+            (quotient, value - quotient * divisor)
+        }
+    };
+
+    let mut values = [0.0; 8];
+    let (years, rest) = split(days as f64, 365.0, 7);
+    let (months, rest) = split(rest, 30.5, 6);
+    let (day_count, rest) = split(rest * 86400.0 + seconds as f64, 86400.0, 5);
+    let (hours, rest) = split(rest, 3600.0, 4);
+    let (minutes, rest) = split(rest, 60.0, 3);
+    let (secs, rest) = split(rest * 1e6 + microseconds as f64, 1e6, 2);
+    let (millis, micros) = split(rest, 1000.0, 1);
+    values[7] = years;
+    values[6] = months;
+    values[5] = day_count;
+    values[4] = hours;
+    values[3] = minutes;
+    values[2] = secs;
+    values[1] = millis;
+    values[0] = split(micros, 1.0, 0).0;
+
+    // Rounding the minimum unit can fill up the next retained unit
+    // ("60 seconds"); carry it, and keep carrying while units overflow
+    let precision = format_precision(format);
+    let scale = 10_f64.powi(precision as i32);
+    let mut unit = min_unit;
+    let mut rounded = (values[unit] * scale).round() / scale;
+    while let Some((larger, factor)) = next_retained_unit(unit, &suppressed) {
+        if rounded < factor {
+            break;
+        }
+        values[unit] = rounded - factor;
+        values[larger] += 1.0;
+        unit = larger;
+        rounded = values[unit];
+    }
+
+    let mut texts = Vec::new();
+    for unit in (min_unit..DELTA_UNITS.len()).rev() {
+        let value = values[unit];
+        if value > 0.0 || (texts.is_empty() && unit == min_unit) {
+            let count = if value > 1.0 && value < 2.0 { 2 } else { value as i64 };
+            let (singular, plural) = DELTA_UNITS[unit];
+            let name = if count == 1 { singular } else { plural };
+            let text = if unit == min_unit && value.fract() > 0.0 {
+                format!("{:.prec$} {}", value, name, prec = precision)
+            } else if unit == DELTA_UNITS.len() - 1 {
+                format!("{} {}", (value as i64).to_formatted_string(&Locale::en), name)
+            } else {
+                format!("{} {}", value as i64, name)
+            };
+            texts.push(text);
+        }
+    }
+
+    Ok(match texts.split_last() {
+        Some((last, head)) if !head.is_empty() => format!("{} and {}", head.join(", "), last),
+        _ => texts.concat(),
+    })
 }
 
 /// A Python module implemented in Rust
@@ -213,5 +694,10 @@ fn humanize_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(fractional, m)?)?;
     m.add_function(wrap_pyfunction!(apnumber, m)?)?;
     m.add_function(wrap_pyfunction!(scientific, m)?)?;
+    m.add_function(wrap_pyfunction!(metric, m)?)?;
+    m.add_function(wrap_pyfunction!(format_number, m)?)?;
+    m.add_function(wrap_pyfunction!(precisedelta, m)?)?;
+    m.add_function(wrap_pyfunction!(naturaldelta, m)?)?;
+    m.add_function(wrap_pyfunction!(naturaltime, m)?)?;
     Ok(())
 }
@@ -0,0 +1,330 @@
+"""
+Tests for humanize-rs
+
+Verifies API compatibility with the humanize package
+"""
+
+from datetime import datetime, timedelta
+
+import pytest
+
+try:
+    from humanize_rs import (
+        apnumber,
+        format_number,
+        intcomma,
+        intword,
+        metric,
+        naturaldelta,
+        naturalsize,
+        naturaltime,
+        ordinal,
+        precisedelta,
+        scientific,
+    )
+except ImportError:
+    pytest.skip("humanize_rs not available", allow_module_level=True)
+
+
+class TestSignificantFigures:
+    """Shared significant-figure rounding in format_number, scientific and metric"""
+
+    def test_orders_of_magnitude(self):
+        assert format_number(0.0004567, 3) == "0.000457"
+        assert format_number(1234.5, 3) == "1230"
+        assert format_number(1.0, 3) == "1.00"
+        assert format_number(1e20, 2) == "100000000000000000000"
+        assert format_number(2.5e-7, 3) == "0.000000250"
+
+    def test_negatives(self):
+        assert format_number(-98765.4, 3) == "-98800"
+        assert format_number(-0.0012345, 2) == "-0.0012"
+
+    def test_rounding_boundaries(self):
+        # Exact binary ties round half to even, as Python's format() does
+        assert format_number(0.125, 2) == "0.12"
+        assert format_number(0.375, 2) == "0.38"
+        assert format_number(999.5, 3) == "1000"
+        assert format_number(9.995, 3) == "9.99"  # 9.995 is just below the tie in binary
+
+    def test_invalid_sigfigs(self):
+        with pytest.raises(ValueError):
+            format_number(1.0, 0)
+
+    def test_scientific(self):
+        assert scientific(500) == "5.00 x 10²"
+        assert scientific(0.0004567) == "4.57 x 10⁻⁴"
+        assert scientific(-12345, 1) == "-1.2 x 10⁴"
+        assert scientific(1.5) == "1.50 x 10⁰"
+        assert scientific(9.999, 2) == "1.00 x 10¹"
+
+    def test_metric(self):
+        assert metric(1500, "V") == "1.50 kV"
+        assert metric(220e-6, "F") == "220 μF"
+        assert metric(2.2) == "2.20"
+        assert metric(-0.0004567, "A") == "-457 μA"
+        assert metric(45, "°") == "45.0°"
+
+    def test_metric_rounds_before_choosing_prefix(self):
+        assert metric(999.9, "V") == "1.00 kV"
+
+    def test_not_finite(self):
+        assert format_number(float("nan")) == "NaN"
+        assert metric(float("inf")) == "+Inf"
+        assert scientific(float("-inf")) == "-Inf"
+
+
+class TestIntcommaLocale:
+    """Locale and separator selection for intcomma"""
+
+    def test_default(self):
+        assert intcomma(1000000) == "1,000,000"
+        assert intcomma(-1234) == "-1,234"
+
+    def test_locales(self):
+        assert intcomma(1000000, locale="de_DE") == "1.000.000"
+        assert intcomma(1000000, locale="de") == "1.000.000"
+        assert intcomma(1000000, locale="fr_FR") == "1\u202f000\u202f000"
+        assert intcomma(1234567, locale="en_IN") == "12,34,567"
+
+    def test_separator(self):
+        assert intcomma(1000000, separator=" ") == "1 000 000"
+        assert intcomma(1000000, locale="de_DE", separator="'") == "1'000'000"
+
+    def test_unknown_locale(self):
+        assert intcomma(1000000, locale="xx_XX", separator="_") == "1_000_000"
+        with pytest.raises(ValueError):
+            intcomma(1000000, locale="xx_XX")
+
+
+class TestLargeNumbers:
+    """intword and ordinal past the range of i64"""
+
+    def test_intword_scales(self):
+        assert intword(999_999) == "999,999"
+        assert intword(1_200_000) == "1.2 million"
+        assert intword(-1_234_567) == "-1.2 million"
+        assert intword(10**100) == "1.0 googol"
+
+    def test_quadrillion_boundary(self):
+        assert intword(10**15) == "1.0 quadrillion"
+        assert intword(10**15 - 1) == "1.0 quadrillion"
+        assert intword(999 * 10**15) == "999.0 quadrillion"
+
+    def test_quintillion_boundary(self):
+        assert intword(10**18) == "1.0 quintillion"
+        assert intword(10**18 - 1) == "1.0 quintillion"
+        assert intword(1_500_000_000_000_000_000) == "1.5 quintillion"
+
+    def test_beyond_i64(self):
+        assert intword(2**64) == "18.4 quintillion"
+        assert intword(1234 * 10**18) == "1.2 sextillion"
+        assert intword(5 * 10**33) == "5.0 decillion"
+
+    def test_strings_and_format(self):
+        assert intword("12000000") == "12.0 million"
+        assert intword(str(10**21)) == "1.0 sextillion"
+        assert intword(123456789, "%.3f") == "123.457 million"
+        assert intword("not a number") == "not a number"
+
+    def test_ordinal_large(self):
+        assert ordinal(2**64) == "18446744073709551616th"
+        assert ordinal(10**20 + 1) == "100000000000000000001st"
+        assert ordinal(10**20 + 12) == "100000000000000000012th"
+        assert ordinal("22") == "22nd"
+        assert ordinal(3, gender="female") == "3rd"
+
+
+class TestPrecisedelta:
+    """precisedelta units, suppression and carrying"""
+
+    DELTA = timedelta(days=2, hours=3, seconds=5, microseconds=2000)
+
+    def test_default(self):
+        assert precisedelta(self.DELTA) == "2 days, 3 hours and 5.00 seconds"
+
+    def test_minimum_unit(self):
+        assert precisedelta(self.DELTA, minimum_unit="microseconds") == (
+            "2 days, 3 hours, 5 seconds and 2 milliseconds"
+        )
+        assert precisedelta(timedelta(hours=2, minutes=30), minimum_unit="hours") == "2.50 hours"
+
+    def test_format(self):
+        assert precisedelta(timedelta(seconds=5.25), format="%0.1f") == "5.2 seconds"
+
+    def test_suppress_days(self):
+        assert precisedelta(self.DELTA, suppress=["days"]) == "51 hours and 5.00 seconds"
+
+    def test_suppress_multiple_units(self):
+        assert precisedelta(self.DELTA, suppress=["days", "hours"]) == (
+            "3060 minutes and 5.00 seconds"
+        )
+        assert precisedelta(
+            self.DELTA, minimum_unit="microseconds", suppress=["seconds", "milliseconds"]
+        ) == "2 days, 3 hours and 5002000 microseconds"
+
+    def test_suppressed_minimum_unit(self):
+        assert precisedelta(timedelta(hours=1, minutes=30), suppress=["seconds"]) == (
+            "1 hour and 30 minutes"
+        )
+        with pytest.raises(ValueError):
+            precisedelta(1, minimum_unit="years", suppress=["years"])
+
+    def test_rounding_carries(self):
+        assert precisedelta(timedelta(seconds=59.999), format="%0.0f") == "1 minute"
+        assert precisedelta(timedelta(minutes=59, seconds=59.9), format="%0.0f") == "1 hour"
+
+    def test_rounding_carries_across_suppressed_unit(self):
+        delta = timedelta(minutes=59, seconds=59.9)
+        assert precisedelta(delta, format="%0.0f", suppress=["minutes"]) == "1 hour"
+
+    def test_years_and_months(self):
+        assert precisedelta(timedelta(days=400, hours=12), minimum_unit="days") == (
+            "1 year, 1 month and 5 days"
+        )
+
+    def test_seconds_and_negative(self):
+        assert precisedelta(0) == "0 seconds"
+        assert precisedelta(3600) == "1 hour"
+        assert precisedelta(-90.5) == "1 minute and 30.50 seconds"
+
+    def test_invalid_unit(self):
+        with pytest.raises(ValueError):
+            precisedelta(1, minimum_unit="fortnights")
+
+
+class TestNaturaltime:
+    """naturaltime tense, units and boundaries"""
+
+    NOW = datetime(2024, 1, 1, 12, 0, 0)
+
+    def test_boundaries(self):
+        assert naturaltime(0) == "now"
+        assert naturaltime(1) == "a second ago"
+        assert naturaltime(59) == "59 seconds ago"
+        assert naturaltime(60) == "a minute ago"
+        assert naturaltime(89) == "a minute ago"
+        assert naturaltime(119) == "a minute ago"
+        assert naturaltime(120) == "2 minutes ago"
+        assert naturaltime(3599) == "59 minutes ago"
+        assert naturaltime(3600) == "an hour ago"
+        assert naturaltime(7200) == "2 hours ago"
+        assert naturaltime(86400) == "a day ago"
+        assert naturaltime(86400 * 2) == "2 days ago"
+
+    def test_months_and_years(self):
+        assert naturaltime(timedelta(days=31)) == "a month ago"
+        assert naturaltime(timedelta(days=364)) == "11 months ago"
+        assert naturaltime(timedelta(days=365)) == "a year ago"
+        assert naturaltime(timedelta(days=366)) == "1 year, 1 day ago"
+        assert naturaltime(timedelta(days=400)) == "1 year, 1 month ago"
+        assert naturaltime(timedelta(days=365 * 3)) == "3 years ago"
+
+    def test_future_symmetry(self):
+        assert naturaltime(-1) == "in a second"
+        assert naturaltime(-3600) == "in an hour"
+        assert naturaltime(timedelta(days=-1)) == "in a day"
+        assert naturaltime(30, future=True) == "in 30 seconds"
+
+    def test_datetime_with_when(self):
+        assert naturaltime(datetime(2024, 1, 1, 11), when=self.NOW) == "an hour ago"
+        assert naturaltime(datetime(2024, 1, 1, 13), when=self.NOW) == "in an hour"
+        assert naturaltime(self.NOW, when=self.NOW) == "now"
+
+    def test_non_numeric_returned_as_str(self):
+        assert naturaltime("soon") == "soon"
+
+    def test_naturaldelta(self):
+        assert naturaldelta(timedelta(minutes=90)) == "an hour"
+        assert naturaldelta(timedelta(days=45), months=False) == "45 days"
+
+
+class TestNaturalsize:
+    """Byte-size boundaries match humanize.naturalsize exactly"""
+
+    def test_zero(self):
+        assert naturalsize(0) == "0 Bytes"
+        assert naturalsize(-0.0) == "0 Bytes"
+        assert naturalsize(0, binary=True) == "0 Bytes"
+        assert naturalsize(0, gnu=True) == "0B"
+
+    def test_one_byte(self):
+        assert naturalsize(1) == "1 Byte"
+        assert naturalsize(-1) == "-1 Byte"
+        assert naturalsize(1, binary=True) == "1 Byte"
+        assert naturalsize(1, gnu=True) == "1B"
+        assert naturalsize(-1, gnu=True) == "-1B"
+
+    def test_only_exactly_one_is_singular(self):
+        assert naturalsize(1.5) == "1 Bytes"
+        assert naturalsize(0.5) == "0 Bytes"
+        assert naturalsize(-1.5) == "-1 Bytes"
+
+    def test_decimal_boundary(self):
+        assert naturalsize(999) == "999 Bytes"
+        assert naturalsize(1000) == "1.0 kB"
+        assert naturalsize(-999) == "-999 Bytes"
+        assert naturalsize(-1000) == "-1.0 kB"
+        assert naturalsize(999_999) == "1000.0 kB"
+        assert naturalsize(1_000_000) == "1.0 MB"
+
+    def test_binary_boundary(self):
+        assert naturalsize(1023, binary=True) == "1023 Bytes"
+        assert naturalsize(1024, binary=True) == "1.0 KiB"
+        assert naturalsize(-1024, binary=True) == "-1.0 KiB"
+        assert naturalsize(1024**2, binary=True) == "1.0 MiB"
+
+    def test_gnu_boundary(self):
+        assert naturalsize(1023, gnu=True) == "1023B"
+        assert naturalsize(1024, gnu=True) == "1.0K"
+        assert naturalsize(-1536, gnu=True) == "-1.5K"
+        assert naturalsize(1024**3, gnu=True) == "1.0G"
+
+    def test_negative(self):
+        assert naturalsize(-1500) == "-1.5 kB"
+        assert naturalsize(-1500, format_str="%.3f") == "-1.500 kB"
+
+
+class TestOrdinalWords:
+    """Spelled-out ordinals up to limit, numeric ordinals past it"""
+
+    def test_words(self):
+        assert ordinal(1, words=True) == "first"
+        assert ordinal(2, words=True) == "second"
+        assert ordinal(12, words=True) == "twelfth"
+        assert ordinal(22, words=True) == "twenty-second"
+        assert ordinal(31, words=True) == "thirty-first"
+
+    def test_past_table(self):
+        assert ordinal(32, words=True) == "32nd"
+        assert ordinal(32, words=True, limit=100) == "32nd"
+        assert ordinal(111, words=True, limit=200) == "111th"
+
+    def test_limit_below_table(self):
+        assert ordinal(10, words=True, limit=10) == "tenth"
+        assert ordinal(11, words=True, limit=10) == "11th"
+        assert ordinal(1, words=True, limit=0) == "1st"
+
+    def test_zero_and_negative(self):
+        assert ordinal(0, words=True) == "0th"
+        assert ordinal(-1, words=True) == "-1th"
+        assert ordinal(-2) == "-2th"
+        assert ordinal(-11) == "-11th"
+
+    def test_words_off(self):
+        assert ordinal(1) == "1st"
+        assert ordinal(31) == "31st"
+
+
+class TestApnumber:
+    """AP style: zero through nine spelled out, digits otherwise"""
+
+    def test_spelled_out(self):
+        assert apnumber(0) == "zero"
+        assert apnumber(1) == "one"
+        assert apnumber(9) == "nine"
+
+    def test_digits(self):
+        assert apnumber(10) == "10"
+        assert apnumber(-1) == "-1"
+        assert apnumber(-9) == "-9"
@@ -1,7 +1,17 @@
 use pyo3::prelude::*;
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::types::{PyDict, PyList, PyString};
 use serde_json::Value;
-use jsonschema::JSONSchema;
+use jsonschema::paths::{JSONPointer, PathChunk};
+use jsonschema::{Draft, JSONSchema};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Mutex;
+
+/// Keywords whose errors only summarize failures of their subschemas
+const WEAK_KEYWORDS: &[&str] = &["anyOf", "oneOf"];
+
+/// Message formatters added with `register_message`, by keyword
+static MESSAGE_FORMATTERS: Mutex<BTreeMap<String, PyObject>> = Mutex::new(BTreeMap::new());
 
 /// Convert Python object to serde_json::Value
 fn python_to_json(py: Python, obj: &PyAny) -> PyResult<Value> {
@@ -12,18 +22,528 @@ fn python_to_json(py: Python, obj: &PyAny) -> PyResult<Value> {
         .map_err(|e| PyValueError::new_err(format!("JSON conversion error: {}", e)))
 }
 
+/// Convert serde_json::Value back to a Python object
+fn json_to_python(py: Python, value: &Value) -> PyObject {
+    match value {
+        Value::Null => py.None(),
+        Value::Bool(b) => b.to_object(py),
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => i.to_object(py),
+            (None, Some(u)) => u.to_object(py),
+            _ => n.as_f64().unwrap_or(f64::NAN).to_object(py),
+        },
+        Value::String(s) => s.to_object(py),
+        Value::Array(items) => {
+            let items: Vec<PyObject> = items.iter().map(|item| json_to_python(py, item)).collect();
+            PyList::new(py, items).to_object(py)
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, item) in map {
+                // Keys are strings, so insertion cannot fail
+                let _ = dict.set_item(key, json_to_python(py, item));
+            }
+            dict.to_object(py)
+        }
+    }
+}
+
+/// The draft named by a schema's `$schema`, with or without the trailing `#`
+///
+/// The library only recognizes the 2019-09 and 2020-12 URLs with a `#`,
+/// while the specs publish them without one; `None` leaves detection to
+/// the library (Draft 7 for unknown or missing URLs).
+fn schema_draft(schema_json: &Value) -> Option<Draft> {
+    let url = schema_json.get("$schema")?.as_str()?;
+    let url = url.strip_suffix('#').unwrap_or(url);
+    match url.strip_prefix("http://").or_else(|| url.strip_prefix("https://"))? {
+        "json-schema.org/draft/2020-12/schema" => Some(Draft::Draft202012),
+        "json-schema.org/draft/2019-09/schema" => Some(Draft::Draft201909),
+        "json-schema.org/draft-07/schema" => Some(Draft::Draft7),
+        "json-schema.org/draft-06/schema" => Some(Draft::Draft6),
+        "json-schema.org/draft-04/schema" => Some(Draft::Draft4),
+        _ => None,
+    }
+}
+
+/// Compile a schema, applying the `format_checker` setting
+///
+/// `None` keeps the library default (formats asserted for drafts 4-7).
+/// `True` forces format assertion and registers the checkers below, so
+/// `date`, `time`, `date-time`, `duration` and `uuid` are checked the same
+/// way on every draft; `regex` uses the library's ECMA 262 check.
+/// `False` treats `format` as an annotation only.
+fn compile_schema(schema_json: &Value, format_checker: Option<bool>) -> PyResult<JSONSchema> {
+    let mut options = JSONSchema::options();
+    if let Some(draft) = schema_draft(schema_json) {
+        options.with_draft(draft);
+    }
+    if let Some(enabled) = format_checker {
+        options.should_validate_formats(enabled);
+    }
+    if format_checker == Some(true) {
+        options
+            .with_format("date", is_date)
+            .with_format("time", is_time)
+            .with_format("date-time", is_date_time)
+            .with_format("duration", is_duration)
+            .with_format("uuid", is_uuid);
+    }
+    options
+        .compile(schema_json)
+        .map_err(|e| PyValueError::new_err(format!("Schema compilation error: {}", e)))
+}
+
+/// Parse `len` ASCII digits starting at `at`
+fn digits_at(value: &str, at: usize, len: usize) -> Option<u32> {
+    let digits = value.get(at..at + len)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// RFC 3339 `full-date`: `YYYY-MM-DD` with the day checked against the month
+fn is_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+    match (digits_at(value, 0, 4), digits_at(value, 5, 2), digits_at(value, 8, 2)) {
+        (Some(year), Some(month @ 1..=12), Some(day)) => {
+            day >= 1 && day <= days_in_month(year as i32, month)
+        }
+        _ => false,
+    }
+}
+
+/// RFC 3339 `full-time`: `HH:MM:SS[.fraction]` followed by `Z` or `+HH:MM`
+fn is_time(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() < 9 || bytes[2] != b':' || bytes[5] != b':' {
+        return false;
+    }
+    let valid_clock = matches!(
+        (digits_at(value, 0, 2), digits_at(value, 3, 2), digits_at(value, 6, 2)),
+        (Some(0..=23), Some(0..=59), Some(0..=60))
+    );
+    if !valid_clock {
+        return false;
+    }
+
+    let mut rest = &value[8..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return false;
+        }
+        rest = &fraction[digits..];
+    }
+    match rest {
+        "Z" | "z" => true,
+        offset if offset.len() == 6 && (offset.starts_with('+') || offset.starts_with('-')) => {
+            offset.as_bytes()[3] == b':'
+                && matches!(
+                    (digits_at(offset, 1, 2), digits_at(offset, 4, 2)),
+                    (Some(0..=23), Some(0..=59))
+                )
+        }
+        _ => false,
+    }
+}
+
+/// RFC 3339 `date-time`: a full date and full time joined by `T`
+fn is_date_time(value: &str) -> bool {
+    match value.find(['T', 't']) {
+        Some(at) => is_date(&value[..at]) && is_time(&value[at + 1..]),
+        None => false,
+    }
+}
+
+/// Count `<digits><unit>` components in `value`, requiring each unit at most
+/// once and in the order given
+fn duration_components(value: &str, units: &[char]) -> Option<usize> {
+    let (mut rest, mut next_unit, mut count) = (value, 0, 0);
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return None;
+        }
+        let unit = rest[digits..].chars().next()?;
+        let position = units[next_unit..].iter().position(|&u| u == unit)?;
+        next_unit += position + 1;
+        count += 1;
+        rest = &rest[digits + unit.len_utf8()..];
+    }
+    Some(count)
+}
+
+/// ISO 8601 duration per RFC 3339 appendix A, e.g. "P1Y2M10DT2H30M" or
+/// "P4W". At least one component is required and fractions aren't allowed.
+fn is_duration(value: &str) -> bool {
+    let Some(body) = value.strip_prefix('P') else {
+        return false;
+    };
+    if let Some(weeks) = body.strip_suffix('W') {
+        return !weeks.is_empty() && weeks.bytes().all(|b| b.is_ascii_digit());
+    }
+    let (date, time) = match body.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (body, None),
+    };
+    let Some(date_count) = duration_components(date, &['Y', 'M', 'D']) else {
+        return false;
+    };
+    match time {
+        Some(time) => matches!(duration_components(time, &['H', 'M', 'S']), Some(n) if n > 0),
+        None => date_count > 0,
+    }
+}
+
+/// UUID in 8-4-4-4-12 hex form
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// A single validation failure with its location in the instance and schema
+#[pyclass]
+#[derive(Clone)]
+struct ValidationError {
+    #[pyo3(get)]
+    message: String,
+    /// JSON pointer to the failing value, e.g. "/users/0/email"
+    #[pyo3(get)]
+    instance_path: String,
+    /// JSON pointer to the failing keyword, e.g. "/properties/users/items/type"
+    #[pyo3(get)]
+    schema_path: String,
+    /// Keyword that rejected the value, e.g. "type" or "required"
+    #[pyo3(get)]
+    keyword: String,
+    path: Vec<PathChunk>,
+    instance: Value,
+    schema: Option<Value>,
+}
+
+#[pymethods]
+impl ValidationError {
+    /// Path to the failing value as a list of keys and indices
+    #[getter]
+    fn path(&self, py: Python) -> PyObject {
+        let items: Vec<PyObject> = self.path.iter().map(|chunk| match chunk {
+            PathChunk::Property(name) => name.to_object(py),
+            PathChunk::Index(idx) => idx.to_object(py),
+            PathChunk::Keyword(keyword) => keyword.to_object(py),
+        }).collect();
+        PyList::new(py, items).to_object(py)
+    }
+
+    /// The failing value, found at `instance_path`
+    #[getter]
+    fn instance(&self, py: Python) -> PyObject {
+        json_to_python(py, &self.instance)
+    }
+
+    /// The subschema holding the failing keyword, or None if `schema_path`
+    /// leads through a `$ref` that cannot be followed in the root schema
+    #[getter]
+    fn schema(&self, py: Python) -> PyObject {
+        match &self.schema {
+            Some(schema) => json_to_python(py, schema),
+            None => py.None(),
+        }
+    }
+
+    /// JSON-serializable `{"path", "message", "keyword"}` summary
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("path", self.path(py))?;
+        dict.set_item("message", &self.message)?;
+        dict.set_item("keyword", &self.keyword)?;
+        Ok(dict.to_object(py))
+    }
+
+    fn __str__(&self) -> String {
+        self.message.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<ValidationError: {:?} at {:?}>", self.message, self.instance_path)
+    }
+}
+
+impl ValidationError {
+    fn from_error(error: jsonschema::ValidationError, root_schema: &Value) -> Self {
+        let keyword = error.schema_path.iter().rev().find_map(|chunk| match chunk {
+            PathChunk::Keyword(keyword) => Some(keyword.to_string()),
+            _ => None,
+        }).unwrap_or_default();
+
+        ValidationError {
+            message: error.to_string(),
+            instance_path: error.instance_path.to_string(),
+            schema_path: error.schema_path.to_string(),
+            keyword,
+            path: pointer_chunks(&error.instance_path),
+            schema: schema_fragment(root_schema, &error.schema_path).cloned(),
+            instance: error.instance.into_owned(),
+        }
+    }
+
+    /// Reword the message with the formatter registered for this keyword,
+    /// if there is one
+    fn localized(mut self, py: Python) -> PyResult<Self> {
+        // Release the lock before calling back into Python
+        let formatter = match MESSAGE_FORMATTERS.lock().unwrap().get(&self.keyword) {
+            Some(formatter) => formatter.clone_ref(py),
+            None => return Ok(self),
+        };
+        let instance = json_to_python(py, &self.instance);
+        let schema_value = match self.schema.as_ref().and_then(|schema| schema.get(&self.keyword)) {
+            Some(value) => json_to_python(py, value),
+            None => py.None(),
+        };
+
+        let formatter = formatter.as_ref(py);
+        let message = if formatter.is_instance_of::<PyString>() {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("keyword", &self.keyword)?;
+            kwargs.set_item("instance", instance)?;
+            kwargs.set_item("schema", schema_value)?;
+            kwargs.set_item("message", &self.message)?;
+            formatter.call_method("format", (), Some(kwargs))?
+        } else {
+            formatter.call1((&self.keyword, instance, schema_value))?
+        };
+        self.message = message.str()?.to_string();
+        Ok(self)
+    }
+
+    /// Default relevance: deeper errors first, then errors from keywords
+    /// other than `anyOf`/`oneOf`. Lower sorts first.
+    fn relevance_key(&self) -> (isize, bool) {
+        (-(self.path.len() as isize), WEAK_KEYWORDS.contains(&self.keyword.as_str()))
+    }
+}
+
+fn pointer_chunks(pointer: &JSONPointer) -> Vec<PathChunk> {
+    pointer.iter().cloned().collect()
+}
+
+/// Walk `schema` along a schema path, stopping at the subschema that holds
+/// the final keyword (e.g. "/items/type" resolves to the "items" subschema)
+fn schema_fragment<'a>(schema: &'a Value, schema_path: &JSONPointer) -> Option<&'a Value> {
+    let chunks: Vec<&PathChunk> = schema_path.iter().collect();
+    let chunks = match chunks.split_last() {
+        Some((PathChunk::Keyword(_), parents)) => parents,
+        _ => &chunks[..],
+    };
+
+    chunks.iter().try_fold(schema, |node, chunk| match chunk {
+        PathChunk::Property(name) => node.get(name.as_ref()),
+        PathChunk::Index(idx) => node.get(*idx),
+        PathChunk::Keyword(keyword) => node.get(*keyword),
+    })
+}
+
+/// Collect all validation errors, most relevant first
+///
+/// `relevance` is a key function applied to each error (lower sorts first);
+/// with `deduplicate`, errors repeating an earlier path and message are dropped.
+/// `schema_json` is the uncompiled schema, used to resolve each error's subschema.
+fn collect_errors(
+    py: Python,
+    schema: &JSONSchema,
+    schema_json: &Value,
+    instance: &Value,
+    relevance: Option<&PyAny>,
+    deduplicate: bool,
+) -> PyResult<PyObject> {
+    let mut errors: Vec<ValidationError> = match schema.validate(instance) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .map(|e| ValidationError::from_error(e, schema_json).localized(py))
+            .collect::<PyResult<_>>()?,
+    };
+
+    if deduplicate {
+        let mut seen = HashSet::new();
+        errors.retain(|e| seen.insert((e.instance_path.clone(), e.message.clone())));
+    }
+
+    if relevance.is_none() {
+        errors.sort_by_key(ValidationError::relevance_key);
+    }
+
+    let items = errors
+        .into_iter()
+        .map(|e| Py::new(py, e).map(|e| e.to_object(py)))
+        .collect::<PyResult<Vec<_>>>()?;
+    let list = PyList::new(py, items);
+
+    if let Some(key) = relevance {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("key", key)?;
+        list.call_method("sort", (), Some(kwargs))?;
+    }
+
+    Ok(list.to_object(py))
+}
+
+/// Word errors from `keyword` your own way, e.g. to translate them
+///
+/// `template` is either a callable taking `(keyword, instance, schema_value)`
+/// and returning the message, or a `str.format` template with `{keyword}`,
+/// `{instance}`, `{schema}` (the keyword's value in the schema) and
+/// `{message}` (the default message) fields. The message is used by
+/// `ValidationError.message` and the text `validate` raises. Registering
+/// `None` restores the default message.
+#[pyfunction]
+fn register_message(keyword: &str, template: Option<&PyAny>) -> PyResult<()> {
+    let mut formatters = MESSAGE_FORMATTERS.lock().unwrap();
+    match template {
+        None => {
+            formatters.remove(keyword);
+        }
+        Some(template) if template.is_instance_of::<PyString>() || template.is_callable() => {
+            formatters.insert(keyword.to_string(), template.into());
+        }
+        Some(template) => {
+            return Err(PyTypeError::new_err(format!(
+                "message template must be a string or callable, not {}",
+                template.get_type().name()?
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Default relevance key used to order `iter_errors` results
+#[pyfunction]
+fn relevance(error: &ValidationError) -> (isize, bool) {
+    error.relevance_key()
+}
+
+/// Return all validation errors for an instance, most relevant first
+#[pyfunction]
+#[pyo3(signature = (instance, schema, relevance=None, deduplicate=false, *, format_checker=None))]
+fn iter_errors(
+    py: Python,
+    instance: &PyAny,
+    schema: &PyAny,
+    relevance: Option<&PyAny>,
+    deduplicate: bool,
+    format_checker: Option<bool>,
+) -> PyResult<PyObject> {
+    let instance_json = python_to_json(py, instance)?;
+    let schema_json = python_to_json(py, schema)?;
+
+    let compiled = compile_schema(&schema_json, format_checker)?;
+
+    collect_errors(py, &compiled, &schema_json, &instance_json, relevance, deduplicate)
+}
+
+/// Keywords holding reusable subschemas, carried over when a property's
+/// subschema is validated on its own so `$ref`s into them still resolve
+const DEFINITION_KEYWORDS: &[&str] = &["$schema", "definitions", "$defs"];
+
+/// The subschema a value under `property_name` is checked against:
+/// `properties[property_name]`, else `additionalProperties`, else `true`
+fn property_schema(schema: &Value, property_name: &str) -> Value {
+    schema
+        .get("properties")
+        .and_then(|properties| properties.get(property_name))
+        .or_else(|| schema.get("additionalProperties"))
+        .cloned()
+        .unwrap_or(Value::Bool(true))
+}
+
+/// `subschema` with the root schema's definitions added, so it compiles
+/// as a standalone document
+fn with_definitions(schema: &Value, mut subschema: Value) -> Value {
+    if let Value::Object(map) = &mut subschema {
+        for keyword in DEFINITION_KEYWORDS {
+            if let Some(definitions) = schema.get(*keyword) {
+                map.entry(keyword.to_string()).or_insert_with(|| definitions.clone());
+            }
+        }
+    }
+    subschema
+}
+
+/// Validate one property's value against its subschema, without the rest
+/// of the instance
+///
+/// The subschema is `properties[property_name]`, falling back to
+/// `additionalProperties` and then to `true` (anything passes). Returns the
+/// errors as `iter_errors` does, with paths relative to `value`.
+#[pyfunction]
+#[pyo3(signature = (schema, property_name, value, *, format_checker=None))]
+fn validate_property(
+    py: Python,
+    schema: &PyAny,
+    property_name: &str,
+    value: &PyAny,
+    format_checker: Option<bool>,
+) -> PyResult<PyObject> {
+    let schema_json = python_to_json(py, schema)?;
+    let value_json = python_to_json(py, value)?;
+
+    let subschema = property_schema(&schema_json, property_name);
+    let compiled = compile_schema(&with_definitions(&schema_json, subschema.clone()), format_checker)?;
+
+    collect_errors(py, &compiled, &subschema, &value_json, None, false)
+}
+
+/// Return the schema's top-level `required` keys missing from `instance`,
+/// in the order the schema lists them
+#[pyfunction]
+fn check_required(py: Python, schema: &PyAny, instance: &PyAny) -> PyResult<Vec<String>> {
+    let schema_json = python_to_json(py, schema)?;
+    let instance: &PyDict = instance
+        .downcast()
+        .map_err(|_| PyTypeError::new_err("instance must be a dict"))?;
+
+    let required = schema_json
+        .get("required")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut missing = Vec::new();
+    for key in required.iter().filter_map(Value::as_str) {
+        if !instance.contains(key)? {
+            missing.push(key.to_string());
+        }
+    }
+    Ok(missing)
+}
+
 /// Validate JSON data against a schema
 ///
 /// Raises ValidationError if validation fails
 #[pyfunction]
-fn validate(py: Python, instance: &PyAny, schema: &PyAny) -> PyResult<()> {
+#[pyo3(signature = (instance, schema, *, format_checker=None))]
+fn validate(py: Python, instance: &PyAny, schema: &PyAny, format_checker: Option<bool>) -> PyResult<()> {
     // Convert Python objects to JSON
     let instance_json = python_to_json(py, instance)?;
     let schema_json = python_to_json(py, schema)?;
 
     // Compile schema
-    let compiled = JSONSchema::compile(&schema_json)
-        .map_err(|e| PyValueError::new_err(format!("Schema compilation error: {}", e)))?;
+    let compiled = compile_schema(&schema_json, format_checker)?;
 
     // Validate - collect errors immediately to avoid lifetime issues
     let validation_result = compiled.validate(&instance_json);
@@ -33,8 +553,8 @@ fn validate(py: Python, instance: &PyAny, schema: &PyAny) -> PyResult<()> {
 
     let error_messages: Vec<String> = validation_result
         .unwrap_err()
-        .map(|e| e.to_string())
-        .collect();
+        .map(|e| ValidationError::from_error(e, &schema_json).localized(py).map(|e| e.message))
+        .collect::<PyResult<_>>()?;
 
     Err(PyValueError::new_err(format!(
         "Validation error: {}",
@@ -46,14 +566,14 @@ fn validate(py: Python, instance: &PyAny, schema: &PyAny) -> PyResult<()> {
 ///
 /// Returns True if valid, False otherwise
 #[pyfunction]
-fn is_valid(py: Python, instance: &PyAny, schema: &PyAny) -> PyResult<bool> {
+#[pyo3(signature = (instance, schema, *, format_checker=None))]
+fn is_valid(py: Python, instance: &PyAny, schema: &PyAny, format_checker: Option<bool>) -> PyResult<bool> {
     // Convert Python objects to JSON
     let instance_json = python_to_json(py, instance)?;
     let schema_json = python_to_json(py, schema)?;
 
     // Compile schema
-    let compiled = JSONSchema::compile(&schema_json)
-        .map_err(|_| PyValueError::new_err("Schema compilation error"))?;
+    let compiled = compile_schema(&schema_json, format_checker)?;
 
     // Check validity
     Ok(compiled.is_valid(&instance_json))
@@ -63,32 +583,47 @@ fn is_valid(py: Python, instance: &PyAny, schema: &PyAny) -> PyResult<bool> {
 #[pyclass]
 struct Validator {
     schema: JSONSchema,
+    schema_json: Value,
 }
 
+// `validate`/`is_valid` share `schema` across threads with the GIL released
+const _: fn() = || {
+    fn assert_sync<T: Send + Sync>() {}
+    assert_sync::<JSONSchema>();
+};
+
 #[pymethods]
 impl Validator {
     #[new]
-    fn new(py: Python, schema: &PyAny) -> PyResult<Self> {
+    #[pyo3(signature = (schema, *, format_checker=None))]
+    fn new(py: Python, schema: &PyAny, format_checker: Option<bool>) -> PyResult<Self> {
         let schema_json = python_to_json(py, schema)?;
-        let compiled = JSONSchema::compile(&schema_json)
-            .map_err(|e| PyValueError::new_err(format!("Schema compilation error: {}", e)))?;
+        let compiled = compile_schema(&schema_json, format_checker)?;
 
-        Ok(Validator { schema: compiled })
+        Ok(Validator { schema: compiled, schema_json })
     }
 
     /// Validate an instance against the schema
+    ///
+    /// The GIL is released while the converted instance is checked, so
+    /// threads sharing one `Validator` validate concurrently.
     fn validate(&self, py: Python, instance: &PyAny) -> PyResult<()> {
         let instance_json = python_to_json(py, instance)?;
 
-        let validation_result = self.schema.validate(&instance_json);
-        if validation_result.is_ok() {
+        let validation_result = py.allow_threads(|| {
+            self.schema.validate(&instance_json).map_err(|errors| {
+                errors
+                    .map(|e| ValidationError::from_error(e, &self.schema_json))
+                    .collect::<Vec<_>>()
+            })
+        });
+        let Err(errors) = validation_result else {
             return Ok(());
-        }
-
-        let error_messages: Vec<String> = validation_result
-            .unwrap_err()
-            .map(|e| e.to_string())
-            .collect();
+        };
+        let error_messages = errors
+            .into_iter()
+            .map(|e| e.localized(py).map(|e| e.message))
+            .collect::<PyResult<Vec<_>>>()?;
 
         Err(PyValueError::new_err(format!(
             "Validation error: {}",
@@ -96,10 +631,52 @@ impl Validator {
         )))
     }
 
-    /// Check if instance is valid
+    /// Check if instance is valid, releasing the GIL as `validate` does
     fn is_valid(&self, py: Python, instance: &PyAny) -> PyResult<bool> {
         let instance_json = python_to_json(py, instance)?;
-        Ok(self.schema.is_valid(&instance_json))
+        Ok(py.allow_threads(|| self.schema.is_valid(&instance_json)))
+    }
+
+    /// Return all validation errors for an instance, most relevant first
+    #[pyo3(signature = (instance, relevance=None, deduplicate=false))]
+    fn iter_errors(
+        &self,
+        py: Python,
+        instance: &PyAny,
+        relevance: Option<&PyAny>,
+        deduplicate: bool,
+    ) -> PyResult<PyObject> {
+        let instance_json = python_to_json(py, instance)?;
+        collect_errors(py, &self.schema, &self.schema_json, &instance_json, relevance, deduplicate)
+    }
+
+    /// Summarize validation as a JSON-serializable dict
+    ///
+    /// Returns `{"valid", "count", "errors", "by_path"}` where `errors` holds
+    /// each error's `to_dict()` (most relevant first) and `by_path` maps
+    /// instance pointers such as "/users/0" to their error messages.
+    fn validation_report(&self, py: Python, instance: &PyAny) -> PyResult<PyObject> {
+        let instance_json = python_to_json(py, instance)?;
+        let errors = collect_errors(py, &self.schema, &self.schema_json, &instance_json, None, false)?;
+        let errors: &PyList = errors.downcast(py)?;
+
+        let items = PyList::empty(py);
+        let by_path = PyDict::new(py);
+        for error in errors {
+            let error: PyRef<ValidationError> = error.extract()?;
+            items.append(error.to_dict(py)?)?;
+            match by_path.get_item(&error.instance_path)? {
+                Some(messages) => messages.downcast::<PyList>()?.append(&error.message)?,
+                None => by_path.set_item(&error.instance_path, vec![&error.message])?,
+            }
+        }
+
+        let report = PyDict::new(py);
+        report.set_item("valid", items.is_empty())?;
+        report.set_item("count", items.len())?;
+        report.set_item("errors", items)?;
+        report.set_item("by_path", by_path)?;
+        Ok(report.to_object(py))
     }
 }
 
@@ -107,6 +684,12 @@ impl Validator {
 fn jsonschema_rs(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(validate, m)?)?;
     m.add_function(wrap_pyfunction!(is_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(iter_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(relevance, m)?)?;
+    m.add_function(wrap_pyfunction!(register_message, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_property, m)?)?;
+    m.add_function(wrap_pyfunction!(check_required, m)?)?;
     m.add_class::<Validator>()?;
+    m.add_class::<ValidationError>()?;
     Ok(())
 }
@@ -166,6 +166,34 @@ class TestValidator:
 
         validator.validate(valid_data)
 
+    def test_validator_shared_across_threads(self):
+        from concurrent.futures import ThreadPoolExecutor
+
+        schema = {
+            "type": "array",
+            "items": {"type": "integer", "minimum": 0},
+        }
+        validator = jsonschema_rs.Validator(schema)
+        good = list(range(2000))
+        bad = good + [-1]
+
+        def check(i):
+            instance = bad if i % 2 else good
+            valid = validator.is_valid(instance)
+            try:
+                validator.validate(instance)
+                raised = False
+            except ValueError:
+                raised = True
+            return valid, raised
+
+        with ThreadPoolExecutor(max_workers=8) as pool:
+            results = list(pool.map(check, range(64)))
+
+        for i, (valid, raised) in enumerate(results):
+            assert valid is (i % 2 == 0)
+            assert raised is (i % 2 == 1)
+
 
 class TestEdgeCases:
     """Test edge cases"""
@@ -245,6 +273,292 @@ class TestConstraints:
         assert jsonschema_rs.is_valid("yellow", schema) is False
 
 
+class TestIterErrors:
+    """Test structured errors from iter_errors"""
+
+    SCHEMA = {
+        "type": "object",
+        "properties": {
+            "a": {"anyOf": [{"type": "string"}, {"type": "integer"}]},
+            "b": {"type": "array", "items": {"type": "integer", "minimum": 0}}
+        },
+        "required": ["c"]
+    }
+
+    def test_valid_instance(self):
+        assert jsonschema_rs.iter_errors({"c": 1}, self.SCHEMA) == []
+
+    def test_error_attributes(self):
+        errors = jsonschema_rs.iter_errors({"c": 1, "b": ["x"]}, self.SCHEMA)
+        assert len(errors) == 1
+        error = errors[0]
+        assert error.keyword == "type"
+        assert error.path == ["b", 0]
+        assert error.instance_path == "/b/0"
+        assert error.schema_path == "/properties/b/items/type"
+
+    def test_sorted_by_relevance(self):
+        errors = jsonschema_rs.iter_errors({"a": 1.5, "b": [1, "x"]}, self.SCHEMA)
+        assert [e.keyword for e in errors] == ["type", "anyOf", "required"]
+
+    def test_custom_relevance(self):
+        errors = jsonschema_rs.iter_errors(
+            {"a": 1.5, "b": [1, "x"]}, self.SCHEMA, relevance=lambda e: len(e.path)
+        )
+        assert [e.keyword for e in errors] == ["required", "anyOf", "type"]
+
+    def test_deduplicate(self):
+        schema = {"items": {"allOf": [{"type": "string"}, {"type": "string"}]}}
+        assert len(jsonschema_rs.iter_errors([1, 2], schema)) == 4
+        assert len(jsonschema_rs.iter_errors([1, 2], schema, deduplicate=True)) == 2
+
+    def test_validator_iter_errors(self):
+        validator = jsonschema_rs.Validator(self.SCHEMA)
+        assert validator.iter_errors({"c": 1}) == []
+        assert [e.keyword for e in validator.iter_errors({})] == ["required"]
+
+    def test_to_dict(self):
+        error = jsonschema_rs.iter_errors({"c": 1, "b": ["x"]}, self.SCHEMA)[0]
+        assert error.to_dict() == {
+            "path": ["b", 0],
+            "message": error.message,
+            "keyword": "type",
+        }
+
+    def test_instance_and_schema(self):
+        error = jsonschema_rs.iter_errors({"c": 1, "b": [1, -2]}, self.SCHEMA)[0]
+        assert error.keyword == "minimum"
+        assert error.instance == -2
+        assert error.schema == {"type": "integer", "minimum": 0}
+
+    def test_required_instance_is_object(self):
+        error = jsonschema_rs.Validator(self.SCHEMA).iter_errors({"b": [1]})[0]
+        assert error.instance == {"b": [1]}
+        assert error.schema == self.SCHEMA
+
+
+class TestValidationReport:
+    """Test Validator.validation_report"""
+
+    SCHEMA = TestIterErrors.SCHEMA
+
+    def test_valid_instance(self):
+        report = jsonschema_rs.Validator(self.SCHEMA).validation_report({"c": 1})
+        assert report == {"valid": True, "count": 0, "errors": [], "by_path": {}}
+
+    def test_invalid_instance(self):
+        report = jsonschema_rs.Validator(self.SCHEMA).validation_report({"b": ["x", "y"]})
+        assert report["valid"] is False
+        assert report["count"] == 3
+        assert [e["keyword"] for e in report["errors"]] == ["type", "type", "required"]
+        assert sorted(report["by_path"]) == ["", "/b/0", "/b/1"]
+
+    def test_serializable(self):
+        import json
+        report = jsonschema_rs.Validator(self.SCHEMA).validation_report({"a": 1.5})
+        assert json.loads(json.dumps(report)) == report
+
+
+class TestPartialValidation:
+    """Test validate_property and check_required"""
+
+    SCHEMA = {
+        "definitions": {"age": {"type": "integer", "minimum": 0}},
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "minLength": 2},
+            "age": {"$ref": "#/definitions/age"},
+        },
+        "required": ["name", "age", "email"],
+    }
+
+    def test_valid_property(self):
+        assert jsonschema_rs.validate_property(self.SCHEMA, "name", "Ada") == []
+
+    def test_invalid_property(self):
+        errors = jsonschema_rs.validate_property(self.SCHEMA, "name", 3)
+        assert [e.keyword for e in errors] == ["type"]
+        assert errors[0].path == []
+        assert errors[0].schema == {"type": "string", "minLength": 2}
+
+    def test_ref_into_root_definitions(self):
+        errors = jsonschema_rs.validate_property(self.SCHEMA, "age", -1)
+        assert [e.keyword for e in errors] == ["minimum"]
+
+    def test_undefined_property_falls_back(self):
+        assert jsonschema_rs.validate_property(self.SCHEMA, "other", 1) == []
+        closed = dict(self.SCHEMA, additionalProperties=False)
+        assert len(jsonschema_rs.validate_property(closed, "other", 1)) == 1
+        typed = dict(self.SCHEMA, additionalProperties={"type": "string"})
+        assert jsonschema_rs.validate_property(typed, "other", "x") == []
+
+    def test_check_required(self):
+        assert jsonschema_rs.check_required(self.SCHEMA, {"name": "Ada"}) == ["age", "email"]
+        assert jsonschema_rs.check_required(self.SCHEMA, {"name": "Ada", "age": 1, "email": ""}) == []
+        assert jsonschema_rs.check_required({"type": "object"}, {}) == []
+
+    def test_check_required_needs_dict(self):
+        with pytest.raises(TypeError):
+            jsonschema_rs.check_required(self.SCHEMA, ["name"])
+
+
+class TestFormatChecker:
+    """Format assertion with format_checker=True"""
+
+    @staticmethod
+    def check(fmt, value):
+        return jsonschema_rs.is_valid(value, {"format": fmt}, format_checker=True)
+
+    def test_date(self):
+        assert self.check("date", "2024-02-29")
+        assert not self.check("date", "2023-13-01")
+        assert not self.check("date", "2023-02-29")
+
+    def test_time(self):
+        assert self.check("time", "10:00:00.123-05:30")
+        assert not self.check("time", "10:00:00")
+        assert not self.check("time", "24:00:00Z")
+
+    def test_date_time(self):
+        assert self.check("date-time", "2023-01-01T10:00:00Z")
+        assert self.check("date-time", "2023-01-01t10:00:00.5+02:00")
+        assert not self.check("date-time", "2023-01-01 10:00:00Z")
+
+    def test_duration(self):
+        assert self.check("duration", "P1Y2M10DT2H30M")
+        assert self.check("duration", "P4W")
+        assert not self.check("duration", "P")
+        assert not self.check("duration", "PT")
+        assert not self.check("duration", "P1D2Y")
+
+    def test_regex(self):
+        assert self.check("regex", "^(?=x)a+$")
+        assert not self.check("regex", "[unclosed")
+
+    def test_uuid(self):
+        assert self.check("uuid", "123e4567-e89b-12d3-a456-426614174000")
+        assert not self.check("uuid", "123e4567e89b12d3a456426614174000")
+
+    def test_disabled(self):
+        assert jsonschema_rs.is_valid("2023-13-01", {"format": "date"}, format_checker=False)
+
+    def test_validator_and_errors(self):
+        validator = jsonschema_rs.Validator({"format": "date"}, format_checker=True)
+        assert not validator.is_valid("2023-13-01")
+        assert [e.keyword for e in validator.iter_errors("2023-13-01")] == ["format"]
+
+
+class TestMessageFormatting:
+    """register_message rewords errors per keyword"""
+
+    SCHEMA = {
+        "type": "object",
+        "properties": {"age": {"type": "integer", "minimum": 0}},
+        "required": ["name"],
+    }
+
+    def teardown_method(self):
+        for keyword in ("type", "required", "minimum"):
+            jsonschema_rs.register_message(keyword, None)
+
+    def test_template(self):
+        jsonschema_rs.register_message("type", "Expected {schema}, got {instance!r}")
+        errors = jsonschema_rs.iter_errors({"name": "x", "age": "7"}, self.SCHEMA)
+        assert [e.message for e in errors] == ["Expected integer, got '7'"]
+
+    def test_callable(self):
+        jsonschema_rs.register_message(
+            "required", lambda keyword, instance, value: "Missing: " + ", ".join(value)
+        )
+        errors = jsonschema_rs.iter_errors({"age": 1}, self.SCHEMA)
+        assert [e.message for e in errors] == ["Missing: name"]
+
+    def test_default_message_field(self):
+        jsonschema_rs.register_message("minimum", "{message} (age)")
+        error = jsonschema_rs.iter_errors({"name": "x", "age": -1}, self.SCHEMA)[0]
+        assert error.message == "-1 is less than the minimum of 0 (age)"
+
+    def test_validate_uses_formatter(self):
+        jsonschema_rs.register_message("type", "wrong type")
+        with pytest.raises(ValueError, match="wrong type"):
+            jsonschema_rs.validate({"name": "x", "age": "7"}, self.SCHEMA)
+        with pytest.raises(ValueError, match="wrong type"):
+            jsonschema_rs.Validator(self.SCHEMA).validate({"name": "x", "age": "7"})
+
+    def test_fallback_and_reset(self):
+        jsonschema_rs.register_message("type", "wrong type")
+        jsonschema_rs.register_message("type", None)
+        errors = jsonschema_rs.iter_errors({"age": "7"}, self.SCHEMA)
+        assert [e.message for e in errors] == [
+            '"7" is not of type "integer"',
+            '"name" is a required property',
+        ]
+
+    def test_invalid_template(self):
+        with pytest.raises(TypeError):
+            jsonschema_rs.register_message("type", 3)
+
+
+DRAFT_2020_12 = "https://json-schema.org/draft/2020-12/schema"
+
+
+class TestDraft202012:
+    """2020-12 keywords are enforced when the schema selects that draft"""
+
+    def test_unevaluated_properties_through_ref(self):
+        schema = {
+            "$schema": DRAFT_2020_12,
+            "$defs": {"named": {"properties": {"name": {"type": "string"}}}},
+            "allOf": [{"$ref": "#/$defs/named"}],
+            "properties": {"age": {"type": "integer"}},
+            "unevaluatedProperties": False,
+        }
+        assert jsonschema_rs.is_valid({"name": "x", "age": 3}, schema)
+        assert not jsonschema_rs.is_valid({"name": "x", "extra": 1}, schema)
+        errors = jsonschema_rs.iter_errors({"name": "x", "extra": 1}, schema)
+        assert len(errors) == 1
+        assert "'extra'" in errors[0].message
+
+    def test_unevaluated_properties_through_all_of(self):
+        schema = {
+            "$schema": DRAFT_2020_12,
+            "allOf": [
+                {"properties": {"a": {"type": "integer"}}},
+                {"properties": {"b": {"type": "integer"}}},
+            ],
+            "unevaluatedProperties": False,
+        }
+        assert jsonschema_rs.is_valid({"a": 1, "b": 2}, schema)
+        assert not jsonschema_rs.is_valid({"a": 1, "c": 3}, schema)
+
+    def test_prefix_items(self):
+        schema = {
+            "$schema": DRAFT_2020_12,
+            "type": "array",
+            "prefixItems": [{"type": "integer"}, {"type": "string"}],
+            "items": False,
+        }
+        assert jsonschema_rs.is_valid([1, "a"], schema)
+        assert jsonschema_rs.is_valid([1], schema)
+        assert not jsonschema_rs.is_valid(["a", 1], schema)
+        assert not jsonschema_rs.is_valid([1, "a", 3], schema)
+
+    def test_schema_url_with_fragment(self):
+        schema = {"$schema": DRAFT_2020_12 + "#", "unevaluatedProperties": False}
+        assert not jsonschema_rs.is_valid({"a": 1}, schema)
+
+    def test_draft7_ignores_2020_keywords(self):
+        schema = {"unevaluatedProperties": False}
+        assert jsonschema_rs.is_valid({"a": 1}, schema)
+
+    def test_validator_uses_selected_draft(self):
+        validator = jsonschema_rs.Validator(
+            {"$schema": DRAFT_2020_12, "prefixItems": [{"type": "integer"}]}
+        )
+        assert validator.is_valid([1, "a"])
+        assert not validator.is_valid(["a"])
+
+
 @pytest.mark.skipif(not JSONSCHEMA_AVAILABLE, reason="jsonschema not installed")
 class TestCompatibility:
     """Test compatibility with Python jsonschema"""
@@ -0,0 +1,64 @@
+//! Compares `escape_string` against the char-by-char escape it replaced,
+//! on the same ~100KB mostly-safe input as TEST 8 in `benchmark.py`.
+//!
+//! Run with `cargo bench --bench escape`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use markupsafe_rs::escape_string;
+
+/// The previous implementation: a full scan for special characters, then
+/// a second pass pushing one char at a time.
+fn escape_chars(text: &str) -> String {
+    if !text
+        .chars()
+        .any(|c| matches!(c, '&' | '<' | '>' | '"' | '\''))
+    {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len() + text.len() / 4);
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#x27;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Mostly plain prose with a single snippet needing escapes, built the same
+/// way as the Python benchmark.
+fn mostly_safe_text() -> String {
+    let prose = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Größe 東京. ".repeat(800);
+    let half = prose.chars().count() / 2;
+    let split = prose
+        .char_indices()
+        .nth(half)
+        .map_or(prose.len(), |(i, _)| i);
+    format!(
+        "{}<b>Tom & Jerry's \"show\"</b>{}",
+        &prose[..split],
+        &prose[split..]
+    )
+}
+
+fn bench_escape(c: &mut Criterion) {
+    let text = mostly_safe_text();
+    assert_eq!(escape_chars(&text), escape_string(&text));
+
+    let mut group = c.benchmark_group("escape 100KB mostly safe");
+    group.bench_function("chars (previous)", |b| {
+        b.iter(|| escape_chars(black_box(&text)))
+    });
+    group.bench_function("bytes (current)", |b| {
+        b.iter(|| escape_string(black_box(&text)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_escape);
+criterion_main!(benches);
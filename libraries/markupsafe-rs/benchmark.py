@@ -261,6 +261,38 @@ def test_real_world_template():
         print(f"🚀 Speedup: {format_speedup(py_time, rs_time)}")
 
 
+def test_escape_large_mostly_safe():
+    """Benchmark: Escape a large string with only a few special chars."""
+    print("\n" + "="*70)
+    print("TEST 8: Escape large mostly-safe text (100KB, one markup snippet)")
+    print("="*70)
+    
+    # Mostly plain prose with a single snippet needing escapes, as in a
+    # rendered article body; exercises bulk copying of safe runs
+    prose = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Größe 東京. " * 800
+    half = len(prose) // 2
+    text = prose[:half] + '<b>Tom & Jerry\'s "show"</b>' + prose[half:]
+    iterations = 2000
+    
+    if HAS_PYTHON:
+        py_time = benchmark("Python escape", lambda: ms_py.escape(text), iterations)
+        print(f"📦 Python:  {iterations/py_time:>12,.0f} ops/sec  ({py_time:.4f}s)")
+    
+    if HAS_RUST:
+        rs_time = benchmark("Rust escape", lambda: ms_rs.escape(text), iterations)
+        print(f"🦀 Rust:    {iterations/rs_time:>12,.0f} ops/sec  ({rs_time:.4f}s)")
+    
+    if HAS_PYTHON and HAS_RUST:
+        print(f"🚀 Speedup: {format_speedup(py_time, rs_time)}")
+        
+        py_result = str(ms_py.escape(text))
+        rs_result = str(ms_rs.escape(text))
+        if py_result == rs_result:
+            print("✓ Results match")
+        else:
+            print(f"✗ Results differ (lengths: {len(py_result)} vs {len(rs_result)})")
+
+
 def main():
     print("🦀 markupsafe-rs Benchmark Suite")
     print("HTML/XML escaping performance comparison\n")
@@ -284,6 +316,7 @@ def main():
     test_markup_operations()
     test_markup_string_methods()
     test_real_world_template()
+    test_escape_large_mostly_safe()
     
     print("\n" + "="*70)
     print("SUMMARY")
@@ -15,32 +15,15 @@ fn escape(py: Python<'_>, s: &PyAny) -> PyResult<PyObject> {
         return Ok(markup.value.clone().into_py(py));
     }
     
-    // Convert to string
-    let text = if let Ok(string) = s.extract::<String>() {
-        string
+    // Borrow the text rather than copying it; `escape_string` makes the
+    // only copy
+    let escaped = if let Ok(text) = s.extract::<&str>() {
+        escape_string(text)
     } else {
-        s.str()?.to_str()?.to_string()
+        escape_string(s.str()?.to_str()?)
     };
     
-    // Fast path: no escaping needed
-    if !text.chars().any(|c| matches!(c, '&' | '<' | '>' | '"' | '\'')) {
-        return Ok(Markup::new(text).into_py(py));
-    }
-    
-    // Escape characters
-    let mut result = String::with_capacity(text.len() + text.len() / 4);
-    for c in text.chars() {
-        match c {
-            '&' => result.push_str("&amp;"),
-            '<' => result.push_str("&lt;"),
-            '>' => result.push_str("&gt;"),
-            '"' => result.push_str("&quot;"),
-            '\'' => result.push_str("&#x27;"),
-            _ => result.push(c),
-        }
-    }
-    
-    Ok(Markup::new(result).into_py(py))
+    Ok(Markup::new(escaped).into_py(py))
 }
 
 /// Escape HTML, returning empty string for None instead of None
@@ -108,7 +91,7 @@ impl Markup {
             // Escape raw strings when concatenating
             escape_string(&s)
         } else {
-            escape_string(&other.str()?.to_str()?.to_string())
+            escape_string(other.str()?.to_str()?)
         };
         
         Ok(Markup::new(format!("{}{}", self.value, other_str)))
@@ -120,7 +103,7 @@ impl Markup {
         } else if let Ok(s) = other.extract::<String>() {
             escape_string(&s)
         } else {
-            escape_string(&other.str()?.to_str()?.to_string())
+            escape_string(other.str()?.to_str()?)
         };
         
         Ok(Markup::new(format!("{}{}", other_str, self.value)))
@@ -156,7 +139,7 @@ impl Markup {
             } else if let Ok(s) = item.extract::<String>() {
                 escape_string(&s)
             } else {
-                escape_string(&item.str()?.to_str()?.to_string())
+                escape_string(item.str()?.to_str()?)
             };
             parts.push(s);
         }
@@ -290,22 +273,39 @@ impl Markup {
 }
 
 /// Helper function to escape a string
-fn escape_string(text: &str) -> String {
-    if !text.chars().any(|c| matches!(c, '&' | '<' | '>' | '"' | '\'')) {
-        return text.to_string();
+///
+/// Scans bytes rather than chars: the five special characters are ASCII and
+/// never occur inside a multi-byte UTF-8 sequence, so slicing at them is
+/// always on a char boundary. Runs of safe text are copied in bulk, and
+/// input with nothing to escape is copied once without a second pass.
+///
+/// Public only so `benches/escape.rs` can measure it.
+#[doc(hidden)]
+pub fn escape_string(text: &str) -> String {
+    let mut result = String::new();
+    let mut safe_start = 0;
+    
+    for (i, byte) in text.bytes().enumerate() {
+        let replacement = match byte {
+            b'&' => "&amp;",
+            b'<' => "&lt;",
+            b'>' => "&gt;",
+            b'"' => "&quot;",
+            b'\'' => "&#x27;",
+            _ => continue,
+        };
+        if safe_start == 0 && result.is_empty() {
+            result.reserve(text.len() + text.len() / 4);
+        }
+        result.push_str(&text[safe_start..i]);
+        result.push_str(replacement);
+        safe_start = i + 1;
     }
     
-    let mut result = String::with_capacity(text.len() + text.len() / 4);
-    for c in text.chars() {
-        match c {
-            '&' => result.push_str("&amp;"),
-            '<' => result.push_str("&lt;"),
-            '>' => result.push_str("&gt;"),
-            '"' => result.push_str("&quot;"),
-            '\'' => result.push_str("&#x27;"),
-            _ => result.push(c),
-        }
+    if result.is_empty() {
+        return text.to_string();
     }
+    result.push_str(&text[safe_start..]);
     result
 }
 
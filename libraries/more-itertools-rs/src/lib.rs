@@ -1,7 +1,11 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyIterator, PyList, PyTuple};
-use pyo3::exceptions::PyValueError;
-use std::collections::{HashSet, HashMap};
+use pyo3::types::{PyByteArray, PyBytes, PyIterator, PyList, PySet, PyString, PyTuple};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::create_exception;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet, HashMap, VecDeque};
+
+create_exception!(more_itertools_rs, UnequalIterablesError, PyValueError);
 
 /// Break iterable into lists of length n
 #[pyfunction]
@@ -75,6 +79,7 @@ fn batched(py: Python, iterable: &PyAny, n: usize, strict: bool) -> PyResult<PyO
 
 /// Flatten one level of nesting
 #[pyfunction]
+#[allow(non_snake_case)] // keyword name matches more-itertools
 fn flatten(py: Python, listOfLists: &PyAny) -> PyResult<PyObject> {
     let iter = PyIterator::from_object(listOfLists)?;
     let mut result = Vec::new();
@@ -90,6 +95,36 @@ fn flatten(py: Python, listOfLists: &PyAny) -> PyResult<PyObject> {
     Ok(PyList::new(py, result).to_object(py))
 }
 
+/// Chain values, flattening iterable arguments one level
+///
+/// Strings, bytes and bytearrays count as single values rather than being
+/// split into characters, so `value_chain(1, [2, 3], "45")` is
+/// `[1, 2, 3, "45"]`. Anything else that can't be iterated is a single value.
+#[pyfunction]
+#[pyo3(signature = (*args))]
+fn value_chain(py: Python, args: &PyTuple) -> PyResult<PyObject> {
+    let result = PyList::empty(py);
+    for value in args {
+        if value.is_instance_of::<PyString>()
+            || value.is_instance_of::<PyBytes>()
+            || value.is_instance_of::<PyByteArray>()
+        {
+            result.append(value)?;
+            continue;
+        }
+        match PyIterator::from_object(value) {
+            Ok(iter) => {
+                for item in iter {
+                    result.append(item?)?;
+                }
+            }
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => result.append(value)?,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(result.to_object(py))
+}
+
 /// Return first item of iterable or default
 #[pyfunction]
 #[pyo3(signature = (iterable, default=None))]
@@ -142,6 +177,155 @@ fn take(py: Python, n: usize, iterable: &PyAny) -> PyResult<PyObject> {
     Ok(PyList::new(py, result).to_object(py))
 }
 
+/// Return the items as a list if there are exactly n of them
+///
+/// Raises ValueError for too few or too many items; at most n + 1 items
+/// are consumed.
+#[pyfunction]
+fn strictly_n(py: Python, iterable: &PyAny, n: usize) -> PyResult<PyObject> {
+    let items = PyIterator::from_object(iterable)?
+        .take(n + 1)
+        .collect::<PyResult<Vec<_>>>()?;
+
+    if items.len() < n {
+        return Err(PyValueError::new_err(format!(
+            "Too few items in iterable (got {})",
+            items.len()
+        )));
+    }
+    if items.len() > n {
+        return Err(PyValueError::new_err(format!(
+            "Too many items in iterable (got at least {})",
+            items.len()
+        )));
+    }
+
+    Ok(PyList::new(py, items).to_object(py))
+}
+
+/// Return the last n items as a list
+///
+/// Only the most recent n items are kept while iterating, so long inputs
+/// aren't materialized.
+#[pyfunction]
+fn tail(py: Python, n: usize, iterable: &PyAny) -> PyResult<PyObject> {
+    let mut window: VecDeque<PyObject> = VecDeque::with_capacity(n);
+    if n > 0 {
+        let iter: Py<PyIterator> = PyIterator::from_object(iterable)?.into();
+        // Each item gets its own pool so items pushed out of the window are
+        // released right away instead of when the call returns
+        while let Some(item) = py.with_pool(|py| {
+            let mut items = iter.as_ref(py);
+            items.next().map(|item| item.map(PyObject::from))
+        }) {
+            if window.len() == n {
+                window.pop_front();
+            }
+            window.push_back(item?);
+        }
+    }
+
+    Ok(PyList::new(py, window).to_object(py))
+}
+
+/// Return `value` followed by the items of `iterable`
+#[pyfunction]
+fn prepend(py: Python, value: &PyAny, iterable: &PyAny) -> PyResult<PyObject> {
+    let result = PyList::new(py, [value]);
+    for item in PyIterator::from_object(iterable)? {
+        result.append(item?)?;
+    }
+    Ok(result.to_object(py))
+}
+
+/// Return the items of `iterable` followed by `value`
+#[pyfunction]
+fn append(py: Python, value: &PyAny, iterable: &PyAny) -> PyResult<PyObject> {
+    let result = PyList::empty(py);
+    for item in PyIterator::from_object(iterable)? {
+        result.append(item?)?;
+    }
+    result.append(value)?;
+    Ok(result.to_object(py))
+}
+
+/// Pad the items of `iterable` with `fillvalue` up to length n
+///
+/// With `next_multiple`, pad up to the next multiple of n instead. Inputs
+/// already long enough are returned as is. Without n there is no length
+/// to pad to, so the items are returned unchanged rather than padded
+/// forever.
+#[pyfunction]
+#[pyo3(signature = (iterable, fillvalue=None, n=None, next_multiple=false))]
+fn padded(
+    py: Python,
+    iterable: &PyAny,
+    fillvalue: Option<PyObject>,
+    n: Option<usize>,
+    next_multiple: bool,
+) -> PyResult<PyObject> {
+    let result = PyList::empty(py);
+    for item in PyIterator::from_object(iterable)? {
+        result.append(item?)?;
+    }
+
+    let Some(n) = n else {
+        return Ok(result.to_object(py));
+    };
+    if n == 0 {
+        return Err(PyValueError::new_err("n must be at least 1"));
+    }
+    let missing = if next_multiple {
+        (n - result.len() % n) % n
+    } else {
+        n.saturating_sub(result.len())
+    };
+    let fillvalue = fillvalue.unwrap_or_else(|| py.None());
+    for _ in 0..missing {
+        result.append(&fillvalue)?;
+    }
+
+    Ok(result.to_object(py))
+}
+
+/// The first `n` terms of `start, func(start), func(func(start)), ...`
+#[pyfunction]
+fn iterate(py: Python, func: &PyAny, start: &PyAny, n: usize) -> PyResult<PyObject> {
+    let result = PyList::empty(py);
+    let mut value = start;
+    for i in 0..n {
+        if i > 0 {
+            value = func.call1((value,))?;
+        }
+        result.append(value)?;
+    }
+    Ok(result.to_object(py))
+}
+
+/// Results of calling `func(*args)` `times` times
+#[pyfunction]
+#[pyo3(signature = (func, times, *args))]
+fn repeatfunc(py: Python, func: &PyAny, times: usize, args: &PyTuple) -> PyResult<PyObject> {
+    let result = PyList::empty(py);
+    for _ in 0..times {
+        result.append(func.call1(args)?)?;
+    }
+    Ok(result.to_object(py))
+}
+
+/// `[func(start), func(start + 1), ...]` for `n` consecutive integers
+///
+/// Unlike the unbounded recipe, `n` is required since a list is returned.
+#[pyfunction]
+#[pyo3(signature = (func, start=0, *, n))]
+fn tabulate(py: Python, func: &PyAny, start: i64, n: usize) -> PyResult<PyObject> {
+    let result = PyList::empty(py);
+    for i in (start..).take(n) {
+        result.append(func.call1((i,))?)?;
+    }
+    Ok(result.to_object(py))
+}
+
 /// Yield distinct elements preserving order
 #[pyfunction]
 fn unique_everseen(py: Python, iterable: &PyAny) -> PyResult<PyObject> {
@@ -161,22 +345,145 @@ fn unique_everseen(py: Python, iterable: &PyAny) -> PyResult<PyObject> {
     Ok(PyList::new(py, result).to_object(py))
 }
 
+/// Yield elements that were seen before, once for every repeat
+///
+/// Hashable elements (or keys) are tracked in a set; unhashable ones such
+/// as lists fall back to a linear search by equality.
+#[pyfunction]
+#[pyo3(signature = (iterable, key=None))]
+fn duplicates_everseen(py: Python, iterable: &PyAny, key: Option<&PyAny>) -> PyResult<PyObject> {
+    let seen_hashable = PySet::empty(py)?;
+    let seen_unhashable = PyList::empty(py);
+    let result = PyList::empty(py);
+
+    for item in PyIterator::from_object(iterable)? {
+        let item = item?;
+        let k = match key {
+            Some(key) => key.call1((item,))?,
+            None => item,
+        };
+
+        let seen = match k.hash() {
+            Ok(_) => {
+                let seen = seen_hashable.contains(k)?;
+                if !seen {
+                    seen_hashable.add(k)?;
+                }
+                seen
+            }
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                let seen = seen_unhashable.contains(k)?;
+                if !seen {
+                    seen_unhashable.append(k)?;
+                }
+                seen
+            }
+            Err(err) => return Err(err),
+        };
+        if seen {
+            result.append(item)?;
+        }
+    }
+
+    Ok(result.to_object(py))
+}
+
+/// Evaluate `pred` on an item, falling back to the item's truthiness when
+/// no predicate is given
+fn test_pred(pred: Option<&PyAny>, item: &PyAny) -> PyResult<bool> {
+    match pred {
+        Some(pred) => pred.call1((item,))?.is_true(),
+        None => item.is_true(),
+    }
+}
+
+/// Shared state behind the two lazy `partition` iterators
+#[pyclass]
+struct PartitionState {
+    source: Py<PyIterator>,
+    pred: Option<PyObject>,
+    false_items: VecDeque<PyObject>,
+    true_items: VecDeque<PyObject>,
+}
+
+impl PartitionState {
+    /// Pull the next item for one side, buffering items for the other side
+    fn next_for(&mut self, py: Python, side: bool) -> PyResult<Option<PyObject>> {
+        let queue = if side { &mut self.true_items } else { &mut self.false_items };
+        if let Some(item) = queue.pop_front() {
+            return Ok(Some(item));
+        }
+
+        let mut source = self.source.as_ref(py);
+        let pred = self.pred.as_ref().map(|p| p.as_ref(py));
+        loop {
+            let item = match source.next() {
+                Some(item) => item?,
+                None => return Ok(None),
+            };
+
+            if test_pred(pred, item)? == side {
+                return Ok(Some(item.to_object(py)));
+            }
+            if side {
+                self.false_items.push_back(item.to_object(py));
+            } else {
+                self.true_items.push_back(item.to_object(py));
+            }
+        }
+    }
+}
+
+/// One side of a lazy `partition`
+#[pyclass]
+struct PartitionIter {
+    state: Py<PartitionState>,
+    side: bool,
+}
+
+#[pymethods]
+impl PartitionIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.state.borrow_mut(py).next_for(py, self.side)
+    }
+}
+
 /// Split iterable into two based on predicate
+///
+/// A `pred` of `None` partitions on truthiness. With `lazy=True` the groups
+/// are returned as iterators that consume the input on demand.
 #[pyfunction]
-fn partition(py: Python, pred: &PyAny, iterable: &PyAny) -> PyResult<PyObject> {
+#[pyo3(signature = (pred, iterable, lazy=false))]
+fn partition(py: Python, pred: &PyAny, iterable: &PyAny, lazy: bool) -> PyResult<PyObject> {
     let iter = PyIterator::from_object(iterable)?;
+    let pred = if pred.is_none() { None } else { Some(pred) };
+
+    if lazy {
+        let state = Py::new(py, PartitionState {
+            source: iter.into(),
+            pred: pred.map(|p| p.to_object(py)),
+            false_items: VecDeque::new(),
+            true_items: VecDeque::new(),
+        })?;
+        let false_iter = Py::new(py, PartitionIter { state: state.clone_ref(py), side: false })?;
+        let true_iter = Py::new(py, PartitionIter { state, side: true })?;
+        return Ok(PyTuple::new(py, &[false_iter.to_object(py), true_iter.to_object(py)]).to_object(py));
+    }
+
     let mut false_items = Vec::new();
     let mut true_items = Vec::new();
 
     for item in iter {
         let item = item?;
-        let item_obj = item.to_object(py);
-        let result: bool = pred.call1((item_obj.clone_ref(py),))?.extract()?;
 
-        if result {
-            true_items.push(item_obj);
+        if test_pred(pred, item)? {
+            true_items.push(item);
         } else {
-            false_items.push(item_obj);
+            false_items.push(item);
         }
     }
 
@@ -186,6 +493,50 @@ fn partition(py: Python, pred: &PyAny, iterable: &PyAny) -> PyResult<PyObject> {
     Ok(PyTuple::new(py, &[false_list, true_list]).to_object(py))
 }
 
+/// Replace items matching a predicate with substitutes
+///
+/// `pred` is called with `window_size` consecutive items as arguments; each
+/// matching window is replaced by `substitutes`, at most `count` times.
+#[pyfunction]
+#[pyo3(signature = (iterable, pred, substitutes, count=None, window_size=1))]
+fn replace(
+    py: Python,
+    iterable: &PyAny,
+    pred: &PyAny,
+    substitutes: &PyAny,
+    count: Option<usize>,
+    window_size: usize,
+) -> PyResult<PyObject> {
+    if window_size == 0 {
+        return Err(PyValueError::new_err("window_size must be at least 1"));
+    }
+
+    let substitutes = PyIterator::from_object(substitutes)?
+        .collect::<PyResult<Vec<_>>>()?;
+    let items = PyIterator::from_object(iterable)?
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut result = Vec::with_capacity(items.len());
+    let mut replaced = 0;
+    let mut i = 0;
+
+    while i < items.len() {
+        if i + window_size <= items.len() && !matches!(count, Some(c) if replaced >= c) {
+            let window = PyTuple::new(py, &items[i..i + window_size]);
+            if pred.call1(window)?.is_true()? {
+                result.extend(substitutes.iter().copied());
+                replaced += 1;
+                i += window_size;
+                continue;
+            }
+        }
+        result.push(items[i]);
+        i += 1;
+    }
+
+    Ok(PyList::new(py, result).to_object(py))
+}
+
 /// Create sliding window over sequence
 #[pyfunction]
 #[pyo3(signature = (seq, n, fillvalue=None, step=1))]
@@ -248,6 +599,265 @@ fn all_unique(iterable: &PyAny) -> PyResult<bool> {
     Ok(true)
 }
 
+/// Check if all elements are equal, optionally after applying `key`
+///
+/// Empty and single-element iterables are all equal.
+#[pyfunction]
+#[pyo3(signature = (iterable, key=None))]
+fn all_equal(iterable: &PyAny, key: Option<&PyAny>) -> PyResult<bool> {
+    let apply = |item: &PyAny| -> PyResult<PyObject> {
+        match key {
+            Some(f) => Ok(f.call1((item,))?.into()),
+            None => Ok(item.into()),
+        }
+    };
+
+    let py = iterable.py();
+    let mut iter = PyIterator::from_object(iterable)?;
+    let first = match iter.next() {
+        Some(item) => apply(item?)?,
+        None => return Ok(true),
+    };
+
+    for item in iter {
+        if !apply(item?)?.as_ref(py).eq(&first)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Count the elements for which `pred` is true (truthy elements by default)
+#[pyfunction]
+#[pyo3(signature = (iterable, pred=None))]
+fn quantify(iterable: &PyAny, pred: Option<&PyAny>) -> PyResult<usize> {
+    let mut count = 0;
+    for item in PyIterator::from_object(iterable)? {
+        let item = item?;
+        let hit = match pred {
+            Some(f) => f.call1((item,))?.is_true()?,
+            None => item.is_true()?,
+        };
+        if hit {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// The elements of `iterable` repeated `n` times
+#[pyfunction]
+fn ncycles(py: Python, iterable: &PyAny, n: usize) -> PyResult<PyObject> {
+    let items = PyIterator::from_object(iterable)?.collect::<PyResult<Vec<_>>>()?;
+    let result = PyList::empty(py);
+    for _ in 0..n {
+        for item in &items {
+            result.append(item)?;
+        }
+    }
+    Ok(result.to_object(py))
+}
+
+/// An item in the weighted reservoir, ordered by its random key
+struct Keyed {
+    key: f64,
+    item: PyObject,
+}
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Keyed {}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.total_cmp(&other.key)
+    }
+}
+
+/// Return k elements chosen at random, without replacement
+///
+/// Uses reservoir sampling, so the input is read once and only k items are
+/// held at a time; streams need not fit in memory. Without weights each
+/// item replaces a reservoir slot with probability k/n (Algorithm R). With
+/// `weights` (an iterable zipped with the items, all positive), each item
+/// gets the key `random() ** (1 / weight)` and the k largest keys are kept
+/// (Efraimidis-Spirakis). Fewer than k items are all returned. `seed`
+/// makes the result reproducible; otherwise the `random` module's global
+/// generator is used.
+#[pyfunction]
+#[pyo3(signature = (iterable, k, weights=None, *, seed=None))]
+fn sample(
+    py: Python,
+    iterable: &PyAny,
+    k: usize,
+    weights: Option<&PyAny>,
+    seed: Option<&PyAny>,
+) -> PyResult<PyObject> {
+    let random = py.import("random")?;
+    let rng: &PyAny = match seed {
+        Some(seed) => random.getattr("Random")?.call1((seed,))?,
+        None => random,
+    };
+    let items = PyIterator::from_object(iterable)?;
+
+    let Some(weights) = weights else {
+        let mut reservoir: Vec<PyObject> = Vec::with_capacity(k);
+        for (i, item) in items.enumerate() {
+            let item = item?;
+            if i < k {
+                reservoir.push(item.into());
+                continue;
+            }
+            let j: usize = rng.call_method1("randrange", (i + 1,))?.extract()?;
+            if j < k {
+                reservoir[j] = item.into();
+            }
+        }
+        return Ok(PyList::new(py, reservoir).to_object(py));
+    };
+
+    if k == 0 {
+        return Ok(PyList::empty(py).to_object(py));
+    }
+    // Min-heap of the k largest keys seen so far
+    let mut reservoir: BinaryHeap<Reverse<Keyed>> = BinaryHeap::with_capacity(k);
+    for (item, weight) in items.zip(PyIterator::from_object(weights)?) {
+        let (item, weight) = (item?, weight?.extract::<f64>()?);
+        if !(weight > 0.0 && weight.is_finite()) {
+            return Err(PyValueError::new_err(format!(
+                "weights must be positive and finite, got {}",
+                weight
+            )));
+        }
+        let u: f64 = rng.call_method0("random")?.extract()?;
+        let key = u.powf(1.0 / weight);
+        if reservoir.len() < k {
+            reservoir.push(Reverse(Keyed { key, item: item.into() }));
+        } else if reservoir.peek().is_some_and(|Reverse(min)| key > min.key) {
+            reservoir.pop();
+            reservoir.push(Reverse(Keyed { key, item: item.into() }));
+        }
+    }
+
+    // Highest key first
+    let chosen: Vec<PyObject> = reservoir
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(keyed)| keyed.item)
+        .collect();
+    Ok(PyList::new(py, chosen).to_object(py))
+}
+
+/// All r-element subsets of `1..n`, in lexicographic order
+fn cut_combinations(n: usize, r: usize) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    if r >= n.max(1) {
+        return result;
+    }
+    let mut combo: Vec<usize> = (1..=r).collect();
+    loop {
+        result.push(combo.clone());
+        // Advance the rightmost position that still has room
+        let Some(i) = (0..r).rev().find(|&i| combo[i] < n - r + i) else {
+            return result;
+        };
+        combo[i] += 1;
+        for j in i + 1..r {
+            combo[j] = combo[j - 1] + 1;
+        }
+    }
+}
+
+/// All ways to split the items into contiguous, non-empty runs
+///
+/// An input of n items has 2 ** (n - 1) partitions, ordered by the number
+/// of cuts and then by cut position: `[1, 2, 3]` gives `[[1, 2, 3]]`,
+/// `[[1], [2, 3]]`, `[[1, 2], [3]]` and `[[1], [2], [3]]`.
+#[pyfunction]
+fn partitions(py: Python, iterable: &PyAny) -> PyResult<PyObject> {
+    let items = PyIterator::from_object(iterable)?.collect::<PyResult<Vec<_>>>()?;
+    let n = items.len();
+    let result = PyList::empty(py);
+    for r in 0..n.max(1) {
+        for cuts in cut_combinations(n, r) {
+            let bounds: Vec<usize> = std::iter::once(0).chain(cuts).chain(std::iter::once(n)).collect();
+            let runs = bounds.windows(2).map(|w| PyList::new(py, &items[w[0]..w[1]]));
+            result.append(PyList::new(py, runs))?;
+        }
+    }
+    Ok(result.to_object(py))
+}
+
+/// Partitions of item indices `start..n` into exactly `k` non-empty groups
+fn set_partitions_of(start: usize, n: usize, k: usize) -> Vec<Vec<Vec<usize>>> {
+    let len = n - start;
+    if k == 1 {
+        return vec![vec![(start..n).collect()]];
+    }
+    if len == k {
+        return vec![(start..n).map(|i| vec![i]).collect()];
+    }
+    let mut result = Vec::new();
+    // The first item on its own, beside every (k-1)-partition of the rest
+    for mut rest in set_partitions_of(start + 1, n, k - 1) {
+        rest.insert(0, vec![start]);
+        result.push(rest);
+    }
+    // The first item added to each group of every k-partition of the rest
+    for rest in set_partitions_of(start + 1, n, k) {
+        for i in 0..rest.len() {
+            let mut partition = rest.clone();
+            partition[i].insert(0, start);
+            result.push(partition);
+        }
+    }
+    result
+}
+
+/// All partitions of the items into non-empty groups, in any order
+///
+/// With `k`, only partitions into exactly `k` groups are returned. The
+/// counts grow quickly: n items have Bell(n) partitions (52 for 5 items,
+/// 115975 for 10) and Stirling(n, k) of them have `k` groups, all of which
+/// are built in memory.
+#[pyfunction]
+#[pyo3(signature = (iterable, k=None))]
+fn set_partitions(py: Python, iterable: &PyAny, k: Option<usize>) -> PyResult<PyObject> {
+    let items = PyIterator::from_object(iterable)?.collect::<PyResult<Vec<_>>>()?;
+    let n = items.len();
+    let sizes = match k {
+        Some(0) => {
+            return Err(PyValueError::new_err(
+                "Can't partition in a negative or zero number of groups",
+            ))
+        }
+        Some(k) if k > n => 0..0,
+        Some(k) => k..k + 1,
+        None => 1..n + 1,
+    };
+    let result = PyList::empty(py);
+    for k in sizes {
+        for partition in set_partitions_of(0, n, k) {
+            let groups = partition
+                .iter()
+                .map(|group| PyList::new(py, group.iter().map(|&i| items[i])));
+            result.append(PyList::new(py, groups))?;
+        }
+    }
+    Ok(result.to_object(py))
+}
+
 /// Interleave multiple iterables
 #[pyfunction]
 fn interleave(py: Python, iterables: &PyTuple) -> PyResult<PyObject> {
@@ -277,6 +887,439 @@ fn interleave(py: Python, iterables: &PyTuple) -> PyResult<PyObject> {
     Ok(PyList::new(py, result).to_object(py))
 }
 
+/// Zip iterables, raising UnequalIterablesError if their lengths differ
+#[pyfunction]
+#[pyo3(signature = (*iterables))]
+fn zip_equal(py: Python, iterables: &PyTuple) -> PyResult<PyObject> {
+    let mut iters: Vec<_> = iterables
+        .iter()
+        .map(PyIterator::from_object)
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut result = Vec::new();
+
+    loop {
+        let mut tuple = Vec::with_capacity(iters.len());
+        for iter in &mut iters {
+            if let Some(item) = iter.next() {
+                tuple.push(item?);
+            }
+        }
+
+        if tuple.is_empty() {
+            break;
+        }
+        if tuple.len() < iters.len() {
+            return Err(UnequalIterablesError::new_err("Iterables have different lengths"));
+        }
+        result.push(PyTuple::new(py, &tuple).to_object(py));
+    }
+
+    Ok(PyList::new(py, result).to_object(py))
+}
+
+/// Zip iterables after shifting each by its offset
+///
+/// A positive offset skips items at the start of that iterable; a negative
+/// one pads its start with `fillvalue`.
+#[pyfunction]
+#[pyo3(signature = (*iterables, offsets, longest=false, fillvalue=None))]
+fn zip_offset(
+    py: Python,
+    iterables: &PyTuple,
+    offsets: Vec<isize>,
+    longest: bool,
+    fillvalue: Option<PyObject>,
+) -> PyResult<PyObject> {
+    if iterables.len() != offsets.len() {
+        return Err(PyValueError::new_err("Number of iterables and offsets didn't match"));
+    }
+
+    let fillvalue = fillvalue.unwrap_or_else(|| py.None());
+    let mut columns: Vec<VecDeque<PyObject>> = Vec::with_capacity(iterables.len());
+
+    for (iterable, &offset) in iterables.iter().zip(&offsets) {
+        let mut column: VecDeque<PyObject> = PyIterator::from_object(iterable)?
+            .skip(offset.max(0) as usize)
+            .map(|item| item.map(|i| i.to_object(py)))
+            .collect::<PyResult<_>>()?;
+        for _ in 0..(-offset).max(0) {
+            column.push_front(fillvalue.clone_ref(py));
+        }
+        columns.push(column);
+    }
+
+    let lengths = columns.iter().map(|c| c.len());
+    let rows = if longest { lengths.max() } else { lengths.min() }.unwrap_or(0);
+
+    let result: Vec<PyObject> = (0..rows)
+        .map(|i| {
+            let tuple: Vec<PyObject> = columns
+                .iter()
+                .map(|c| c.get(i).unwrap_or(&fillvalue).clone_ref(py))
+                .collect();
+            PyTuple::new(py, tuple).to_object(py)
+        })
+        .collect();
+
+    Ok(PyList::new(py, result).to_object(py))
+}
+
+/// Inverse of zip: split an iterable of tuples into a tuple of lists
+///
+/// The number of lists comes from the first item; each list stops at the
+/// first item too short to contribute to it.
+#[pyfunction]
+fn unzip(py: Python, iterable: &PyAny) -> PyResult<PyObject> {
+    let rows: Vec<Vec<&PyAny>> = PyIterator::from_object(iterable)?
+        .map(|row| row.and_then(|r| PyIterator::from_object(r)?.collect::<PyResult<Vec<_>>>()))
+        .collect::<PyResult<_>>()?;
+
+    let width = rows.first().map_or(0, |r| r.len());
+    let columns: Vec<PyObject> = (0..width)
+        .map(|i| {
+            let column: Vec<&PyAny> = rows.iter().map_while(|r| r.get(i).copied()).collect();
+            PyList::new(py, column).to_object(py)
+        })
+        .collect();
+
+    Ok(PyTuple::new(py, columns).to_object(py))
+}
+
+/// Like `range`, but for any numeric type, including float steps
+///
+/// Accepts `(stop)`, `(start, stop)` or `(start, stop, step)`. Each item is
+/// computed as `start + i * step` rather than by repeated addition, so float
+/// ranges don't drift.
+#[pyfunction]
+#[pyo3(signature = (*args))]
+fn numeric_range(py: Python, args: &PyTuple) -> PyResult<PyObject> {
+    let (start, stop, step): (&PyAny, &PyAny, Option<&PyAny>) = match args.len() {
+        1 => {
+            let stop = args.get_item(0)?;
+            (stop.get_type().call1((0,))?, stop, None)
+        }
+        2 => (args.get_item(0)?, args.get_item(1)?, None),
+        3 => (args.get_item(0)?, args.get_item(1)?, Some(args.get_item(2)?)),
+        n => {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "numeric_range expected at most 3 arguments, got {}",
+                n
+            )))
+        }
+    };
+
+    let operator = py.import("operator")?;
+    let add = operator.getattr("add")?;
+    let mul = operator.getattr("mul")?;
+    let step = match step {
+        Some(step) => step,
+        None => operator
+            .getattr("sub")?
+            .call1((stop, start))?
+            .get_type()
+            .call1((1,))?,
+    };
+    if step.eq(0)? {
+        return Err(PyValueError::new_err("numeric_range() arg 3 must not be zero"));
+    }
+    let ascending = step.gt(0)?;
+
+    let result = PyList::empty(py);
+    for i in 0usize.. {
+        let value = add.call1((start, mul.call1((step, i))?))?;
+        let in_range = if ascending { value.lt(stop)? } else { value.gt(stop)? };
+        if !in_range {
+            break;
+        }
+        result.append(value)?;
+    }
+
+    Ok(result.to_object(py))
+}
+
+/// Inverse of `itertools.accumulate`: differences between consecutive items
+///
+/// `func(later, earlier)` defaults to subtraction. The first item is kept
+/// as-is unless `initial` is given, mirroring `accumulate(..., initial=)`.
+#[pyfunction]
+#[pyo3(signature = (iterable, func=None, initial=None))]
+fn difference(
+    py: Python,
+    iterable: &PyAny,
+    func: Option<&PyAny>,
+    initial: Option<&PyAny>,
+) -> PyResult<PyObject> {
+    let func = match func {
+        Some(func) => func,
+        None => py.import("operator")?.getattr("sub")?,
+    };
+    let result = PyList::empty(py);
+    let mut iter = PyIterator::from_object(iterable)?;
+
+    let mut prev = match iter.next() {
+        Some(item) => item?,
+        None => return Ok(result.to_object(py)),
+    };
+    if initial.is_none() {
+        result.append(prev)?;
+    }
+
+    for item in iter {
+        let item = item?;
+        result.append(func.call1((item, prev))?)?;
+        prev = item;
+    }
+
+    Ok(result.to_object(py))
+}
+
+/// Sum of elementwise products, `sum(map(operator.mul, vec1, vec2))`
+///
+/// Works with any numbers supporting `*` and `+` (ints, floats, Decimals).
+/// Raises ValueError if the inputs differ in length.
+#[pyfunction]
+fn dotproduct(py: Python, vec1: &PyAny, vec2: &PyAny) -> PyResult<PyObject> {
+    let vec1 = PyIterator::from_object(vec1)?.collect::<PyResult<Vec<_>>>()?;
+    let vec2 = PyIterator::from_object(vec2)?.collect::<PyResult<Vec<_>>>()?;
+    if vec1.len() != vec2.len() {
+        return Err(PyValueError::new_err(format!(
+            "dotproduct() arguments have different lengths: {} and {}",
+            vec1.len(),
+            vec2.len()
+        )));
+    }
+
+    let operator = py.import("operator")?;
+    let add = operator.getattr("add")?;
+    let mul = operator.getattr("mul")?;
+    let mut total = 0.to_object(py).into_ref(py);
+    for (x, y) in vec1.into_iter().zip(vec2) {
+        total = add.call1((total, mul.call1((x, y))?))?;
+    }
+    Ok(total.to_object(py))
+}
+
+/// Sum of the squares of the items, starting from 0
+#[pyfunction]
+fn sum_of_squares(py: Python, iterable: &PyAny) -> PyResult<PyObject> {
+    let operator = py.import("operator")?;
+    let add = operator.getattr("add")?;
+    let mul = operator.getattr("mul")?;
+    let mut total = 0.to_object(py).into_ref(py);
+    for item in PyIterator::from_object(iterable)? {
+        let item = item?;
+        total = add.call1((total, mul.call1((item, item))?))?;
+    }
+    Ok(total.to_object(py))
+}
+
+/// Discrete convolution of `signal` with `kernel`
+///
+/// Returns `len(signal) + len(kernel) - 1` values, where item `n` is the sum
+/// of `kernel[k] * signal[n - k]`; empty if either input is empty.
+#[pyfunction]
+fn convolve(py: Python, signal: &PyAny, kernel: &PyAny) -> PyResult<PyObject> {
+    let signal = PyIterator::from_object(signal)?.collect::<PyResult<Vec<_>>>()?;
+    let kernel = PyIterator::from_object(kernel)?.collect::<PyResult<Vec<_>>>()?;
+    let result = PyList::empty(py);
+    if signal.is_empty() || kernel.is_empty() {
+        return Ok(result.to_object(py));
+    }
+
+    let operator = py.import("operator")?;
+    let add = operator.getattr("add")?;
+    let mul = operator.getattr("mul")?;
+    for n in 0..signal.len() + kernel.len() - 1 {
+        let first = n.saturating_sub(signal.len() - 1);
+        let last = n.min(kernel.len() - 1);
+        let mut total = 0.to_object(py).into_ref(py);
+        for k in (first..=last).rev() {
+            total = add.call1((total, mul.call1((kernel[k], signal[n - k]))?))?;
+        }
+        result.append(total)?;
+    }
+    Ok(result.to_object(py))
+}
+
+/// Run-length encoding, mirroring `more_itertools.run_length`
+#[pyclass(name = "run_length")]
+struct RunLength;
+
+#[pymethods]
+impl RunLength {
+    /// Compress consecutive equal items into `(item, count)` pairs
+    #[staticmethod]
+    fn encode(py: Python, iterable: &PyAny) -> PyResult<PyObject> {
+        let mut runs: Vec<(&PyAny, usize)> = Vec::new();
+        for item in PyIterator::from_object(iterable)? {
+            let item = item?;
+            match runs.last_mut() {
+                Some((current, count)) if current.eq(item)? => *count += 1,
+                _ => runs.push((item, 1)),
+            }
+        }
+        Ok(runs.to_object(py))
+    }
+
+    /// Expand `(item, count)` pairs back into a flat list
+    #[staticmethod]
+    fn decode(py: Python, iterable: &PyAny) -> PyResult<PyObject> {
+        let result = PyList::empty(py);
+        for pair in PyIterator::from_object(iterable)? {
+            let (item, count): (&PyAny, usize) = pair?.extract()?;
+            for _ in 0..count {
+                result.append(item)?;
+            }
+        }
+        Ok(result.to_object(py))
+    }
+}
+
+/// Sort parallel iterables together by the columns in `key_list`
+///
+/// Rows are ordered by the tuple of their `key_list` columns, or by
+/// `key(*those columns)` when `key` is given. The sort is stable and columns
+/// are truncated to the shortest input, as with `zip`.
+#[pyfunction]
+#[pyo3(signature = (iterables, key_list=vec![0], key=None, reverse=false))]
+fn sort_together(
+    py: Python,
+    iterables: &PyAny,
+    key_list: Vec<usize>,
+    key: Option<&PyAny>,
+    reverse: bool,
+) -> PyResult<PyObject> {
+    let columns: Vec<Vec<&PyAny>> = PyIterator::from_object(iterables)?
+        .map(|column| column.and_then(|c| PyIterator::from_object(c)?.collect::<PyResult<Vec<_>>>()))
+        .collect::<PyResult<_>>()?;
+    let len = columns.iter().map(Vec::len).min().unwrap_or(0);
+
+    let keys = PyList::empty(py);
+    for row in 0..len {
+        let fields = key_list
+            .iter()
+            .map(|&k| {
+                columns.get(k).map(|column| column[row]).ok_or_else(|| {
+                    pyo3::exceptions::PyIndexError::new_err(format!("key_list index {} out of range", k))
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let fields = PyTuple::new(py, fields);
+        keys.append(match key {
+            Some(key) => key.call1(fields)?,
+            None => fields.as_ref(),
+        })?;
+    }
+
+    let kwargs = pyo3::types::PyDict::new(py);
+    kwargs.set_item("key", keys.getattr("__getitem__")?)?;
+    kwargs.set_item("reverse", reverse)?;
+    let order: Vec<usize> = py
+        .import("builtins")?
+        .getattr("sorted")?
+        .call(((0..len).collect::<Vec<_>>(),), Some(kwargs))?
+        .extract()?;
+
+    let result: Vec<PyObject> = columns
+        .iter()
+        .map(|column| PyTuple::new(py, order.iter().map(|&i| column[i])).to_object(py))
+        .collect();
+    Ok(PyList::new(py, result).to_object(py))
+}
+
+/// Group consecutive items by key, transforming each group
+///
+/// Returns `(key, group)` pairs in input order, where `group` is the list of
+/// `valuefunc(item)` for the run of items sharing that key, or
+/// `reducefunc(group)` when given. Like `itertools.groupby`, equal keys that
+/// are not adjacent form separate groups, so sort the input first if needed.
+#[pyfunction]
+#[pyo3(signature = (iterable, keyfunc=None, valuefunc=None, reducefunc=None))]
+fn groupby_transform(
+    py: Python,
+    iterable: &PyAny,
+    keyfunc: Option<&PyAny>,
+    valuefunc: Option<&PyAny>,
+    reducefunc: Option<&PyAny>,
+) -> PyResult<PyObject> {
+    let mut groups: Vec<(&PyAny, &PyList)> = Vec::new();
+    for item in PyIterator::from_object(iterable)? {
+        let item = item?;
+        let key = match keyfunc {
+            Some(f) => f.call1((item,))?,
+            None => item,
+        };
+        let value = match valuefunc {
+            Some(f) => f.call1((item,))?,
+            None => item,
+        };
+        match groups.last() {
+            Some((current, group)) if current.eq(key)? => group.append(value)?,
+            _ => groups.push((key, PyList::new(py, [value]))),
+        }
+    }
+
+    let result = groups
+        .into_iter()
+        .map(|(key, group)| {
+            let group = match reducefunc {
+                Some(f) => f.call1((group,))?,
+                None => group,
+            };
+            Ok((key, group).to_object(py))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(PyList::new(py, result).to_object(py))
+}
+
+/// Indices of items for which `pred` is true
+///
+/// `pred` defaults to `bool`. With `window_size > 1`, each window of that
+/// many consecutive items is passed to `pred` as separate arguments and the
+/// window's start index is reported.
+#[pyfunction]
+#[pyo3(signature = (iterable, pred=None, window_size=1))]
+fn locate(py: Python, iterable: &PyAny, pred: Option<&PyAny>, window_size: usize) -> PyResult<Vec<usize>> {
+    if window_size == 0 {
+        return Err(PyValueError::new_err("window_size must be at least 1"));
+    }
+    let pred = match pred {
+        Some(pred) => pred,
+        None => py.import("builtins")?.getattr("bool")?,
+    };
+
+    let mut items: Vec<PyObject> = PyIterator::from_object(iterable)?
+        .map(|item| item.map(|item| item.to_object(py)))
+        .collect::<PyResult<_>>()?;
+    if items.is_empty() {
+        return Ok(Vec::new());
+    }
+    // Like `windowed`, a sequence shorter than the window gives a single
+    // window padded with a sentinel that compares unequal to everything
+    if items.len() < window_size {
+        let sentinel = py.import("builtins")?.getattr("object")?.call0()?.to_object(py);
+        items.resize(window_size, sentinel);
+    }
+
+    let mut indices = Vec::new();
+    for (i, window) in items.windows(window_size).enumerate() {
+        if pred.call1(PyTuple::new(py, window))?.is_true()? {
+            indices.push(i);
+        }
+    }
+    Ok(indices)
+}
+
+/// Like `locate`, but with indices in descending order
+#[pyfunction]
+#[pyo3(signature = (iterable, pred=None, window_size=1))]
+fn rlocate(py: Python, iterable: &PyAny, pred: Option<&PyAny>, window_size: usize) -> PyResult<Vec<usize>> {
+    let mut indices = locate(py, iterable, pred, window_size)?;
+    indices.reverse();
+    Ok(indices)
+}
+
 /// Count occurrences of each element
 #[pyfunction]
 fn count_items(py: Python, iterable: &PyAny) -> PyResult<PyObject> {
@@ -334,17 +1377,48 @@ fn more_itertools_rs(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(chunked, m)?)?;
     m.add_function(wrap_pyfunction!(batched, m)?)?;
     m.add_function(wrap_pyfunction!(flatten, m)?)?;
+    m.add_function(wrap_pyfunction!(value_chain, m)?)?;
     m.add_function(wrap_pyfunction!(first, m)?)?;
     m.add_function(wrap_pyfunction!(last, m)?)?;
     m.add_function(wrap_pyfunction!(take, m)?)?;
+    m.add_function(wrap_pyfunction!(strictly_n, m)?)?;
+    m.add_function(wrap_pyfunction!(tail, m)?)?;
+    m.add_function(wrap_pyfunction!(prepend, m)?)?;
+    m.add_function(wrap_pyfunction!(append, m)?)?;
+    m.add_function(wrap_pyfunction!(padded, m)?)?;
+    m.add_function(wrap_pyfunction!(iterate, m)?)?;
+    m.add_function(wrap_pyfunction!(repeatfunc, m)?)?;
+    m.add_function(wrap_pyfunction!(tabulate, m)?)?;
     m.add_function(wrap_pyfunction!(unique_everseen, m)?)?;
+    m.add_function(wrap_pyfunction!(duplicates_everseen, m)?)?;
     m.add_function(wrap_pyfunction!(partition, m)?)?;
+    m.add_function(wrap_pyfunction!(replace, m)?)?;
     m.add_function(wrap_pyfunction!(windowed, m)?)?;
     m.add_function(wrap_pyfunction!(all_unique, m)?)?;
+    m.add_function(wrap_pyfunction!(all_equal, m)?)?;
+    m.add_function(wrap_pyfunction!(quantify, m)?)?;
+    m.add_function(wrap_pyfunction!(ncycles, m)?)?;
+    m.add_function(wrap_pyfunction!(sample, m)?)?;
+    m.add_function(wrap_pyfunction!(partitions, m)?)?;
+    m.add_function(wrap_pyfunction!(set_partitions, m)?)?;
     m.add_function(wrap_pyfunction!(interleave, m)?)?;
+    m.add_function(wrap_pyfunction!(zip_equal, m)?)?;
+    m.add_function(wrap_pyfunction!(zip_offset, m)?)?;
+    m.add_function(wrap_pyfunction!(unzip, m)?)?;
+    m.add_function(wrap_pyfunction!(numeric_range, m)?)?;
+    m.add_function(wrap_pyfunction!(difference, m)?)?;
+    m.add_function(wrap_pyfunction!(dotproduct, m)?)?;
+    m.add_function(wrap_pyfunction!(sum_of_squares, m)?)?;
+    m.add_function(wrap_pyfunction!(convolve, m)?)?;
+    m.add_class::<RunLength>()?;
+    m.add_function(wrap_pyfunction!(sort_together, m)?)?;
+    m.add_function(wrap_pyfunction!(groupby_transform, m)?)?;
+    m.add_function(wrap_pyfunction!(locate, m)?)?;
+    m.add_function(wrap_pyfunction!(rlocate, m)?)?;
     m.add_function(wrap_pyfunction!(count_items, m)?)?;
     m.add_function(wrap_pyfunction!(is_sorted, m)?)?;
 
+    m.add("UnequalIterablesError", _py.get_type::<UnequalIterablesError>())?;
     m.add("__version__", "0.1.0")?;
 
     Ok(())
@@ -134,6 +134,41 @@ class TestPartition:
         assert false_items == [1, 3, 5]
         assert true_items == []
 
+    def test_none_predicate(self):
+        false_items, true_items = mit.partition(None, [0, 1, '', 'a', None])
+        assert false_items == [0, '', None]
+        assert true_items == [1, 'a']
+
+    def test_lazy(self):
+        false_items, true_items = mit.partition(lambda x: x % 2, iter(range(6)), lazy=True)
+        assert next(true_items) == 1
+        assert list(false_items) == [0, 2, 4]
+        assert list(true_items) == [3, 5]
+
+    def test_lazy_infinite(self):
+        import itertools
+        _, true_items = mit.partition(lambda x: x > 5, itertools.count(), lazy=True)
+        assert next(true_items) == 6
+
+
+class TestReplace:
+    def test_basic(self):
+        result = mit.replace([1, 1, 0, 1, 1, 0, 1, 1], lambda x: x == 0, (2, 3))
+        assert result == [1, 1, 2, 3, 1, 1, 2, 3, 1, 1]
+
+    def test_count(self):
+        result = mit.replace([1, 1, 0, 1, 1, 0, 1, 1], lambda x: x == 0, (2, 3), count=1)
+        assert result == [1, 1, 2, 3, 1, 1, 0, 1, 1]
+
+    def test_window_size(self):
+        pred = lambda *args: args == (0, 1, 2)
+        result = mit.replace([0, 1, 2, 5, 0, 1, 2, 5], pred, [3, 4], window_size=3)
+        assert result == [3, 4, 5, 3, 4, 5]
+
+    def test_zero_window_size(self):
+        with pytest.raises(ValueError):
+            mit.replace([1, 2], lambda x: True, [], window_size=0)
+
 
 class TestWindowed:
     def test_basic(self):
@@ -178,6 +213,400 @@ class TestInterleave:
         assert result == [1, 'a', 'x', 2, 'b', 'y']
 
 
+class TestZipEqual:
+    def test_basic(self):
+        assert mit.zip_equal([1, 2, 3], 'abc') == [(1, 'a'), (2, 'b'), (3, 'c')]
+
+    def test_unequal(self):
+        with pytest.raises(mit.UnequalIterablesError):
+            mit.zip_equal([1, 2, 3], 'ab')
+
+    def test_is_value_error(self):
+        with pytest.raises(ValueError):
+            mit.zip_equal([1], [])
+
+
+class TestZipOffset:
+    def test_basic(self):
+        result = mit.zip_offset('0123', 'abcdef', offsets=(0, 1))
+        assert result == [('0', 'b'), ('1', 'c'), ('2', 'd'), ('3', 'e')]
+
+    def test_longest(self):
+        result = mit.zip_offset('0123', 'abcdef', offsets=(0, 1), longest=True)
+        assert result[-1] == (None, 'f')
+
+    def test_negative_offset(self):
+        result = mit.zip_offset('01', 'ab', offsets=(-1, 0), fillvalue='x')
+        assert result == [('x', 'a'), ('0', 'b')]
+
+    def test_mismatched_offsets(self):
+        with pytest.raises(ValueError):
+            mit.zip_offset('01', 'ab', offsets=(0,))
+
+
+class TestUnzip:
+    def test_basic(self):
+        assert mit.unzip([(1, 'a'), (2, 'b')]) == ([1, 2], ['a', 'b'])
+
+    def test_ragged(self):
+        assert mit.unzip([(1, 'a'), (2, 'b'), (3,)]) == ([1, 2, 3], ['a', 'b'])
+
+    def test_empty(self):
+        assert mit.unzip([]) == ()
+
+
+class TestNumericRange:
+    def test_stop_only(self):
+        assert mit.numeric_range(4) == [0, 1, 2, 3]
+
+    def test_float_step(self):
+        assert mit.numeric_range(0, 1, 0.25) == [0, 0.25, 0.5, 0.75]
+
+    def test_negative_step(self):
+        assert mit.numeric_range(1.0, 0.0, -0.5) == [1.0, 0.5]
+
+    def test_no_drift(self):
+        # Repeated addition of 0.1 would give 0.9999999999999999 as an 11th item
+        result = mit.numeric_range(0, 1, 0.1)
+        assert len(result) == 10
+        assert result[-1] == 9 * 0.1
+
+    def test_fractions(self):
+        from fractions import Fraction
+        result = mit.numeric_range(Fraction(0), Fraction(1), Fraction(1, 3))
+        assert result == [0, Fraction(1, 3), Fraction(2, 3)]
+
+    def test_zero_step(self):
+        with pytest.raises(ValueError):
+            mit.numeric_range(0, 1, 0)
+
+
+class TestDifference:
+    def test_basic(self):
+        assert mit.difference([0, 1, 3, 6, 10]) == [0, 1, 2, 3, 4]
+
+    def test_custom_func(self):
+        assert mit.difference([1, 2, 6, 24], lambda x, y: x // y) == [1, 2, 3, 4]
+
+    def test_initial(self):
+        assert mit.difference([10, 11, 13], initial=10) == [1, 2]
+
+    def test_empty(self):
+        assert mit.difference([]) == []
+
+
+class TestNumericRecipes:
+    def test_dotproduct(self):
+        assert mit.dotproduct([1, 2, 3], [4, 5, 6]) == 32
+        assert mit.dotproduct([], []) == 0
+
+    def test_dotproduct_decimal(self):
+        from decimal import Decimal
+        assert mit.dotproduct([Decimal("1.5")], [Decimal("2")]) == Decimal("3.0")
+
+    def test_dotproduct_length_mismatch(self):
+        with pytest.raises(ValueError):
+            mit.dotproduct([1, 2], [1])
+
+    def test_sum_of_squares(self):
+        assert mit.sum_of_squares([1, 2, 3]) == 14
+        assert mit.sum_of_squares([]) == 0
+
+    def test_convolve(self):
+        assert mit.convolve([1, 2, 3], [1, 1]) == [1, 3, 5, 3]
+        assert mit.convolve([1, 2, 3, 4, 5], [0.25] * 4) == [
+            0.25, 0.75, 1.5, 2.5, 3.5, 3.0, 2.25, 1.25
+        ]
+
+    def test_convolve_empty(self):
+        assert mit.convolve([], [1]) == []
+        assert mit.convolve([1], []) == []
+
+
+class TestRunLength:
+    def test_encode(self):
+        assert mit.run_length.encode("aaabbc") == [("a", 3), ("b", 2), ("c", 1)]
+
+    def test_encode_uses_equality(self):
+        assert mit.run_length.encode([1, 1.0, 2]) == [(1, 2), (2, 1)]
+
+    def test_encode_empty(self):
+        assert mit.run_length.encode([]) == []
+
+    def test_encode_single(self):
+        assert mit.run_length.encode([7]) == [(7, 1)]
+
+    def test_decode(self):
+        assert mit.run_length.decode([("a", 3), ("b", 1)]) == ["a", "a", "a", "b"]
+        assert mit.run_length.decode([]) == []
+
+    def test_round_trip(self):
+        data = list("aaabccccd")
+        assert mit.run_length.decode(mit.run_length.encode(data)) == data
+
+
+class TestSortTogether:
+    def test_default_key(self):
+        result = mit.sort_together([(3, 1, 2), ("c", "a", "b")])
+        assert result == [(1, 2, 3), ("a", "b", "c")]
+
+    def test_key_list_and_reverse(self):
+        result = mit.sort_together([(1, 2, 1), (3, 1, 2), "abc"], key_list=(0, 1), reverse=True)
+        assert result == [(2, 1, 1), (1, 3, 2), ("b", "a", "c")]
+
+    def test_key(self):
+        result = mit.sort_together([(1, 2, 3), (3, 1, 2)], key_list=(1,), key=lambda x: -x)
+        assert result == [(1, 3, 2), (3, 2, 1)]
+
+    def test_truncates_to_shortest(self):
+        assert mit.sort_together([(2, 1, 3), "ab"]) == [(1, 2), ("b", "a")]
+
+
+class TestGroupbyTransform:
+    def test_default(self):
+        assert mit.groupby_transform([1, 1, 2, 1]) == [(1, [1, 1]), (2, [2]), (1, [1])]
+
+    def test_funcs(self):
+        result = mit.groupby_transform(
+            "AaaABbBCcA", lambda x: x.upper(), lambda x: x.lower(), "".join
+        )
+        assert result == [("A", "aaaa"), ("B", "bbb"), ("C", "cc"), ("A", "a")]
+
+    def test_empty(self):
+        assert mit.groupby_transform([]) == []
+
+
+class TestLocate:
+    def test_default_pred(self):
+        assert mit.locate([0, 1, 1, 0, 1, 0, 0]) == [1, 2, 4]
+
+    def test_pred(self):
+        assert mit.locate("abcb", lambda x: x == "b") == [1, 3]
+
+    def test_window_size(self):
+        data = [0, 1, 2, 3, 0, 1, 2, 3]
+        pred = lambda *window: window == (1, 2, 3)
+        assert mit.locate(data, pred, window_size=3) == [1, 5]
+        assert mit.rlocate(data, pred, window_size=3) == [5, 1]
+
+    def test_rlocate(self):
+        assert mit.rlocate([0, 1, 1, 0, 1, 0, 0]) == [4, 2, 1]
+
+    def test_empty_and_no_match(self):
+        assert mit.locate([]) == []
+        assert mit.rlocate([]) == []
+        assert mit.locate([0, 0, 0]) == []
+        assert mit.locate([1, 2], lambda *w: False, window_size=3) == []
+
+    def test_zero_window(self):
+        with pytest.raises(ValueError):
+            mit.locate([1], window_size=0)
+
+
+class TestTail:
+    def test_tail(self):
+        assert mit.tail(3, range(10)) == [7, 8, 9]
+
+    def test_shorter_than_n(self):
+        assert mit.tail(5, [1, 2]) == [1, 2]
+
+    def test_zero(self):
+        assert mit.tail(0, range(3)) == []
+
+    def test_iterator(self):
+        assert mit.tail(2, iter(range(100000))) == [99998, 99999]
+
+
+class TestPrependAppend:
+    def test_prepend(self):
+        assert mit.prepend(0, [1, 2]) == [0, 1, 2]
+        assert mit.prepend(0, []) == [0]
+
+    def test_append(self):
+        assert mit.append(3, (1, 2)) == [1, 2, 3]
+        assert mit.append(3, []) == [3]
+
+
+class TestPadded:
+    def test_pad_to_n(self):
+        assert mit.padded([1, 2, 3], "?", 5) == [1, 2, 3, "?", "?"]
+        assert mit.padded([1, 2, 3], n=2) == [1, 2, 3]
+
+    def test_next_multiple(self):
+        assert mit.padded([1, 2, 3, 4], n=3, next_multiple=True) == [1, 2, 3, 4, None, None]
+        assert mit.padded([1, 2, 3], n=3, next_multiple=True) == [1, 2, 3]
+        assert mit.padded([], n=3, next_multiple=True) == []
+
+    def test_without_n(self):
+        assert mit.padded([1, 2]) == [1, 2]
+
+    def test_invalid_n(self):
+        with pytest.raises(ValueError):
+            mit.padded([1], n=0)
+
+
+class TestFunctionSequences:
+    def test_iterate(self):
+        assert mit.iterate(lambda x: x * 2, 1, 5) == [1, 2, 4, 8, 16]
+        assert mit.iterate(lambda x: x * 2, 1, 0) == []
+
+    def test_repeatfunc(self):
+        assert mit.repeatfunc(pow, 3, 2, 5) == [32, 32, 32]
+        calls = []
+        assert mit.repeatfunc(lambda: calls.append(1) or len(calls), 3) == [1, 2, 3]
+
+    def test_tabulate(self):
+        assert mit.tabulate(lambda x: x * x, n=4) == [0, 1, 4, 9]
+        assert mit.tabulate(str, -1, n=3) == ["-1", "0", "1"]
+
+    def test_tabulate_requires_n(self):
+        with pytest.raises(TypeError):
+            mit.tabulate(str)
+
+
+class TestRecipes:
+    def test_all_equal(self):
+        assert mit.all_equal([1, 1, 1]) is True
+        assert mit.all_equal([1, 1, 2]) is False
+        assert mit.all_equal(iter("aaa")) is True
+
+    def test_all_equal_empty_and_single(self):
+        assert mit.all_equal([]) is True
+        assert mit.all_equal([5]) is True
+
+    def test_all_equal_key(self):
+        assert mit.all_equal(["a", "A"], key=str.lower) is True
+        assert mit.all_equal(["a", "b"], key=str.lower) is False
+
+    def test_quantify(self):
+        assert mit.quantify([0, 1, "", "x", None, True]) == 3
+        assert mit.quantify(range(10), pred=lambda x: x % 2 == 0) == 5
+        assert mit.quantify([]) == 0
+
+    def test_ncycles(self):
+        assert mit.ncycles([1, 2], 3) == [1, 2, 1, 2, 1, 2]
+        assert mit.ncycles(iter("ab"), 2) == ["a", "b", "a", "b"]
+        assert mit.ncycles([1, 2], 0) == []
+        assert mit.ncycles([], 5) == []
+
+
+class TestStrictlyN:
+    def test_exact(self):
+        assert mit.strictly_n([1, 2, 3], 3) == [1, 2, 3]
+        assert mit.strictly_n(iter([]), 0) == []
+
+    def test_too_short(self):
+        with pytest.raises(ValueError):
+            mit.strictly_n([1, 2], 3)
+
+    def test_too_long(self):
+        with pytest.raises(ValueError):
+            mit.strictly_n([1, 2, 3, 4], 3)
+
+    def test_stops_after_n_plus_one(self):
+        it = iter(range(10))
+        with pytest.raises(ValueError):
+            mit.strictly_n(it, 2)
+        assert next(it) == 3
+
+
+class TestDuplicatesEverseen:
+    def test_every_repeat(self):
+        assert mit.duplicates_everseen("mississippi") == list("sissipi")
+        assert mit.duplicates_everseen([1, 2, 1, 1, 3, 2]) == [1, 1, 2]
+
+    def test_key(self):
+        assert mit.duplicates_everseen(["a", "A", "b", "B", "a"], key=str.lower) == ["A", "B", "a"]
+
+    def test_unhashable(self):
+        assert mit.duplicates_everseen([[1], [2], [1], [1]]) == [[1], [1]]
+
+    def test_empty(self):
+        assert mit.duplicates_everseen([]) == []
+
+
+class TestValueChain:
+    def test_mixed(self):
+        assert mit.value_chain(1, 2, [3, 4], "56") == [1, 2, 3, 4, "56"]
+
+    def test_bytes_are_scalars(self):
+        assert mit.value_chain(b"ab", bytearray(b"c"), (5,)) == [b"ab", bytearray(b"c"), 5]
+
+    def test_one_level(self):
+        assert mit.value_chain([[1, 2]], iter([3])) == [[1, 2], 3]
+        assert mit.value_chain() == []
+
+
+class TestPartitions:
+    def test_small(self):
+        assert mit.partitions([1, 2, 3]) == [
+            [[1, 2, 3]],
+            [[1], [2, 3]],
+            [[1, 2], [3]],
+            [[1], [2], [3]],
+        ]
+
+    def test_counts(self):
+        assert [len(mit.partitions(range(n))) for n in range(1, 8)] == [1, 2, 4, 8, 16, 32, 64]
+
+    def test_edges(self):
+        assert mit.partitions([]) == [[[]]]
+        assert mit.partitions("a") == [[["a"]]]
+
+
+class TestSetPartitions:
+    def test_small(self):
+        assert mit.set_partitions([1, 2, 3]) == [
+            [[1, 2, 3]],
+            [[1], [2, 3]],
+            [[1, 2], [3]],
+            [[2], [1, 3]],
+            [[1], [2], [3]],
+        ]
+
+    def test_bell_numbers(self):
+        assert [len(mit.set_partitions(range(n))) for n in range(1, 8)] == [1, 2, 5, 15, 52, 203, 877]
+
+    def test_stirling_numbers(self):
+        assert [len(mit.set_partitions(range(5), k)) for k in range(1, 7)] == [1, 15, 25, 10, 1, 0]
+
+    def test_groups_cover_items(self):
+        for partition in mit.set_partitions("abcd", 2):
+            assert len(partition) == 2
+            assert sorted(x for group in partition for x in group) == list("abcd")
+
+    def test_zero_groups(self):
+        with pytest.raises(ValueError):
+            mit.set_partitions([1, 2], 0)
+
+
+class TestSample:
+    def test_size(self):
+        assert len(mit.sample(range(100), 5)) == 5
+        assert sorted(mit.sample(range(3), 5)) == [0, 1, 2]
+        assert mit.sample(range(10), 0) == []
+
+    def test_without_replacement(self):
+        result = mit.sample(range(20), 10)
+        assert len(set(result)) == 10
+        assert set(result) <= set(range(20))
+
+    def test_seed(self):
+        assert mit.sample(range(1000), 5, seed=42) == mit.sample(iter(range(1000)), 5, seed=42)
+        weights = [1, 2, 3, 4] * 25
+        assert mit.sample(range(100), 3, weights, seed=7) == mit.sample(range(100), 3, weights, seed=7)
+
+    def test_weights(self):
+        counts = {"a": 0, "b": 0}
+        for seed in range(500):
+            counts[mit.sample("ab", 1, weights=[1, 9], seed=seed)[0]] += 1
+        assert counts["b"] > counts["a"] * 3
+
+    def test_invalid_weight(self):
+        with pytest.raises(ValueError):
+            mit.sample("ab", 1, weights=[1, 0])
+
+
 class TestIsSorted:
     def test_sorted_ascending(self):
         assert mit.is_sorted([1, 2, 3, 4, 5]) is True
@@ -1,8 +1,24 @@
+// pyo3 0.22's `#[pyfunction]` expansion trips this lint on `PyResult` returns
+#![allow(clippy::useless_conversion)]
+
 use once_cell::sync::Lazy;
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyIterator, PyList, PyString, PyTuple};
 use regex::Regex;
 use std::cmp::Ordering;
 
+// pyo3 0.22's exception macro checks a `gil-refs` feature this crate doesn't declare
+#[allow(unexpected_cfgs)]
+mod exceptions {
+    use super::*;
+    create_exception!(packaging_rs, InvalidVersion, PyValueError);
+    create_exception!(packaging_rs, InvalidSpecifier, PyValueError);
+}
+use exceptions::{InvalidSpecifier, InvalidVersion};
+
 // PEP 440 version regex
 static VERSION_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(?ix)
@@ -20,7 +36,7 @@ static VERSION_REGEX: Lazy<Regex> = Lazy::new(|| {
                 (?P<pre_num>[0-9]+)?
             )
         )?
-        (?:
+        (?P<post>
             (?:-(?P<post_num1>[0-9]+))
             |
             (?:
@@ -30,7 +46,7 @@ static VERSION_REGEX: Lazy<Regex> = Lazy::new(|| {
                 (?P<post_num2>[0-9]+)?
             )
         )?
-        (?:
+        (?P<dev>
             [-_\.]?
             (?:dev)
             [-_\.]?
@@ -44,8 +60,17 @@ static VERSION_REGEX: Lazy<Regex> = Lazy::new(|| {
     ").unwrap()
 });
 
+// One PEP 440 specifier clause: an operator followed by a version
+static SPECIFIER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*(?P<operator>===|~=|==|!=|<=|>=|<|>)\s*(?P<version>[^\s,;]+)\s*$").unwrap()
+});
+
 /// Parsed version components
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// Equality, ordering and hashing follow PEP 440: trailing zeros in the
+/// release don't count ("1.0" == "1.0.0"), and a local version sorts after
+/// its public version.
+#[derive(Clone, Debug)]
 struct VersionParts {
     epoch: u32,
     release: Vec<u32>,
@@ -55,6 +80,35 @@ struct VersionParts {
     local: Option<String>,
 }
 
+impl PartialEq for VersionParts {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for VersionParts {}
+
+impl std::hash::Hash for VersionParts {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Hash what `cmp` compares: release without trailing zeros and
+        // numeric local segments without leading zeros
+        let trailing_zeros = self.release.iter().rev().take_while(|&&n| n == 0).count();
+        self.epoch.hash(state);
+        self.release[..self.release.len() - trailing_zeros].hash(state);
+        self.pre.hash(state);
+        self.post.hash(state);
+        self.dev.hash(state);
+        if let Some(local) = &self.local {
+            for segment in local.split('.') {
+                match segment.bytes().all(|b| b.is_ascii_digit()) {
+                    true => segment.trim_start_matches('0').hash(state),
+                    false => segment.hash(state),
+                }
+            }
+        }
+    }
+}
+
 impl PartialOrd for VersionParts {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -63,6 +117,14 @@ impl PartialOrd for VersionParts {
 
 impl Ord for VersionParts {
     fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_public(other)
+            .then_with(|| cmp_local(self.local.as_deref(), other.local.as_deref()))
+    }
+}
+
+impl VersionParts {
+    /// Order by the public version, ignoring any local segment
+    fn cmp_public(&self, other: &Self) -> Ordering {
         // Compare epoch first
         match self.epoch.cmp(&other.epoch) {
             Ordering::Equal => {}
@@ -124,6 +186,41 @@ impl Ord for VersionParts {
     }
 }
 
+/// PEP 440 local version ordering: no local segment sorts first, then
+/// dot-separated segments compare in turn, numbers as integers and above
+/// any alphanumeric segment; a longer local wins a shared prefix
+fn cmp_local(a: Option<&str>, b: Option<&str>) -> Ordering {
+    let (a, b) = match (a, b) {
+        (None, None) => return Ordering::Equal,
+        (None, Some(_)) => return Ordering::Less,
+        (Some(_), None) => return Ordering::Greater,
+        (Some(a), Some(b)) => (a, b),
+    };
+    let is_number = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    let mut a_segments = a.split('.');
+    let mut b_segments = b.split('.');
+    loop {
+        let ord = match (a_segments.next(), b_segments.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => match (is_number(x), is_number(y)) {
+                (true, true) => {
+                    // Compare as integers of any size
+                    let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                    x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+                }
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => x.cmp(y),
+            },
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
 fn pre_type_order(pre_type: &str) -> u32 {
     match pre_type.to_lowercase().as_str() {
         "a" | "alpha" => 0,
@@ -133,19 +230,31 @@ fn pre_type_order(pre_type: &str) -> u32 {
     }
 }
 
+/// Parse a version into its normalized components: the `v` prefix and
+/// surrounding whitespace are dropped, numbers lose leading zeros,
+/// pre-release spellings become "a"/"b"/"rc" and the local segment is
+/// lowercased with "." separators. Everything else derives from these parts.
 fn parse_version_parts(version: &str) -> Option<VersionParts> {
-    let caps = VERSION_REGEX.captures(version)?;
+    let caps = VERSION_REGEX.captures(version.trim())?;
+    
+    // A number too large for u32 makes the version invalid rather than
+    // silently reading as 0
+    let number = |name: &str| -> Option<Option<u32>> {
+        match caps.name(name) {
+            Some(m) => m.as_str().parse().ok().map(Some),
+            None => Some(None),
+        }
+    };
     
-    let epoch = caps.name("epoch")
-        .map(|m| m.as_str().parse().unwrap_or(0))
-        .unwrap_or(0);
+    let epoch = number("epoch")?.unwrap_or(0);
     
     let release: Vec<u32> = caps.name("release")?
         .as_str()
         .split('.')
-        .filter_map(|s| s.parse().ok())
-        .collect();
+        .map(|s| s.parse().ok())
+        .collect::<Option<_>>()?;
     
+    let pre_num = number("pre_num")?.unwrap_or(0);
     let pre = caps.name("pre").map(|m| {
         let pre_str = m.as_str().to_lowercase();
         let pre_type = if pre_str.starts_with("a") || pre_str.starts_with("alpha") {
@@ -155,21 +264,18 @@ fn parse_version_parts(version: &str) -> Option<VersionParts> {
         } else {
             "rc".to_string()
         };
-        let pre_num = caps.name("pre_num")
-            .map(|m| m.as_str().parse().unwrap_or(0))
-            .unwrap_or(0);
         (pre_type, pre_num)
     });
     
-    let post = caps.name("post_num1")
-        .or_else(|| caps.name("post_num2"))
-        .map(|m| m.as_str().parse().unwrap_or(0));
+    // A bare "post"/"dev" marker without a number means 0
+    let post_num = number("post_num1")?.or(number("post_num2")?);
+    let post = caps.name("post").map(|_| post_num.unwrap_or(0));
     
-    let dev = caps.name("dev_num")
-        .map(|m| m.as_str().parse().unwrap_or(0));
+    let dev_num = number("dev_num")?;
+    let dev = caps.name("dev").map(|_| dev_num.unwrap_or(0));
     
     let local = caps.name("local")
-        .map(|m| m.as_str().to_string());
+        .map(|m| m.as_str().to_lowercase().replace(['-', '_'], "."));
     
     Some(VersionParts {
         epoch,
@@ -194,8 +300,8 @@ impl Version {
     #[new]
     fn new(version: &str) -> PyResult<Self> {
         let parts = parse_version_parts(version)
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
-                format!("Invalid version: {}", version)
+            .ok_or_else(|| InvalidVersion::new_err(
+                format!("Invalid version: '{}'", version)
             ))?;
         
         Ok(Version {
@@ -204,40 +310,55 @@ impl Version {
         })
     }
     
+    /// Normalized PEP 440 form, e.g. "1.0.0-alpha.1" -> "1.0.0a1"
     fn __str__(&self) -> String {
-        self.original.clone()
+        self.normalized()
     }
     
     fn __repr__(&self) -> String {
-        format!("<Version('{}')>", self.original)
+        format!("<Version('{}')>", self.normalized())
     }
     
     fn __hash__(&self) -> u64 {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
+        // Hash the parsed parts so equal versions hash equally
         let mut hasher = DefaultHasher::new();
-        self.original.hash(&mut hasher);
+        self.parts.hash(&mut hasher);
         hasher.finish()
     }
     
-    fn __eq__(&self, other: &Version) -> bool {
-        self.parts == other.parts
-    }
-    
-    fn __lt__(&self, other: &Version) -> bool {
-        self.parts < other.parts
-    }
-    
-    fn __le__(&self, other: &Version) -> bool {
-        self.parts <= other.parts
-    }
-    
-    fn __gt__(&self, other: &Version) -> bool {
-        self.parts > other.parts
+    /// Compare against another Version, a version string, or a release
+    /// tuple such as `(1, 2, 3)` (treated as `Version("1.2.3")`)
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        let other_parts = if let Ok(version) = other.downcast::<Version>() {
+            version.borrow().parts.clone()
+        } else if let Ok(s) = other.downcast::<PyString>() {
+            Version::new(s.to_str()?)?.parts
+        } else if let Some(release) = other.downcast::<PyTuple>().ok().and_then(|t| t.extract::<Vec<u32>>().ok()) {
+            if release.is_empty() {
+                return Ok(py.NotImplemented());
+            }
+            VersionParts {
+                epoch: 0,
+                release,
+                pre: None,
+                post: None,
+                dev: None,
+                local: None,
+            }
+        } else {
+            return Ok(py.NotImplemented());
+        };
+        
+        Ok(op.matches(self.parts.cmp(&other_parts)).into_py(py))
     }
     
-    fn __ge__(&self, other: &Version) -> bool {
-        self.parts >= other.parts
+    /// The version string exactly as it was given
+    #[getter]
+    fn original(&self) -> String {
+        self.original.clone()
     }
     
     #[getter]
@@ -343,6 +464,80 @@ impl Version {
         
         result
     }
+
+    /// Return a new version with the major segment incremented
+    fn bump_major(&self) -> PyResult<Version> {
+        self.bumped(0)
+    }
+
+    /// Return a new version with the minor segment incremented
+    fn bump_minor(&self) -> PyResult<Version> {
+        self.bumped(1)
+    }
+
+    /// Return a new version with the micro segment incremented
+    fn bump_micro(&self) -> PyResult<Version> {
+        self.bumped(2)
+    }
+
+    /// Return a new version with the local segment removed
+    fn without_local(&self) -> Version {
+        let mut parts = self.parts.clone();
+        parts.local = None;
+        Version::from_parts(parts)
+    }
+
+    /// Return the final release of this version (pre and dev segments removed)
+    fn finalize(&self) -> Version {
+        let mut parts = self.parts.clone();
+        parts.pre = None;
+        parts.dev = None;
+        Version::from_parts(parts)
+    }
+}
+
+impl Version {
+    fn from_parts(parts: VersionParts) -> Self {
+        let mut version = Version {
+            original: String::new(),
+            parts,
+        };
+        version.original = version.normalized();
+        version
+    }
+
+    /// Normalized string form: public version plus local segment
+    fn normalized(&self) -> String {
+        match &self.parts.local {
+            Some(local) => format!("{}+{}", self.public(), local),
+            None => self.public(),
+        }
+    }
+
+    /// Increment the release segment at `index`, zeroing every segment after
+    /// it and clearing pre/post/dev; InvalidVersion if the segment is at
+    /// its maximum
+    fn bumped(&self, index: usize) -> PyResult<Version> {
+        let mut parts = self.parts.clone();
+        if parts.release.len() <= index {
+            parts.release.resize(index + 1, 0);
+        }
+        parts.release[index] = parts.release[index].checked_add(1).ok_or_else(|| {
+            InvalidVersion::new_err(format!(
+                "Can't bump '{}': release segment {} is already {}",
+                self.original,
+                index,
+                u32::MAX
+            ))
+        })?;
+        for segment in parts.release.iter_mut().skip(index + 1) {
+            *segment = 0;
+        }
+        parts.pre = None;
+        parts.post = None;
+        parts.dev = None;
+        Ok(Version::from_parts(parts))
+    }
 }
 
 /// Parse a version string
@@ -357,17 +552,347 @@ fn is_valid_version(version: &str) -> bool {
     parse_version_parts(version).is_some()
 }
 
-/// Canonicalize a version string
+/// Canonicalize a version string, like `packaging.utils.canonicalize_version`
+///
+/// Pre, post and dev spellings are normalized ("1.0alpha1" -> "1.0a1"), a
+/// zero epoch is omitted and the local segment is kept. With
+/// `strip_trailing_zero`, trailing zero release segments are dropped so
+/// "1.0.0" becomes "1". Strings that aren't valid versions are returned as is.
 #[pyfunction]
-fn canonicalize_version(version: &str) -> PyResult<String> {
-    let v = Version::new(version)?;
-    Ok(v.public())
+#[pyo3(signature = (version, *, strip_trailing_zero=true))]
+fn canonicalize_version(version: &str, strip_trailing_zero: bool) -> String {
+    let Some(mut parts) = parse_version_parts(version) else {
+        return version.to_string();
+    };
+    if strip_trailing_zero {
+        while parts.release.len() > 1 && parts.release.last() == Some(&0) {
+            parts.release.pop();
+        }
+    }
+    Version::from_parts(parts).normalized()
+}
+
+/// A version given as a Version or a version string
+fn coerce_version(item: &Bound<'_, PyAny>) -> PyResult<Version> {
+    match item.downcast::<Version>() {
+        Ok(version) => Ok(version.borrow().clone()),
+        Err(_) => Version::new(item.extract::<&str>()?),
+    }
+}
+
+/// A single specifier clause such as ">=1.0" or "==2.1.*"
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct Specifier {
+    operator: String,
+    version: String,
+    /// Parsed version (the prefix for "==X.*"/"!=X.*"); None for "==="
+    parts: Option<VersionParts>,
+    wildcard: bool,
+    prereleases: Option<bool>,
+}
+
+#[pymethods]
+impl Specifier {
+    #[new]
+    #[pyo3(signature = (spec="", prereleases=None))]
+    fn new(spec: &str, prereleases: Option<bool>) -> PyResult<Self> {
+        let invalid = || InvalidSpecifier::new_err(format!("Invalid specifier: '{}'", spec));
+        let caps = SPECIFIER_REGEX.captures(spec).ok_or_else(invalid)?;
+        let operator = caps["operator"].to_string();
+        let version = caps["version"].to_string();
+
+        if operator == "===" {
+            return Ok(Specifier { operator, version, parts: None, wildcard: false, prereleases });
+        }
+
+        let prefix = version.strip_suffix(".*");
+        let wildcard = prefix.is_some();
+        let parts = parse_version_parts(prefix.unwrap_or(&version)).ok_or_else(invalid)?;
+        let valid = if wildcard {
+            // Wildcards only follow a plain release
+            matches!(operator.as_str(), "==" | "!=")
+                && parts.pre.is_none()
+                && parts.post.is_none()
+                && parts.dev.is_none()
+                && parts.local.is_none()
+        } else {
+            match operator.as_str() {
+                "==" | "!=" => true,
+                "~=" => parts.local.is_none() && parts.release.len() >= 2,
+                _ => parts.local.is_none(),
+            }
+        };
+        if !valid {
+            return Err(invalid());
+        }
+
+        Ok(Specifier { operator, version, parts: Some(parts), wildcard, prereleases })
+    }
+
+    #[getter]
+    fn operator(&self) -> String {
+        self.operator.clone()
+    }
+
+    #[getter]
+    fn version(&self) -> String {
+        self.version.clone()
+    }
+
+    /// Whether pre-releases match: the value given to the constructor, or
+    /// else whether the specifier itself names a pre-release
+    #[getter]
+    fn prereleases(&self) -> bool {
+        self.prereleases.unwrap_or_else(|| match (&self.parts, self.operator.as_str()) {
+            (_, "!=") => false,
+            (Some(parts), _) => parts.pre.is_some() || parts.dev.is_some(),
+            (None, _) => parse_version_parts(&self.version)
+                .is_some_and(|parts| parts.pre.is_some() || parts.dev.is_some()),
+        })
+    }
+
+    /// Whether `version` (a Version or version string) satisfies this clause;
+    /// `prereleases` overrides the `prereleases` property
+    #[pyo3(signature = (version, prereleases=None))]
+    fn contains(&self, version: &Bound<'_, PyAny>, prereleases: Option<bool>) -> PyResult<bool> {
+        let version = coerce_version(version)?;
+        if version.is_prerelease() && !prereleases.unwrap_or_else(|| self.prereleases()) {
+            return Ok(false);
+        }
+        Ok(self.matches(&version))
+    }
+
+    fn __contains__(&self, version: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.contains(version, None)
+    }
+
+    fn __str__(&self) -> String {
+        format!("{}{}", self.operator, self.version)
+    }
+
+    fn __repr__(&self) -> String {
+        match self.prereleases {
+            Some(pre) => format!(
+                "<Specifier('{}', prereleases={})>",
+                self.__str__(),
+                if pre { "True" } else { "False" }
+            ),
+            None => format!("<Specifier('{}')>", self.__str__()),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        let mut hasher = DefaultHasher::new();
+        self.canonical().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Equal to another Specifier or specifier string with the same operator
+    /// and canonical version
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        let other = if let Ok(spec) = other.downcast::<Specifier>() {
+            spec.get().clone()
+        } else if let Some(spec) = other.extract::<&str>().ok().and_then(|s| Specifier::new(s, None).ok()) {
+            spec
+        } else {
+            return Ok(py.NotImplemented());
+        };
+        match op {
+            CompareOp::Eq => Ok((self.canonical() == other.canonical()).into_py(py)),
+            CompareOp::Ne => Ok((self.canonical() != other.canonical()).into_py(py)),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+}
+
+impl Specifier {
+    /// Operator and canonical version, for equality and hashing
+    fn canonical(&self) -> (String, String) {
+        let version = if self.parts.is_none() || self.wildcard {
+            self.version.clone()
+        } else {
+            canonicalize_version(&self.version, self.operator != "~=")
+        };
+        (self.operator.clone(), version)
+    }
+
+    /// PEP 440 matching, ignoring the pre-release policy
+    fn matches(&self, candidate: &Version) -> bool {
+        let Some(spec) = &self.parts else {
+            return candidate.normalized().eq_ignore_ascii_case(&self.version);
+        };
+        let cand = &candidate.parts;
+        let same_base = base_parts(cand) == base_parts(spec);
+        // Ordered comparisons look at the candidate's public version only
+        let ord = cand.cmp_public(spec);
+        match self.operator.as_str() {
+            "==" => self.equals(cand),
+            "!=" => !self.equals(cand),
+            "<=" => ord != Ordering::Greater,
+            ">=" => ord != Ordering::Less,
+            // "<1.0" excludes 1.0's own pre-releases unless it names one
+            "<" => ord == Ordering::Less && !(same_base && candidate.is_prerelease() && spec.pre.is_none() && spec.dev.is_none()),
+            // ">1.0" excludes 1.0's post-releases and local versions
+            ">" => ord == Ordering::Greater && !(same_base && ((cand.post.is_some() && spec.post.is_none()) || cand.local.is_some())),
+            "~=" => {
+                let prefix = &spec.release[..spec.release.len() - 1];
+                ord != Ordering::Less && cand.epoch == spec.epoch && release_starts_with(&cand.release, prefix)
+            }
+            _ => false,
+        }
+    }
+
+    /// "==" matching: release prefix for wildcards, otherwise version
+    /// equality with the local segment ignored unless the specifier has one
+    fn equals(&self, cand: &VersionParts) -> bool {
+        let Some(spec) = &self.parts else {
+            return false;
+        };
+        if self.wildcard {
+            return cand.epoch == spec.epoch && release_starts_with(&cand.release, &spec.release);
+        }
+        match spec.local {
+            Some(_) => cand.cmp(spec) == Ordering::Equal,
+            None => cand.cmp_public(spec) == Ordering::Equal,
+        }
+    }
+}
+
+/// Epoch and release only
+fn base_parts(parts: &VersionParts) -> VersionParts {
+    VersionParts {
+        epoch: parts.epoch,
+        release: parts.release.clone(),
+        pre: None,
+        post: None,
+        dev: None,
+        local: None,
+    }
+}
+
+/// Whether `release`, zero-padded, starts with `prefix`
+fn release_starts_with(release: &[u32], prefix: &[u32]) -> bool {
+    prefix
+        .iter()
+        .enumerate()
+        .all(|(i, n)| release.get(i).copied().unwrap_or(0) == *n)
+}
+
+/// A comma-separated set of specifiers, e.g. ">=1.0,!=1.3.*,<2"
+#[pyclass(frozen)]
+pub struct SpecifierSet {
+    specs: Vec<Specifier>,
+    prereleases: Option<bool>,
+}
+
+#[pymethods]
+impl SpecifierSet {
+    #[new]
+    #[pyo3(signature = (specifiers="", prereleases=None))]
+    fn new(specifiers: &str, prereleases: Option<bool>) -> PyResult<Self> {
+        let mut specs: Vec<Specifier> = Vec::new();
+        for clause in specifiers.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+            let spec = Specifier::new(clause, None)?;
+            if !specs.iter().any(|s| s.canonical() == spec.canonical()) {
+                specs.push(spec);
+            }
+        }
+        Ok(SpecifierSet { specs, prereleases })
+    }
+
+    /// Whether pre-releases match: the value given to the constructor, or
+    /// else whether any clause names a pre-release
+    #[getter]
+    fn prereleases(&self) -> bool {
+        self.prereleases
+            .unwrap_or_else(|| self.specs.iter().any(|s| s.prereleases()))
+    }
+
+    /// Whether `version` satisfies every clause; `prereleases` overrides
+    /// the `prereleases` property
+    #[pyo3(signature = (version, prereleases=None))]
+    fn contains(&self, version: &Bound<'_, PyAny>, prereleases: Option<bool>) -> PyResult<bool> {
+        let version = coerce_version(version)?;
+        if version.is_prerelease() && !prereleases.unwrap_or_else(|| self.prereleases()) {
+            return Ok(false);
+        }
+        Ok(self.specs.iter().all(|s| s.matches(&version)))
+    }
+
+    fn __contains__(&self, version: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.contains(version, None)
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        let specs = self
+            .specs
+            .iter()
+            .map(|s| Py::new(py, s.clone()))
+            .collect::<PyResult<Vec<_>>>()?;
+        PyList::new_bound(py, specs).as_any().iter()
+    }
+
+    fn __len__(&self) -> usize {
+        self.specs.len()
+    }
+
+    /// Clauses sorted and joined with commas
+    fn __str__(&self) -> String {
+        let mut clauses: Vec<String> = self.specs.iter().map(|s| s.__str__()).collect();
+        clauses.sort();
+        clauses.join(",")
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<SpecifierSet('{}')>", self.__str__())
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        let mut hasher = DefaultHasher::new();
+        self.canonical().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Equal to another SpecifierSet or specifier string with the same clauses
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let py = other.py();
+        let other = if let Ok(set) = other.downcast::<SpecifierSet>() {
+            set.get().canonical()
+        } else if let Some(set) = other.extract::<&str>().ok().and_then(|s| SpecifierSet::new(s, None).ok()) {
+            set.canonical()
+        } else {
+            return Ok(py.NotImplemented());
+        };
+        match op {
+            CompareOp::Eq => Ok((self.canonical() == other).into_py(py)),
+            CompareOp::Ne => Ok((self.canonical() != other).into_py(py)),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+}
+
+impl SpecifierSet {
+    fn canonical(&self) -> Vec<(String, String)> {
+        let mut clauses: Vec<_> = self.specs.iter().map(Specifier::canonical).collect();
+        clauses.sort();
+        clauses
+    }
 }
 
 /// Python module
 #[pymodule]
 fn packaging_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Version>()?;
+    m.add_class::<Specifier>()?;
+    m.add_class::<SpecifierSet>()?;
+    m.add("InvalidVersion", m.py().get_type_bound::<InvalidVersion>())?;
+    m.add("InvalidSpecifier", m.py().get_type_bound::<InvalidSpecifier>())?;
     m.add_function(wrap_pyfunction!(parse, m)?)?;
     m.add_function(wrap_pyfunction!(is_valid_version, m)?)?;
     m.add_function(wrap_pyfunction!(canonicalize_version, m)?)?;
@@ -0,0 +1,326 @@
+"""
+Tests for packaging-rs
+
+Verifies API compatibility with packaging.version
+"""
+
+import pytest
+
+try:
+    from packaging_rs import (
+        InvalidSpecifier,
+        InvalidVersion,
+        Specifier,
+        SpecifierSet,
+        Version,
+        canonicalize_version,
+    )
+except ImportError:
+    pytest.skip("packaging_rs not available", allow_module_level=True)
+
+
+class TestNormalizedStr:
+    """str() emits the PEP 440 normalized form"""
+
+    @pytest.mark.parametrize("raw, normalized", [
+        ("1.0.0-alpha.1", "1.0.0a1"),
+        ("1.0.0.BETA2", "1.0.0b2"),
+        ("1.0c1", "1.0rc1"),
+        ("v1.2", "1.2"),
+        ("1.0-1", "1.0.post1"),
+        ("1.0.0-dev", "1.0.0.dev0"),
+        ("0!01.02", "1.2"),
+        ("1.0+ABC-def_1", "1.0+abc.def.1"),
+    ])
+    def test_str(self, raw, normalized):
+        assert str(Version(raw)) == normalized
+
+    def test_original_preserved(self):
+        v = Version("1.0.0-alpha.1")
+        assert v.original == "1.0.0-alpha.1"
+
+    def test_repr_uses_normalized(self):
+        assert repr(Version("1.0.0-alpha.1")) == "<Version('1.0.0a1')>"
+
+    def test_hash_matches_equality(self):
+        assert hash(Version("1.0-alpha1")) == hash(Version("1.0a1"))
+        assert {Version("1.0-alpha1"): 1}[Version("1.0a1")] == 1
+
+
+class TestNormalizedParts:
+    """Parsed parts are normalized, so every derived form agrees"""
+
+    def test_v_prefix(self):
+        v = Version("v1.0")
+        assert str(v) == v.public == v.base_version == "1.0"
+        assert v.original == "v1.0"
+        assert v == Version("1.0")
+
+    def test_trailing_zeros_kept(self):
+        v = Version("1.0.0")
+        assert str(v) == "1.0.0"
+        assert v.release == [1, 0, 0]
+
+    def test_leading_zeros(self):
+        v = Version("01.2")
+        assert str(v) == "1.2"
+        assert v.release == [1, 2]
+
+    def test_epoch(self):
+        v = Version("v1!01.002")
+        assert str(v) == v.public == "1!1.2"
+        assert (v.epoch, v.release) == (1, [1, 2])
+        assert str(Version("0!1.0")) == "1.0"
+
+    def test_whitespace(self):
+        assert str(Version("  1.0\n")) == "1.0"
+
+    def test_local_normalized(self):
+        assert Version("1.0+ABC-def").local == "abc.def"
+        assert Version("1.0+ABC") == Version("1.0+abc")
+        assert hash(Version("1.0+ABC")) == hash(Version("1.0+abc"))
+
+    def test_oversized_segment_invalid(self):
+        with pytest.raises(InvalidVersion):
+            Version("1.99999999999")
+
+
+class TestDerivedVersions:
+    """Bump and finalize helpers"""
+
+    def test_bump(self):
+        v = Version("1.2.3rc1")
+        assert str(v.bump_major()) == "2.0.0"
+        assert str(v.bump_minor()) == "1.3.0"
+        assert str(v.bump_micro()) == "1.2.4"
+
+    def test_finalize(self):
+        assert str(Version("1.2.3rc1.dev2").finalize()) == "1.2.3"
+
+    def test_bump_pads_short_release(self):
+        assert str(Version("1").bump_minor()) == "1.1"
+        assert str(Version("1").bump_micro()) == "1.0.1"
+
+    def test_bump_zeroes_later_segments(self):
+        assert str(Version("1.2.3.4").bump_minor()) == "1.3.0.0"
+
+    def test_bump_clears_pre_post_dev(self):
+        v = Version("1.2.3rc1.post2.dev3")
+        assert str(v.bump_micro()) == "1.2.4"
+        assert str(Version("1.2.post1").bump_minor()) == "1.3"
+
+    def test_bump_keeps_epoch_and_local(self):
+        assert str(Version("1!1.2").bump_major()) == "1!2.0"
+        assert str(Version("1.2+loc").bump_minor()) == "1.3+loc"
+
+    def test_bump_overflow(self):
+        with pytest.raises(InvalidVersion):
+            Version("4294967295.0").bump_major()
+        with pytest.raises(ValueError):
+            Version("1.4294967295").bump_minor()
+        assert str(Version("1.4294967295").bump_major()) == "2.0"
+
+    def test_finalize_keeps_post_epoch_and_local(self):
+        assert str(Version("1!1.2.3rc1.post2.dev3+loc").finalize()) == "1!1.2.3.post2+loc"
+        assert str(Version("1.2").finalize()) == "1.2"
+
+    def test_without_local(self):
+        assert str(Version("1.2.3+local").without_local()) == "1.2.3"
+        assert str(Version("1!1.2rc1+a.b").without_local()) == "1!1.2rc1"
+        assert str(Version("1.2").without_local()) == "1.2"
+
+    def test_derived_versions_compare(self):
+        assert Version("1.2.3rc1").finalize() > Version("1.2.3rc1")
+        assert Version("1.2.3+local").without_local() == Version("1.2.3")
+
+
+class TestComparison:
+    """Versions compare against other versions, strings and release tuples"""
+
+    def test_strings(self):
+        assert Version("1.2.3") >= "1.2.0"
+        assert Version("1.2.3") == "1.2.3"
+        assert Version("1.2.3") != "1.2.4"
+        assert "1.5" < Version("2.0")
+
+    def test_invalid_string(self):
+        with pytest.raises(InvalidVersion):
+            Version("1.2.3") < "not a version"
+
+    def test_invalid_version_is_value_error(self):
+        with pytest.raises(ValueError):
+            Version("not a version")
+
+    def test_tuples(self):
+        assert Version("1.2.3") == (1, 2, 3)
+        assert Version("1.2.3rc1") != (1, 2, 3)
+        assert Version("1.2.3rc1") < (1, 2, 3)
+        assert Version("1.3") > (1, 2, 9)
+
+    def test_trailing_zeros(self):
+        assert Version("1.0") == "1.0.0"
+        assert Version("1.0") == Version("1.0.0")
+        assert not Version("1.0") != "1.0.0"
+        assert Version("1.0") == (1, 0, 0)
+        assert Version("1.2") == (1, 2, 0)
+        assert Version("1.0.0") == (1,)
+
+    def test_hash_matches_equality(self):
+        assert hash(Version("1.0")) == hash(Version("1.0.0"))
+        assert hash(Version("1.0+1")) == hash(Version("1.0.0+01"))
+        assert len({Version("1.0"), Version("1.0.0"), Version("1")}) == 1
+        assert {Version("2.0"): "x"}[Version("2")] == "x"
+
+    def test_local_ordering(self):
+        assert Version("1.0+abc") > Version("1.0")
+        assert Version("1.0+abc") != "1.0"
+        assert Version("1.0+abc") < "1.0.post1"
+        assert Version("1.0+abc") < Version("1.0+abc.1")
+        assert Version("1.0+1") > Version("1.0+abc")
+        assert Version("1.0+10") > Version("1.0+9")
+        assert Version("1.0+abc") < Version("1.0+abd")
+        assert Version("1.0+ABC") == Version("1.0+abc")
+        assert Version("1.0+01") == Version("1.0+1")
+        assert Version("1.0+abc") > (1, 0)
+
+    def test_specifiers_ignore_candidate_local(self):
+        assert SpecifierSet("<=1.0").contains("1.0+abc")
+        assert SpecifierSet(">=1.0").contains("1.0+abc")
+        assert SpecifierSet("==1.0").contains("1.0+abc")
+        assert not SpecifierSet(">1.0").contains("1.0+abc")
+        assert not SpecifierSet("==1.0+abd").contains("1.0+abc")
+        assert SpecifierSet("==1.0+01").contains("1.0.0+1")
+
+    def test_unsupported_type(self):
+        assert Version("1") != 1
+        with pytest.raises(TypeError):
+            Version("1") < 1
+
+
+class TestCanonicalizeVersion:
+    """canonicalize_version matches packaging.utils"""
+
+    def test_strip_trailing_zero(self):
+        assert canonicalize_version("1.0.0") == "1"
+        assert canonicalize_version("1.10.0") == "1.10"
+        assert canonicalize_version("0.0") == "0"
+
+    def test_keep_trailing_zero(self):
+        assert canonicalize_version("1.0.0", strip_trailing_zero=False) == "1.0.0"
+        assert canonicalize_version("1.10.0", strip_trailing_zero=False) == "1.10.0"
+
+    def test_prerelease_spellings(self):
+        assert canonicalize_version("1.0alpha1", strip_trailing_zero=False) == "1.0a1"
+        assert canonicalize_version("1.0.0.PREVIEW2") == "1rc2"
+
+    def test_post_and_dev(self):
+        assert canonicalize_version("1.0-r3") == "1.post3"
+        assert canonicalize_version("1!2.0.0.post0.dev1") == "1!2.post0.dev1"
+
+    def test_zero_epoch_omitted(self):
+        assert canonicalize_version("0!1.2") == "1.2"
+
+    def test_local_kept(self):
+        assert canonicalize_version("1.0.0+Local-1") == "1+local.1"
+
+    def test_invalid_returned_unchanged(self):
+        assert canonicalize_version("not a version") == "not a version"
+
+
+class TestSpecifier:
+    """Single specifier clauses, like packaging.specifiers.Specifier"""
+
+    def test_properties(self):
+        spec = Specifier(">= 1.0")
+        assert spec.operator == ">="
+        assert spec.version == "1.0"
+        assert str(spec) == ">=1.0"
+        assert repr(spec) == "<Specifier('>=1.0')>"
+
+    def test_comparison_operators(self):
+        assert "1.0" in Specifier(">=1.0")
+        assert "0.9" not in Specifier(">=1.0")
+        assert Version("1.9") in Specifier("<2.0")
+        assert "1.1" in Specifier(">1.0")
+
+    def test_exclusive_ordering(self):
+        assert Specifier("<2.0").contains("2.0rc1", prereleases=True) is False
+        assert Specifier(">1.0").contains("1.0.post1") is False
+        assert Specifier(">1.0").contains("1.0+local") is False
+
+    def test_wildcards(self):
+        assert "1.0.5" in Specifier("==1.0.*")
+        assert "1" in Specifier("==1.0.*")
+        assert "1.1" not in Specifier("==1.0.*")
+        assert "1.1" in Specifier("!=1.0.*")
+
+    def test_compatible_release(self):
+        assert "2.9" in Specifier("~=2.2")
+        assert "3.0" not in Specifier("~=2.2")
+        assert "1.4.9" in Specifier("~=1.4.5")
+        assert "1.5" not in Specifier("~=1.4.5")
+
+    def test_equality_and_local(self):
+        assert "1.0.0" in Specifier("==1.0")
+        assert "1.0+abc" in Specifier("==1.0")
+        assert "1.0" not in Specifier("==1.0+abc")
+        assert "1.0" in Specifier("===1.0")
+        assert "1.0.0" not in Specifier("===1.0")
+
+    def test_prereleases(self):
+        assert "1.0rc1" not in Specifier(">=0.9")
+        assert Specifier(">=0.9").contains("1.0rc1", prereleases=True)
+        assert "1.0rc2" in Specifier(">=1.0rc1")
+        assert Specifier(">=0.9", prereleases=True).prereleases is True
+
+    def test_invalid(self):
+        for spec in ["foo", "", "~=1", ">=1.0.*", "==1.0a1.*", "<1.0+local"]:
+            with pytest.raises(InvalidSpecifier):
+                Specifier(spec)
+
+    def test_invalid_is_value_error(self):
+        with pytest.raises(ValueError):
+            Specifier("foo")
+
+    def test_eq_and_hash(self):
+        assert Specifier(">=1.0") == Specifier(">=1.0.0")
+        assert Specifier(">=1.0") == ">=1.0"
+        assert Specifier(">=1.0") != Specifier(">1.0")
+        assert Specifier("~=1.0") != Specifier("~=1.0.0")
+        assert len({Specifier("==1.0"), Specifier("==1.0.0"), Specifier("==2")}) == 2
+
+
+class TestSpecifierSet:
+    """Sets of specifiers are collections of Specifier"""
+
+    def test_iteration(self):
+        specs = SpecifierSet(">=1.0, <2,!=1.3.*")
+        assert len(specs) == 3
+        assert all(isinstance(s, Specifier) for s in specs)
+        assert {s.operator for s in specs} == {">=", "<", "!="}
+        assert str(specs) == "!=1.3.*,<2,>=1.0"
+
+    def test_contains(self):
+        specs = SpecifierSet(">=1.0,<2,!=1.3.*")
+        assert "1.5" in specs
+        assert "1.3.1" not in specs
+        assert "2.0" not in specs
+
+    def test_empty(self):
+        assert "1.0" in SpecifierSet()
+        assert "1.0rc1" not in SpecifierSet()
+        assert SpecifierSet().contains("1.0rc1", prereleases=True)
+
+    def test_eq_and_hash(self):
+        assert SpecifierSet("<2,>=1") == SpecifierSet(">=1, <2")
+        assert SpecifierSet("<2,>=1") == ">=1,<2"
+        assert len(SpecifierSet("==1.0,==1.0.0")) == 1
+        assert hash(SpecifierSet("<2,>=1")) == hash(SpecifierSet(">=1,<2"))
+
+    def test_invalid(self):
+        with pytest.raises(InvalidSpecifier):
+            SpecifierSet(">=1.0,bogus")
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
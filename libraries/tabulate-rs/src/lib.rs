@@ -1,6 +1,13 @@
+// pyo3 0.22's `#[pyfunction]` expansion trips this lint on `PyResult` returns
+#![allow(clippy::useless_conversion)]
+
+use std::borrow::Cow;
+
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
-use unicode_width::UnicodeWidthStr;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyList, PyLong, PyString};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+const ANSI_RESET: &str = "\x1b[0m";
 
 /// Table format specification
 #[derive(Clone)]
@@ -15,16 +22,95 @@ struct TableFormat {
 
 #[derive(Clone)]
 struct Line {
-    begin: &'static str,
-    hline: &'static str,
-    sep: &'static str,
-    end: &'static str,
+    begin: Cow<'static, str>,
+    hline: Cow<'static, str>,
+    sep: Cow<'static, str>,
+    end: Cow<'static, str>,
 }
 
 impl Line {
     const fn new(begin: &'static str, hline: &'static str, sep: &'static str, end: &'static str) -> Self {
-        Self { begin, hline, sep, end }
+        Self {
+            begin: Cow::Borrowed(begin),
+            hline: Cow::Borrowed(hline),
+            sep: Cow::Borrowed(sep),
+            end: Cow::Borrowed(end),
+        }
+    }
+}
+
+/// Keys a custom `tablefmt` dict may have
+const TABLEFMT_KEYS: [&str; 7] = [
+    "line_above",
+    "line_below",
+    "line_between_rows",
+    "header_line",
+    "padding",
+    "with_header_hide",
+    "datarow",
+];
+
+/// Build a format from a `tablefmt` dict. Rules are `None` or a
+/// `(begin, hline, sep, end)` tuple, and `datarow` a `(begin, sep, end)`
+/// tuple for the cells (default `("", "  ", "")`). Returns the format and
+/// the data row.
+fn format_from_dict(dict: &Bound<'_, PyDict>) -> PyResult<(TableFormat, [Cow<'static, str>; 3])> {
+    let invalid = |message: String| pyo3::exceptions::PyValueError::new_err(message);
+    for key in dict.keys() {
+        let key: String = key.str()?.to_string();
+        if !TABLEFMT_KEYS.contains(&key.as_str()) {
+            return Err(invalid(format!("unknown tablefmt key {:?}; expected one of {}", key, TABLEFMT_KEYS.join(", "))));
+        }
     }
+    let line = |key: &str| -> PyResult<Option<Line>> {
+        match dict.get_item(key)? {
+            Some(value) if !value.is_none() => {
+                let (begin, hline, sep, end): (String, String, String, String) = value.extract().map_err(|_| {
+                    invalid(format!("tablefmt[{:?}] must be None or a (begin, hline, sep, end) tuple of strings", key))
+                })?;
+                if hline.is_empty() {
+                    return Err(invalid(format!("tablefmt[{:?}] needs a non-empty hline", key)));
+                }
+                Ok(Some(Line {
+                    begin: Cow::Owned(begin),
+                    hline: Cow::Owned(hline),
+                    sep: Cow::Owned(sep),
+                    end: Cow::Owned(end),
+                }))
+            }
+            _ => Ok(None),
+        }
+    };
+    let padding = match dict.get_item("padding")? {
+        Some(value) => value
+            .extract::<usize>()
+            .map_err(|_| invalid("tablefmt[\"padding\"] must be a non-negative int".to_string()))?,
+        None => 1,
+    };
+    let with_header_hide = match dict.get_item("with_header_hide")? {
+        Some(value) => value
+            .extract::<bool>()
+            .map_err(|_| invalid("tablefmt[\"with_header_hide\"] must be a bool".to_string()))?,
+        None => false,
+    };
+    let datarow = match dict.get_item("datarow")? {
+        Some(value) => {
+            let (begin, sep, end): (String, String, String) = value
+                .extract()
+                .map_err(|_| invalid("tablefmt[\"datarow\"] must be a (begin, sep, end) tuple of strings".to_string()))?;
+            [Cow::Owned(begin), Cow::Owned(sep), Cow::Owned(end)]
+        }
+        None => [Cow::Borrowed(""), Cow::Borrowed("  "), Cow::Borrowed("")],
+    };
+    let format = TableFormat {
+        line_above: line("line_above")?,
+        line_below: line("line_below")?,
+        line_between_rows: line("line_between_rows")?,
+        header_line: line("header_line")?,
+        padding,
+        with_header_hide,
+    };
+    Ok((format, datarow))
 }
 
 fn get_format(name: &str) -> TableFormat {
@@ -117,6 +203,22 @@ fn get_format(name: &str) -> TableFormat {
             padding: 1,
             with_header_hide: false,
         },
+        "fancy_grid" => TableFormat {
+            line_above: Some(Line::new("╒", "═", "╤", "╕")),
+            line_below: Some(Line::new("╘", "═", "╧", "╛")),
+            line_between_rows: Some(Line::new("├", "─", "┼", "┤")),
+            header_line: Some(Line::new("╞", "═", "╪", "╡")),
+            padding: 1,
+            with_header_hide: false,
+        },
+        "fancy_outline" => TableFormat {
+            line_above: Some(Line::new("╒", "═", "╤", "╕")),
+            line_below: Some(Line::new("╘", "═", "╧", "╛")),
+            line_between_rows: None,
+            header_line: Some(Line::new("╞", "═", "╪", "╡")),
+            padding: 1,
+            with_header_hide: false,
+        },
         "tsv" => TableFormat {
             line_above: None,
             line_below: None,
@@ -139,13 +241,96 @@ fn get_format(name: &str) -> TableFormat {
 fn get_separator(format: &str) -> &'static str {
     match format {
         "tsv" => "\t",
+        "fancy_grid" | "fancy_outline" => "│",
         "github" | "pipe" | "orgtbl" | "rounded_grid" | "heavy_grid" | "double_grid" | "grid" | "pretty" => "|",
         _ => "  ",
     }
 }
 
+/// Byte length of the ANSI CSI sequence (e.g. a color code) starting `s`
+fn csi_len(s: &str) -> Option<usize> {
+    let rest = s.strip_prefix("\x1b[")?;
+    let end = rest.find(|c: char| ('\x40'..='\x7e').contains(&c))?;
+    Some(2 + end + 1)
+}
+
+/// `s` without ANSI CSI sequences, which take no room on screen
+fn strip_ansi(s: &str) -> Cow<'_, str> {
+    if !s.contains('\x1b') {
+        return Cow::Borrowed(s);
+    }
+    let mut stripped = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        match csi_len(rest) {
+            Some(len) => rest = &rest[len..],
+            None => {
+                stripped.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    Cow::Owned(stripped)
+}
+
+/// Columns `s` takes on screen, ignoring ANSI escape sequences
 fn visible_width(s: &str) -> usize {
-    UnicodeWidthStr::width(s)
+    UnicodeWidthStr::width(strip_ansi(s).as_ref())
+}
+
+/// Width of a possibly multi-line cell: its widest line
+fn cell_width(cell: &str) -> usize {
+    cell.split('\n').map(visible_width).max().unwrap_or(0)
+}
+
+/// Split `s` after as many chars as fit in `width` columns, taking at least
+/// one char so the tail always shrinks. ANSI sequences take no room.
+fn split_at_width(s: &str, width: usize) -> (&str, &str) {
+    let (mut used, mut i) = (0, 0);
+    while let Some(c) = s[i..].chars().next() {
+        if let Some(len) = csi_len(&s[i..]) {
+            i += len;
+            continue;
+        }
+        used += c.width().unwrap_or(0);
+        if used > width && i > 0 {
+            return s.split_at(i);
+        }
+        i += c.len_utf8();
+    }
+    (s, "")
+}
+
+/// Wrap `text` to lines at most `width` columns wide, as `textwrap` does:
+/// words break on whitespace, existing line breaks are kept, and a word
+/// longer than `width` is split, starting on the current line if it has room
+fn wrap_text(text: &str, width: usize) -> String {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for mut word in paragraph.split_whitespace() {
+            while !word.is_empty() {
+                let room = if line.is_empty() { width } else { width.saturating_sub(visible_width(&line) + 1) };
+                let fits = visible_width(word) <= room;
+                if !fits && (visible_width(word) <= width || room == 0) {
+                    lines.push(std::mem::take(&mut line));
+                    continue;
+                }
+                let (head, tail) = if fits { (word, "") } else { split_at_width(word, room) };
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(head);
+                word = tail;
+                if !word.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
 }
 
 fn pad_cell(content: &str, width: usize, align: char) -> String {
@@ -156,7 +341,7 @@ fn pad_cell(content: &str, width: usize, align: char) -> String {
     
     let padding = width - content_width;
     match align {
-        'r' => format!("{:>width$}{}", "", content, width = padding),
+        'r' | 'd' => format!("{:>width$}{}", "", content, width = padding),
         'c' => {
             let left = padding / 2;
             let right = padding - left;
@@ -168,28 +353,76 @@ fn pad_cell(content: &str, width: usize, align: char) -> String {
 
 fn build_line(widths: &[usize], line: &Line, padding: usize) -> String {
     let mut result = String::new();
-    result.push_str(line.begin);
+    result.push_str(&line.begin);
     
     for (i, &width) in widths.iter().enumerate() {
         if i > 0 {
-            result.push_str(line.sep);
+            result.push_str(&line.sep);
         }
         let total_width = width + padding * 2;
         for _ in 0..total_width {
-            result.push_str(line.hline);
+            result.push_str(&line.hline);
         }
     }
     
-    result.push_str(line.end);
+    result.push_str(&line.end);
     result
 }
 
-fn build_row(cells: &[String], widths: &[usize], aligns: &[char], sep: &str, padding: usize, use_borders: bool) -> String {
+/// Resize a rule's column junction to `width`: blank junctions become that
+/// many spaces, others are extended with `hline` on the left or cut short
+fn fit_junction(junction: &str, hline: &str, width: usize) -> String {
+    if junction.trim().is_empty() {
+        return " ".repeat(width);
+    }
+    let mut fitted = junction.to_string();
+    while visible_width(&fitted) < width {
+        fitted.insert_str(0, hline);
+    }
+    split_at_width(&fitted, width).0.to_string()
+}
+
+/// Vertical alignment code for a `rowalign` name
+fn parse_valign(name: &str) -> char {
+    match name {
+        "center" => 'c',
+        "bottom" => 'b',
+        _ => 't',
+    }
+}
+
+/// Render a logical row. Cells containing newlines span several physical
+/// lines; shorter cells get blank lines below (`valign` `'t'`), above
+/// (`'b'`) or split around their text (`'c'`, extra line below).
+fn build_row(cells: &[String], widths: &[usize], aligns: &[char], valign: char, sep: &str, padding: usize, edges: (&str, &str)) -> String {
+    let cell_lines: Vec<Vec<&str>> = cells.iter().map(|cell| cell.split('\n').collect()).collect();
+    let height = cell_lines.iter().map(Vec::len).max().unwrap_or(1);
+    let offsets: Vec<usize> = cell_lines
+        .iter()
+        .map(|lines| match valign {
+            'b' => height - lines.len(),
+            'c' => (height - lines.len()) / 2,
+            _ => 0,
+        })
+        .collect();
+    
+    (0..height)
+        .map(|n| {
+            let fragments: Vec<&str> = cell_lines
+                .iter()
+                .zip(&offsets)
+                .map(|(lines, &offset)| n.checked_sub(offset).and_then(|n| lines.get(n)).copied().unwrap_or(""))
+                .collect();
+            build_line_of_cells(&fragments, widths, aligns, sep, padding, edges)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn build_line_of_cells(cells: &[&str], widths: &[usize], aligns: &[char], sep: &str, padding: usize, edges: (&str, &str)) -> String {
     let mut result = String::new();
     
-    if use_borders {
-        result.push_str("|");
-    }
+    result.push_str(edges.0);
     
     for (i, (cell, &width)) in cells.iter().zip(widths.iter()).enumerate() {
         if i > 0 {
@@ -208,226 +441,1392 @@ fn build_row(cells: &[String], widths: &[usize], aligns: &[char], sep: &str, pad
         }
     }
     
-    if use_borders {
-        result.push_str("|");
+    result.push_str(edges.1);
+    
+    result
+}
+
+const DEFAULT_FLOATFMT: &str = "g";
+const DEFAULT_INTFMT: &str = "";
+const DEFAULT_INFVAL: &str = "∞";
+
+/// `floatfmt` or `intfmt`: one format spec for every column, or one per
+/// column
+#[derive(FromPyObject)]
+enum FormatSpecs {
+    All(String),
+    PerColumn(Vec<String>),
+}
+
+impl FormatSpecs {
+    /// Spec for column `col`; columns past the end of a list use `default`
+    fn column<'a>(&'a self, col: usize, default: &'a str) -> &'a str {
+        match self {
+            FormatSpecs::All(spec) => spec,
+            FormatSpecs::PerColumn(specs) => specs.get(col).map_or(default, |spec| spec.as_str()),
+        }
+    }
+}
+
+/// Column `col`'s spec from an optional `floatfmt` or `intfmt`
+fn column_spec<'a>(specs: &'a Option<FormatSpecs>, col: usize, default: &'a str) -> &'a str {
+    specs.as_ref().map_or(default, |specs| specs.column(col, default))
+}
+
+/// `disable_numparse`: for every column, or a list of column indices
+#[derive(FromPyObject)]
+enum DisableNumparse {
+    All(bool),
+    Columns(Vec<usize>),
+}
+
+impl Default for DisableNumparse {
+    fn default() -> Self {
+        DisableNumparse::All(false)
+    }
+}
+
+impl DisableNumparse {
+    /// Whether column `col` is treated as text: not auto-aligned as a
+    /// number and its floats not passed through `floatfmt`
+    fn column(&self, col: usize) -> bool {
+        match self {
+            DisableNumparse::All(disabled) => *disabled,
+            DisableNumparse::Columns(cols) => cols.contains(&col),
+        }
+    }
+}
+
+/// `rowalign`: one vertical alignment for every row, or one per row
+#[derive(FromPyObject)]
+enum RowAlign {
+    All(String),
+    PerRow(Vec<String>),
+}
+
+impl RowAlign {
+    /// Vertical alignment code for data row `row`; rows past the end of a
+    /// list are top-aligned
+    fn row(&self, row: usize) -> char {
+        match self {
+            RowAlign::All(name) => parse_valign(name),
+            RowAlign::PerRow(names) => names.get(row).map_or('t', |name| parse_valign(name)),
+        }
+    }
+}
+
+/// `maxcolwidths`: one limit for every column, or one per column with
+/// `None` for columns left unwrapped
+#[derive(Clone, FromPyObject)]
+enum MaxColWidths {
+    All(usize),
+    PerColumn(Vec<Option<usize>>),
+}
+
+impl MaxColWidths {
+    fn column(&self, col: usize) -> Option<usize> {
+        match self {
+            MaxColWidths::All(width) => Some(*width),
+            MaxColWidths::PerColumn(widths) => widths.get(col).copied().flatten(),
+        }
+    }
+    
+    /// Wrap each cell wider than its column's limit
+    fn wrap(&self, rows: &mut [Vec<String>]) {
+        for row in rows {
+            for (col, cell) in row.iter_mut().enumerate() {
+                if let Some(width) = self.column(col).filter(|&width| cell_width(cell) > width) {
+                    *cell = wrap_text(cell, width);
+                }
+            }
+        }
+    }
+}
+
+/// A float format spec in the subset of Python's mini-language handled
+/// natively: `[width][.precision][type]` with type one of `fFeEgG%`
+struct FloatSpec {
+    width: usize,
+    precision: Option<usize>,
+    kind: Option<char>,
+}
+
+impl FloatSpec {
+    fn parse(spec: &str) -> Option<Self> {
+        let (rest, kind) = match spec.chars().last() {
+            Some(c @ ('f' | 'F' | 'e' | 'E' | 'g' | 'G' | '%')) => (&spec[..spec.len() - 1], Some(c)),
+            _ => (spec, None),
+        };
+        let (width, precision) = match rest.split_once('.') {
+            Some((width, precision)) => (width, Some(precision)),
+            None => (rest, None),
+        };
+        let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if !digits(width) || width.starts_with('0') || precision.is_some_and(|p| p.is_empty() || !digits(p)) {
+            return None;
+        }
+        // Python's type-less `.N` differs subtly from `.Ng`; leave it to Python
+        if precision.is_some() && kind.is_none() {
+            return None;
+        }
+        Some(FloatSpec {
+            width: width.parse().unwrap_or(0),
+            precision: precision.map(|p| p.parse()).transpose().ok()?,
+            kind,
+        })
+    }
+}
+
+/// Python's exponent form: `1.5e3` becomes `1.5e+03`
+fn python_exponent(formatted: &str, upper: bool) -> String {
+    let (mantissa, exp) = formatted.split_once('e').unwrap_or((formatted, "0"));
+    let exp: i32 = exp.parse().unwrap_or(0);
+    let sign = if exp < 0 { '-' } else { '+' };
+    format!("{}{}{}{:02}", mantissa, if upper { 'E' } else { 'e' }, sign, exp.abs())
+}
+
+/// Format finite `f` as Python's `format(f, "[.precision]<kind>")` would
+fn format_float(f: f64, precision: Option<usize>, kind: char) -> String {
+    let upper = kind.is_ascii_uppercase();
+    let precision = precision.unwrap_or(6);
+    match kind {
+        'f' | 'F' => format!("{:.*}", precision, f),
+        '%' => format!("{:.*}%", precision, f * 100.0),
+        'e' | 'E' => python_exponent(&format!("{:.*e}", precision, f), upper),
+        _ => {
+            // General format: scientific only for exponents outside
+            // [-4, precision), trailing zeros dropped either way
+            let precision = precision.max(1);
+            let scientific = format!("{:.*e}", precision - 1, f);
+            let exp: i32 = scientific.split_once('e').and_then(|(_, e)| e.parse().ok()).unwrap_or(0);
+            let strip = |s: &str| {
+                if s.contains('.') {
+                    s.trim_end_matches('0').trim_end_matches('.').to_string()
+                } else {
+                    s.to_string()
+                }
+            };
+            if (-4..precision as i32).contains(&exp) {
+                strip(&format!("{:.*}", (precision as i32 - 1 - exp) as usize, f))
+            } else {
+                let (mantissa, exp) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+                python_exponent(&format!("{}e{}", strip(mantissa), exp), upper)
+            }
+        }
+    }
+}
+
+/// Group an integer's digits in threes, e.g. `-1234567` into `-1,234,567`
+fn group_digits(digits: &str, sep: char) -> String {
+    let (sign, digits) = digits.split_at(usize::from(digits.starts_with('-')));
+    let mut grouped = String::from(sign);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(sep);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// Format a single Python cell value as display text. Floats follow
+/// `float_fmt` and ints `int_fmt`; bools keep their `str()`. NaN counts as
+/// missing and infinities show as `infval`, signed when negative.
+fn format_cell(cell: &Bound<'_, PyAny>, missing: &str, infval: &str, float_fmt: &str, int_fmt: &str) -> String {
+    let as_str = |cell: &Bound<'_, PyAny>| cell.str().map(|s| s.to_string()).unwrap_or_default();
+    let python_format = |cell: &Bound<'_, PyAny>, spec: &str| {
+        cell.call_method1("__format__", (spec,))
+            .map(|s| as_str(&s))
+            .unwrap_or_else(|_| as_str(cell))
+    };
+    if cell.is_none() {
+        return missing.to_string();
+    }
+    if cell.is_instance_of::<PyBool>() {
+        return as_str(cell);
+    }
+    if cell.is_instance_of::<PyLong>() {
+        return match int_fmt {
+            "" => as_str(cell),
+            "," | "_" => group_digits(&as_str(cell), int_fmt.chars().next().unwrap_or(',')),
+            _ => python_format(cell, int_fmt),
+        };
+    }
+    let Ok(f) = cell.extract::<f64>() else {
+        return as_str(cell);
+    };
+    if f.is_nan() {
+        return missing.to_string();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { infval.to_string() } else { format!("-{}", infval) };
+    }
+    match FloatSpec::parse(float_fmt) {
+        Some(FloatSpec { width, precision, kind }) => {
+            let text = match kind {
+                Some(kind) => format_float(f, precision, kind),
+                None => as_str(cell),
+            };
+            format!("{:>width$}", text)
+        }
+        // Anything else (signs, fill, grouping...) goes through Python
+        None => python_format(cell, float_fmt),
+    }
+}
+
+/// Extract the `str()` of each item of a sequence or other iterable, e.g.
+/// `headers` or `colalign`; a lone string or a non-iterable is a TypeError
+fn extract_strings(name: &str, value: &Bound<'_, PyAny>) -> PyResult<Vec<String>> {
+    let not_a_sequence = || {
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "{} must be a sequence of strings, not {}",
+            name,
+            value.get_type().name().map(|n| n.to_string()).unwrap_or_default()
+        ))
+    };
+    if value.is_instance_of::<PyString>() {
+        return Err(not_a_sequence());
+    }
+    value
+        .iter()
+        .map_err(|_| not_a_sequence())?
+        .map(|item| Ok(item?.str()?.to_string()))
+        .collect()
+}
+
+/// How the header row is given
+enum Headers {
+    List(Vec<String>),
+    /// `headers="firstrow"`: the first data row is the header
+    FirstRow,
+    /// `headers="keys"`: dict keys, or column indices for list rows
+    Keys,
+}
+
+impl Headers {
+    fn from_arg(headers: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let Some(headers) = headers else {
+            return Ok(Headers::List(Vec::new()));
+        };
+        match headers.extract::<&str>() {
+            Ok("firstrow") => Ok(Headers::FirstRow),
+            Ok("keys") => Ok(Headers::Keys),
+            Ok(other) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "headers must be a list, \"firstrow\" or \"keys\", not {:?}",
+                other
+            ))),
+            Err(_) => Ok(Headers::List(extract_strings("headers", headers)?)),
+        }
+    }
+}
+
+/// Whether to prepend an index column, and its labels
+#[derive(Clone)]
+enum ShowIndex {
+    Never,
+    /// `showindex=True` or `"always"`: 0-based row numbers
+    Always,
+    /// `showindex=[...]`: one label per row
+    Labels(Vec<String>),
+}
+
+impl ShowIndex {
+    fn from_arg(showindex: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let Some(showindex) = showindex.filter(|arg| !arg.is_none()) else {
+            return Ok(ShowIndex::Never);
+        };
+        if let Ok(flag) = showindex.downcast::<PyBool>() {
+            return Ok(if flag.is_true() { ShowIndex::Always } else { ShowIndex::Never });
+        }
+        match showindex.extract::<&str>() {
+            Ok("always") => Ok(ShowIndex::Always),
+            Ok("never" | "default") => Ok(ShowIndex::Never),
+            Ok(other) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "showindex must be a bool, \"default\", \"always\", \"never\" or a list of labels, not {:?}",
+                other
+            ))),
+            Err(_) => Ok(ShowIndex::Labels(
+                showindex
+                    .iter()?
+                    .map(|label| Ok(label?.str()?.to_string()))
+                    .collect::<PyResult<_>>()?,
+            )),
+        }
+    }
+    
+    /// Index labels for `num_rows` rows of `data_cols` cells, or `None`
+    /// without an index
+    fn labels(self, num_rows: usize, data_cols: usize) -> PyResult<Option<Vec<String>>> {
+        match self {
+            ShowIndex::Never => Ok(None),
+            ShowIndex::Always => Ok(Some((0..num_rows).map(|i| i.to_string()).collect())),
+            ShowIndex::Labels(labels) if labels.len() == num_rows => Ok(Some(labels)),
+            ShowIndex::Labels(labels) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "index must be as long as the number of data rows ({} labels for {} rows); it is \
+                 prepended as column 0, so lists of {} entries for headers, colalign, headersalign \
+                 or maxcolwidths describe the data columns and {} entries include the index",
+                labels.len(),
+                num_rows,
+                data_cols,
+                data_cols + 1
+            ))),
+        }
+    }
+}
+
+/// Make room for an index column in options that describe `data_cols`
+/// data columns, as `tabulate` documents: each list short of an entry for
+/// the index gets one (a blank header unless `labels_index`, right
+/// alignment, no width limit), and the index is never parsed as text
+fn shift_past_index(
+    data_cols: usize,
+    labels_index: bool,
+    header_row: &mut Vec<String>,
+    colalign: &mut Option<Vec<String>>,
+    headersalign: &mut Option<HeadersAlign>,
+    maxcolwidths: &mut Option<MaxColWidths>,
+    text_cols: &mut Vec<bool>,
+) {
+    text_cols.insert(0, false);
+    if !header_row.is_empty() && header_row.len() <= data_cols && !labels_index {
+        header_row.insert(0, String::new());
+    }
+    let colalign = colalign.get_or_insert_with(Vec::new);
+    if colalign.len() <= data_cols {
+        colalign.insert(0, "right".to_string());
+    }
+    if let Some(HeadersAlign::PerColumn(names)) = headersalign {
+        if names.len() <= data_cols {
+            names.insert(0, "same".to_string());
+        }
+    }
+    if let Some(MaxColWidths::PerColumn(limits)) = maxcolwidths {
+        if limits.len() <= data_cols {
+            limits.insert(0, None);
+        }
+    }
+}
+
+/// Lay out dict rows as columns in first-seen key order, filling keys a
+/// row lacks with `missing`; returns the keys and the rows
+fn dict_rows_to_columns<T: Clone>(dict_rows: Vec<Vec<(String, T)>>, missing: T) -> (Vec<String>, Vec<Vec<T>>) {
+    let mut keys: Vec<String> = Vec::new();
+    for (key, _) in dict_rows.iter().flatten() {
+        if !keys.contains(key) {
+            keys.push(key.clone());
+        }
+    }
+    let rows = dict_rows
+        .into_iter()
+        .map(|row| {
+            keys.iter()
+                .map(|key| match row.iter().find(|(k, _)| k == key) {
+                    Some((_, value)) => value.clone(),
+                    None => missing.clone(),
+                })
+                .collect()
+        })
+        .collect();
+    (keys, rows)
+}
+
+/// Alignment code for a tabulate alignment name
+fn parse_align(name: &str) -> char {
+    match name {
+        "right" => 'r',
+        "center" => 'c',
+        "decimal" => 'd',
+        _ => 'l',
+    }
+}
+
+/// Whether a cell reads as a number, allowing padding, `,` or `_` digit
+/// grouping as `intfmt` and `floatfmt` may produce, and the default `infval`
+fn is_number(cell: &str) -> bool {
+    let cell = cell.trim();
+    cell.parse::<f64>().is_ok()
+        || cell.strip_prefix('-').unwrap_or(cell) == DEFAULT_INFVAL
+        || (cell.contains([',', '_']) && cell.replace([',', '_'], "").parse::<f64>().is_ok())
+}
+
+/// Resolve per-column alignment. Precedence is a `colalign` entry, then
+/// `colglobalalign`, then auto-detection (numbers by `numalign`, with
+/// `"decimal"` giving `'d'`, and strings by `stralign`). A `colalign` entry
+/// of `"global"` defers to the latter two. Columns flagged in `text_cols`
+/// are never detected as numbers, and `missing` cells don't count.
+#[allow(clippy::too_many_arguments)]
+fn resolve_aligns(
+    rows: &[Vec<String>],
+    num_cols: usize,
+    text_cols: &[bool],
+    missing: &str,
+    colalign: Option<&[String]>,
+    colglobalalign: Option<&str>,
+    num_align: &str,
+    str_align: &str,
+) -> Vec<char> {
+    let mut aligns: Vec<char> = match colglobalalign {
+        Some(global) => vec![parse_align(global); num_cols],
+        None => (0..num_cols)
+            .map(|i| {
+                let is_numeric = !text_cols.get(i).copied().unwrap_or(false) && rows.iter().all(|row| {
+                    row.get(i)
+                        .map(|s| is_number(&strip_ansi(s)) || s.is_empty() || s == missing)
+                        .unwrap_or(true)
+                });
+                parse_align(if is_numeric { num_align } else { str_align })
+            })
+            .collect(),
+    };
+    
+    if let Some(list) = colalign {
+        for (align, name) in aligns.iter_mut().zip(list) {
+            if name != "global" {
+                *align = parse_align(name);
+            }
+        }
     }
     
+    aligns
+}
+
+/// Default `(numalign, stralign)` for a format; `pretty` centers every
+/// column, as PrettyTable does
+fn default_aligns(fmt_name: &str) -> (&'static str, &'static str) {
+    match fmt_name {
+        "pretty" => ("center", "center"),
+        _ => ("right", "left"),
+    }
+}
+
+/// `headersalign`: one alignment for every header, or one per column
+#[derive(FromPyObject)]
+#[derive(Clone)]
+enum HeadersAlign {
+    All(String),
+    PerColumn(Vec<String>),
+}
+
+/// Header alignment. Precedence is a `headersalign` entry, then
+/// `headersglobalalign`, then the column's own alignment; a `headersalign`
+/// entry of `"same"`, or a missing one, defers to the latter two.
+fn header_aligns(aligns: &[char], headersglobalalign: Option<&str>, headersalign: Option<&HeadersAlign>) -> Vec<char> {
+    let mut result = match headersglobalalign {
+        Some(global) => vec![parse_align(global); aligns.len()],
+        None => aligns.to_vec(),
+    };
+    let names = match headersalign {
+        Some(HeadersAlign::All(name)) => vec![name.clone(); aligns.len()],
+        Some(HeadersAlign::PerColumn(names)) => names.clone(),
+        None => Vec::new(),
+    };
+    for (align, name) in result.iter_mut().zip(&names) {
+        if name != "same" {
+            *align = parse_align(name);
+        }
+    }
     result
 }
 
+/// Split a number into the text before its decimal point and the rest,
+/// e.g. `"-3.14"` into `("-3", ".14")`. Integers have an empty fraction.
+/// Scientific values split on the mantissa's point, or on the exponent
+/// marker when the mantissa has none (`"1e5"` into `("1", "e5")`).
+fn split_decimal(cell: &str) -> (&str, &str) {
+    match cell.find('.').or_else(|| cell.find(['e', 'E'])) {
+        Some(at) => cell.split_at(at),
+        None => (cell, ""),
+    }
+}
+
+/// Pad the numbers in decimal-aligned (`'d'`) columns to a common integer
+/// and fractional width so their points line up once right-aligned.
+/// Other cells, such as missing values, are left to plain right alignment.
+fn align_decimals(rows: &mut [Vec<String>], aligns: &[char]) {
+    for (col, _) in aligns.iter().enumerate().filter(|(_, &align)| align == 'd') {
+        let (mut int_width, mut frac_width) = (0, 0);
+        for cell in rows.iter().filter_map(|row| row.get(col)).filter(|cell| is_number(cell)) {
+            let (int_part, frac_part) = split_decimal(cell.trim());
+            int_width = int_width.max(visible_width(int_part));
+            frac_width = frac_width.max(visible_width(frac_part));
+        }
+        
+        // Padded by display width, since infval ("-∞") isn't one byte per column
+        for cell in rows.iter_mut().filter_map(|row| row.get_mut(col)) {
+            if is_number(cell) {
+                let (int_part, frac_part) = split_decimal(cell.trim());
+                let int_pad = " ".repeat(int_width - visible_width(int_part));
+                let frac_pad = " ".repeat(frac_width - visible_width(frac_part));
+                *cell = format!("{}{}{}{}", int_pad, int_part, frac_part, frac_pad);
+            }
+        }
+    }
+}
+
+/// Widest visible cell per column, headers included
+fn column_widths(header_row: &[String], rows: &[Vec<String>], num_cols: usize) -> Vec<usize> {
+    let mut widths: Vec<usize> = vec![0; num_cols];
+    
+    for row in std::iter::once(header_row).chain(rows.iter().map(|r| r.as_slice())) {
+        for (i, cell) in row.iter().enumerate().take(num_cols) {
+            widths[i] = widths[i].max(cell_width(cell));
+        }
+    }
+    
+    widths
+}
+
+/// Pad a row with `missing` cells up to `num_cols`
+fn fill_row(row: &mut Vec<String>, num_cols: usize, missing: &str) {
+    while row.len() < num_cols {
+        row.push(missing.to_string());
+    }
+}
+
+/// Drawing rules for a table format
+struct TableStyle {
+    format: TableFormat,
+    sep: Cow<'static, str>,
+    /// Text before the first and after the last cell of a row
+    edges: (Cow<'static, str>, Cow<'static, str>),
+    /// ANSI code applied to rules, borders and column separators
+    border_color: Option<String>,
+}
+
+impl TableStyle {
+    fn new(fmt_name: &str, border_color: Option<&str>) -> Self {
+        let sep = get_separator(fmt_name);
+        // Bordered formats draw their outer edges with the column separator
+        let use_borders = matches!(
+            fmt_name,
+            "github" | "pipe" | "orgtbl" | "rounded_grid" | "heavy_grid" | "double_grid" | "grid" | "pretty" | "fancy_grid" | "fancy_outline"
+        );
+        let edge = if use_borders { sep } else { "" };
+        Self {
+            format: get_format(fmt_name),
+            sep: Cow::Borrowed(sep),
+            edges: (Cow::Borrowed(edge), Cow::Borrowed(edge)),
+            border_color: border_color.filter(|c| !c.is_empty()).map(str::to_string),
+        }
+    }
+    
+    /// Style for a `tablefmt` argument: a format name, or a dict as read by
+    /// `format_from_dict`. Also returns the name, empty for a dict.
+    fn from_arg(tablefmt: Option<&Bound<'_, PyAny>>, border_color: Option<&str>) -> PyResult<(String, Self)> {
+        let Some(tablefmt) = tablefmt.filter(|arg| !arg.is_none()) else {
+            return Ok(("simple".to_string(), Self::new("simple", border_color)));
+        };
+        if let Ok(name) = tablefmt.extract::<String>() {
+            let style = Self::new(&name, border_color);
+            return Ok((name, style));
+        }
+        let dict = tablefmt
+            .downcast::<PyDict>()
+            .map_err(|_| pyo3::exceptions::PyValueError::new_err("tablefmt must be a format name or a dict"))?;
+        let (format, [begin, sep, end]) = format_from_dict(dict)?;
+        let style = Self {
+            format,
+            sep,
+            edges: (begin, end),
+            border_color: border_color.filter(|c| !c.is_empty()).map(str::to_string),
+        };
+        Ok((String::new(), style))
+    }
+    
+    /// Replace the column separator with `colsep`, unless the format draws
+    /// row borders, whose separator stays part of the box. Rule junctions
+    /// are resized to match so columns stay lined up.
+    fn with_colsep(mut self, colsep: Option<&str>) -> Self {
+        let Some(colsep) = colsep.filter(|_| self.edges_width() == 0) else {
+            return self;
+        };
+        let width = visible_width(colsep);
+        let format = &mut self.format;
+        for line in [&mut format.line_above, &mut format.line_below, &mut format.line_between_rows, &mut format.header_line]
+            .into_iter()
+            .flatten()
+        {
+            line.sep = Cow::Owned(fit_junction(&line.sep, &line.hline, width));
+        }
+        self.sep = Cow::Owned(colsep.to_string());
+        self
+    }
+    
+    /// Color structural glyphs, resetting right after so cell content and
+    /// later output keep their own colors. Whitespace is left as is.
+    fn paint(&self, glyphs: &str) -> String {
+        match &self.border_color {
+            Some(color) if !glyphs.trim().is_empty() => format!("{}{}{}", color, glyphs, ANSI_RESET),
+            _ => glyphs.to_string(),
+        }
+    }
+    
+    fn row(&self, cells: &[String], widths: &[usize], aligns: &[char], valign: char) -> String {
+        let edges = (self.paint(&self.edges.0), self.paint(&self.edges.1));
+        build_row(cells, widths, aligns, valign, &self.paint(&self.sep), self.format.padding, (&edges.0, &edges.1))
+    }
+    
+    fn line(&self, line: &Option<Line>, widths: &[usize]) -> Option<String> {
+        line.as_ref().map(|line| self.paint(&build_line(widths, line, self.format.padding)))
+    }
+    
+    /// Visible width of a rendered row
+    fn table_width(&self, widths: &[usize]) -> usize {
+        let cells: usize = widths.iter().map(|w| w + 2 * self.format.padding).sum();
+        self.edges_width() + cells + visible_width(&self.sep) * widths.len().saturating_sub(1)
+    }
+    
+    /// Whether captions sit inside the outer border rather than above or
+    /// below the table
+    fn boxed(&self) -> bool {
+        self.edges_width() > 0 && self.format.line_above.is_some()
+    }
+    
+    /// Visible width of a row's two edges
+    fn edges_width(&self) -> usize {
+        visible_width(&self.edges.0) + visible_width(&self.edges.1)
+    }
+    
+    /// Room for caption text on one line
+    fn caption_width(&self, widths: &[usize]) -> usize {
+        let width = self.table_width(widths);
+        if self.boxed() {
+            width.saturating_sub(self.edges_width() + 2 * self.format.padding)
+        } else {
+            width
+        }
+    }
+    
+    /// Widen the columns evenly so `caption` fits on one line
+    fn fit_caption(&self, widths: &mut [usize], caption: &str) {
+        let extra = visible_width(caption).saturating_sub(self.caption_width(widths));
+        let count = widths.len();
+        for (i, width) in widths.iter_mut().enumerate() {
+            *width += extra / count + usize::from(i < extra % count);
+        }
+    }
+    
+    /// A centered caption line; boxed formats get a single spanning cell
+    fn caption(&self, text: &str, widths: &[usize]) -> String {
+        let width = self.caption_width(widths);
+        if self.boxed() {
+            self.row(&[text.to_string()], &[width], &['c'], 't')
+        } else {
+            pad_cell(text, width, 'c').trim_end().to_string()
+        }
+    }
+    
+    /// Rule with no column junctions, e.g. the top border above a title
+    fn spanning_line(&self, line: &Option<Line>, widths: &[usize]) -> Option<String> {
+        self.line(line, &[self.caption_width(widths)])
+    }
+    
+    /// Rule between a caption and the columns, taking its junctions from
+    /// `edge` (the top or bottom border) so columns start or end there
+    fn caption_rule(&self, edge: &Option<Line>, widths: &[usize]) -> Option<String> {
+        let edge = edge.as_ref()?;
+        let inner = self.format.line_between_rows.as_ref().unwrap_or(edge);
+        let line = Line {
+            sep: edge.sep.clone(),
+            ..inner.clone()
+        };
+        self.line(&Some(line), widths)
+    }
+    
+    /// Lines preceding the first data row: title, top rule, header and
+    /// header rule
+    fn head(&self, header_row: &[String], widths: &[usize], header_aligns: &[char], title: Option<&str>) -> Vec<String> {
+        let mut output = Vec::new();
+        
+        // Title, then the top line
+        match title {
+            Some(title) if self.boxed() => {
+                output.extend(self.spanning_line(&self.format.line_above, widths));
+                output.push(self.caption(title, widths));
+                output.extend(self.caption_rule(&self.format.line_above, widths));
+            }
+            Some(title) => {
+                output.push(self.caption(title, widths));
+                output.extend(self.line(&self.format.line_above, widths));
+            }
+            None => output.extend(self.line(&self.format.line_above, widths)),
+        }
+        
+        // Header
+        if !header_row.is_empty() {
+            let mut padded_headers = header_row.to_vec();
+            fill_row(&mut padded_headers, widths.len(), "");
+            output.push(self.row(&padded_headers, widths, header_aligns, 't'));
+            
+            // Header separator
+            output.extend(self.line(&self.format.header_line, widths));
+        }
+        
+        output
+    }
+    
+    /// Closing lines after the last data row: bottom rule and footer
+    fn tail(&self, widths: &[usize], has_header: bool, footer: Option<&str>) -> Vec<String> {
+        let mut output = Vec::new();
+        
+        if let Some(footer) = footer.filter(|_| self.boxed()) {
+            output.extend(self.caption_rule(&self.format.line_below, widths));
+            output.push(self.caption(footer, widths));
+            output.extend(self.spanning_line(&self.format.line_below, widths));
+            return output;
+        }
+        
+        // Bottom line; `with_header_hide` formats such as simple drop it
+        // when the header rule already frames the table
+        if !(self.format.with_header_hide && has_header) {
+            output.extend(self.line(&self.format.line_below, widths));
+        }
+        
+        output.extend(footer.map(|footer| self.caption(footer, widths)));
+        
+        output
+    }
+}
+
+/// Escape LaTeX's special characters in cell text
+fn latex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '^' => escaped.push_str("\\^{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render a `tabular` environment for `latex` (`\hline` rules) or
+/// `latex_booktabs` (`\toprule`, `\midrule` and `\bottomrule`). Cells
+/// must already be escaped; the column spec comes from `aligns`, with
+/// decimal columns set right-aligned.
+fn render_latex(header_row: &[String], rows: &[Vec<String>], widths: &[usize], aligns: &[char], header_aligns: &[char], booktabs: bool) -> String {
+    let (top, mid, bottom) = if booktabs {
+        ("\\toprule", "\\midrule", "\\bottomrule")
+    } else {
+        ("\\hline", "\\hline", "\\hline")
+    };
+    let spec: String = aligns
+        .iter()
+        .map(|align| match align {
+            'r' | 'd' => 'r',
+            'c' => 'c',
+            _ => 'l',
+        })
+        .collect();
+    let row = |cells: &[String], aligns: &[char]| format!("{}\\\\", build_row(cells, widths, aligns, 't', "&", 1, ("", "")));
+    
+    let mut output = vec![format!("\\begin{{tabular}}{{{}}}", spec), top.to_string()];
+    if !header_row.is_empty() {
+        let mut padded_headers = header_row.to_vec();
+        fill_row(&mut padded_headers, widths.len(), "");
+        output.push(row(&padded_headers, header_aligns));
+        output.push(mid.to_string());
+    }
+    output.extend(rows.iter().map(|cells| row(cells, aligns)));
+    output.push(bottom.to_string());
+    output.push("\\end{tabular}".to_string());
+    output.join("\n")
+}
+
+/// Escape HTML's special characters in cell text
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render an HTML `<table>`, with a `<thead>` when there are headers.
+/// Cells must already be escaped; as in tabulate, they are padded to the
+/// column width and non-left columns get a `text-align` style.
+fn render_html(header_row: &[String], rows: &[Vec<String>], widths: &[usize], aligns: &[char], header_aligns: &[char]) -> String {
+    let row = |cells: &[String], aligns: &[char], tag: &str| {
+        let cells: String = cells
+            .iter()
+            .zip(widths)
+            .zip(aligns)
+            .map(|((cell, &width), &align)| {
+                let style = match align {
+                    'r' | 'd' => " style=\"text-align: right;\"",
+                    'c' => " style=\"text-align: center;\"",
+                    _ => "",
+                };
+                format!("<{tag}{style}>{}</{tag}>", pad_cell(cell, width, align))
+            })
+            .collect();
+        format!("<tr>{}</tr>", cells)
+    };
+    
+    let mut output = vec!["<table>".to_string()];
+    if !header_row.is_empty() {
+        let mut padded_headers = header_row.to_vec();
+        fill_row(&mut padded_headers, widths.len(), "");
+        output.push("<thead>".to_string());
+        output.push(row(&padded_headers, header_aligns, "th"));
+        output.push("</thead>".to_string());
+    }
+    output.push("<tbody>".to_string());
+    output.extend(rows.iter().map(|cells| row(cells, aligns, "td")));
+    output.push("</tbody>".to_string());
+    output.push("</table>".to_string());
+    output.join("\n")
+}
+
+/// Render a MediaWiki `wikitable`: `!` header cells, `|` data cells and
+/// `|-` between rows. As in tabulate, cells are padded to the column width
+/// and non-left columns carry an `align` attribute. Cells must already
+/// have `|` escaped.
+fn render_mediawiki(header_row: &[String], rows: &[Vec<String>], widths: &[usize], aligns: &[char], header_aligns: &[char]) -> String {
+    let row = |cells: &[String], aligns: &[char], sep: &str| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .zip(aligns)
+            .map(|((cell, &width), &align)| {
+                let attr = match align {
+                    'r' | 'd' => "align=\"right\"| ",
+                    'c' => "align=\"center\"| ",
+                    _ => "",
+                };
+                format!(" {}{} ", attr, pad_cell(cell, width, align))
+            })
+            .collect();
+        format!("{}{}", sep, cells.join(&sep.repeat(2))).trim_end().to_string()
+    };
+    
+    let mut output = vec![
+        "{| class=\"wikitable\" style=\"text-align: left;\"".to_string(),
+        "|+ <!-- caption -->".to_string(),
+        "|-".to_string(),
+    ];
+    if !header_row.is_empty() {
+        let mut padded_headers = header_row.to_vec();
+        fill_row(&mut padded_headers, widths.len(), "");
+        output.push(row(&padded_headers, header_aligns, "!"));
+        output.push("|-".to_string());
+    }
+    for (i, cells) in rows.iter().enumerate() {
+        if i > 0 {
+            output.push("|-".to_string());
+        }
+        output.push(row(cells, aligns, "|"));
+    }
+    output.push("|}".to_string());
+    output.join("\n")
+}
+
+/// Render a Jira table: `||`-delimited header cells and `|`-delimited data
+/// cells. Cells must already have `|` escaped.
+fn render_jira(header_row: &[String], rows: &[Vec<String>], widths: &[usize], aligns: &[char], header_aligns: &[char]) -> String {
+    let mut output = Vec::new();
+    if !header_row.is_empty() {
+        let mut padded_headers = header_row.to_vec();
+        fill_row(&mut padded_headers, widths.len(), "");
+        output.push(build_row(&padded_headers, widths, header_aligns, 't', "||", 1, ("||", "||")));
+    }
+    output.extend(rows.iter().map(|cells| build_row(cells, widths, aligns, 't', "|", 1, ("|", "|"))));
+    output.join("\n")
+}
+
 /// Main tabulate function
 /// tabulate([["a", "b"], ["c", "d"]], headers=["X", "Y"]) -> formatted table
 #[pyfunction]
-#[pyo3(signature = (tabular_data, headers=None, tablefmt=None, floatfmt=None, numalign=None, stralign=None, missingval=None, showindex=None, disable_numparse=None, colalign=None))]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (tabular_data, headers=None, tablefmt=None, floatfmt=None, intfmt=None, numalign=None, stralign=None, missingval=None, infval=None, showindex=None, disable_numparse=None, colalign=None, colglobalalign=None, headersglobalalign=None, headersalign=None, maxcolwidths=None, rowalign=None, colsep=None, border_color=None, title=None, footer=None))]
 fn tabulate(
     py: Python<'_>,
     tabular_data: &Bound<'_, PyAny>,
     headers: Option<&Bound<'_, PyAny>>,
-    tablefmt: Option<&str>,
-    floatfmt: Option<&str>,
+    tablefmt: Option<&Bound<'_, PyAny>>,
+    floatfmt: Option<FormatSpecs>,
+    intfmt: Option<FormatSpecs>,
     numalign: Option<&str>,
     stralign: Option<&str>,
     missingval: Option<&str>,
+    infval: Option<&str>,
     showindex: Option<&Bound<'_, PyAny>>,
-    disable_numparse: Option<bool>,
+    disable_numparse: Option<DisableNumparse>,
     colalign: Option<&Bound<'_, PyAny>>,
+    colglobalalign: Option<&str>,
+    headersglobalalign: Option<&str>,
+    headersalign: Option<HeadersAlign>,
+    maxcolwidths: Option<MaxColWidths>,
+    rowalign: Option<RowAlign>,
+    colsep: Option<&str>,
+    border_color: Option<&str>,
+    title: Option<&str>,
+    footer: Option<&str>,
 ) -> PyResult<String> {
-    let fmt_name = tablefmt.unwrap_or("simple");
-    let format = get_format(fmt_name);
-    let sep = get_separator(fmt_name);
+    let (fmt_name, style) = TableStyle::from_arg(tablefmt, border_color)?;
+    let style = style.with_colsep(colsep);
+    let fmt_name = fmt_name.as_str();
     let missing = missingval.unwrap_or("");
-    let float_fmt = floatfmt.unwrap_or(".6g");
-    let num_align = numalign.unwrap_or("right");
-    let str_align = stralign.unwrap_or("left");
-    let _disable_num = disable_numparse.unwrap_or(false);
-    
-    let use_borders = matches!(fmt_name, "github" | "pipe" | "orgtbl" | "rounded_grid" | "heavy_grid" | "double_grid" | "grid" | "pretty");
-    
-    // Parse headers
-    let header_row: Vec<String> = if let Some(h) = headers {
-        if let Ok(list) = h.downcast::<PyList>() {
-            list.iter()
-                .map(|item| item.str().map(|s| s.to_string()).unwrap_or_default())
-                .collect()
-        } else if let Ok(s) = h.extract::<String>() {
-            if s == "firstrow" || s == "keys" {
-                vec![] // Will handle specially
-            } else {
-                vec![]
-            }
-        } else {
-            vec![]
-        }
-    } else {
-        vec![]
-    };
+    let infval = infval.unwrap_or(DEFAULT_INFVAL);
+    let (default_num, default_str) = default_aligns(fmt_name);
+    let num_align = numalign.unwrap_or(default_num);
+    let str_align = stralign.unwrap_or(default_str);
+    let disable_numparse = disable_numparse.unwrap_or_default();
+    
+    let headers = Headers::from_arg(headers)?;
+    let show_index = ShowIndex::from_arg(showindex)?;
     
     // Parse data rows
     let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut dict_rows: Vec<Vec<(String, Bound<'_, PyAny>)>> = Vec::new();
+    let format_row = |row: &[Bound<'_, PyAny>]| -> Vec<String> {
+        row.iter()
+            .enumerate()
+            .map(|(col, cell)| {
+                // Empty specs format numbers as `str()` does
+                if disable_numparse.column(col) {
+                    format_cell(cell, missing, infval, "", "")
+                } else {
+                    let float_fmt = column_spec(&floatfmt, col, DEFAULT_FLOATFMT);
+                    format_cell(cell, missing, infval, float_fmt, column_spec(&intfmt, col, DEFAULT_INTFMT))
+                }
+            })
+            .collect()
+    };
     
-    // Handle list of lists
-    if let Ok(list) = tabular_data.downcast::<PyList>() {
-        for item in list.iter() {
-            if let Ok(row_list) = item.downcast::<PyList>() {
-                let row: Vec<String> = row_list
-                    .iter()
-                    .map(|cell| {
-                        if cell.is_none() {
-                            missing.to_string()
-                        } else if let Ok(f) = cell.extract::<f64>() {
-                            // Format float
-                            if float_fmt == ".6g" {
-                                format!("{:.6}", f).trim_end_matches('0').trim_end_matches('.').to_string()
-                            } else {
-                                format!("{}", f)
-                            }
-                        } else {
-                            cell.str().map(|s| s.to_string()).unwrap_or_default()
-                        }
-                    })
-                    .collect();
-                rows.push(row);
-            } else if let Ok(tuple) = item.extract::<Vec<PyObject>>() {
-                let row: Vec<String> = tuple
-                    .iter()
-                    .map(|cell| {
-                        cell.bind(py).str().map(|s| s.to_string()).unwrap_or_default()
-                    })
-                    .collect();
-                rows.push(row);
-            }
+    // Lists take a fast path; any other iterable of rows is iterated
+    let items: Vec<Bound<'_, PyAny>> = match tabular_data.downcast::<PyList>() {
+        Ok(list) => list.iter().collect(),
+        Err(_) => tabular_data.iter()?.collect::<PyResult<_>>()?,
+    };
+    for item in items {
+        if let Ok(dict) = item.downcast::<PyDict>() {
+            let row = dict
+                .iter()
+                .map(|(key, value)| Ok((key.str()?.to_string(), value)))
+                .collect::<PyResult<Vec<_>>>()?;
+            dict_rows.push(row);
+        } else if let Ok(row_list) = item.downcast::<PyList>() {
+            rows.push(format_row(&row_list.iter().collect::<Vec<_>>()));
+        } else if item.is_instance_of::<PyString>() || item.is_instance_of::<PyBytes>() {
+            rows.push(format_row(std::slice::from_ref(&item)));
+        } else if let Ok(cells) = item.iter() {
+            rows.push(format_row(&cells.collect::<PyResult<Vec<_>>>()?));
+        } else {
+            // A scalar is a one-cell row
+            rows.push(format_row(std::slice::from_ref(&item)));
         }
     }
-    // Handle list of dicts
-    else if let Ok(list) = tabular_data.downcast::<PyList>() {
-        if let Some(first) = list.get_item(0).ok() {
-            if let Ok(_dict) = first.downcast::<PyDict>() {
-                // Extract keys as headers, values as rows
-                for item in list.iter() {
-                    if let Ok(dict) = item.downcast::<PyDict>() {
-                        let row: Vec<String> = dict
-                            .values()
-                            .iter()
-                            .map(|v| v.str().map(|s| s.to_string()).unwrap_or_default())
-                            .collect();
-                        rows.push(row);
-                    }
-                }
-            }
+    // Dict cells are formatted once laid out, so per-column formats follow keys
+    let (keys, keyed_rows) = dict_rows_to_columns(dict_rows, py.None().into_bound(py));
+    rows.extend(keyed_rows.iter().map(|row| format_row(row)));
+    
+    // A header list for auto-numbered rows may label the index; see below
+    let listed_headers = matches!(headers, Headers::List(_));
+    let mut header_row: Vec<String> = match headers {
+        Headers::List(header_row) => header_row,
+        Headers::FirstRow if rows.is_empty() => Vec::new(),
+        Headers::FirstRow => rows.remove(0),
+        Headers::Keys if !keys.is_empty() => keys,
+        Headers::Keys => {
+            let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+            (0..width).map(|i| i.to_string()).collect()
         }
-    }
+    };
     
     if rows.is_empty() {
         return Ok(String::new());
     }
     
+    // Prepend the index column. `headers`, `colalign`, `headersalign` and
+    // per-column `maxcolwidths` each describe the data columns only, and are
+    // shifted past the index, unless they have an entry for every column
+    // including it. A shifted index gets a blank header and right alignment.
+    // With `showindex="always"`, a `headers` list one entry shorter than the
+    // widened rows isn't shifted: its first entry labels the index.
+    let mut colalign = colalign.map(|colalign| extract_strings("colalign", colalign)).transpose()?;
+    let mut headersalign = headersalign;
+    let mut maxcolwidths = maxcolwidths;
+    let data_cols = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let mut text_cols: Vec<bool> = (0..data_cols).map(|col| disable_numparse.column(col)).collect();
+    let numbered = matches!(show_index, ShowIndex::Always);
+    if let Some(labels) = show_index.labels(rows.len(), data_cols)? {
+        let labels_index = numbered && listed_headers && header_row.len() == data_cols;
+        shift_past_index(data_cols, labels_index, &mut header_row, &mut colalign, &mut headersalign, &mut maxcolwidths, &mut text_cols);
+        for (row, label) in rows.iter_mut().zip(labels) {
+            row.insert(0, label);
+        }
+    }
+    
     // Calculate column count
     let num_cols = rows.iter().map(|r| r.len()).max().unwrap_or(0);
     let num_cols = num_cols.max(header_row.len());
     
     // Normalize rows to same length
     for row in &mut rows {
-        while row.len() < num_cols {
-            row.push(missing.to_string());
-        }
+        fill_row(row, num_cols, missing);
+    }
+    if let Some(maxcolwidths) = &maxcolwidths {
+        maxcolwidths.wrap(&mut rows);
     }
     
     // Parse column alignments
-    let mut aligns: Vec<char> = vec!['l'; num_cols];
-    
-    if let Some(ca) = colalign {
-        if let Ok(list) = ca.downcast::<PyList>() {
-            for (i, item) in list.iter().enumerate() {
-                if i < num_cols {
-                    if let Ok(s) = item.extract::<String>() {
-                        aligns[i] = match s.as_str() {
-                            "right" => 'r',
-                            "center" => 'c',
-                            _ => 'l',
-                        };
-                    }
-                }
-            }
-        }
-    } else {
-        // Auto-detect: numbers right, strings left
-        for (i, _) in (0..num_cols).enumerate() {
-            let is_numeric = rows.iter().all(|row| {
-                row.get(i)
-                    .map(|s| s.parse::<f64>().is_ok() || s.is_empty())
-                    .unwrap_or(true)
-            });
-            if is_numeric && num_align == "right" {
-                aligns[i] = 'r';
-            } else if !is_numeric && str_align == "left" {
-                aligns[i] = 'l';
-            }
+    let aligns = resolve_aligns(&rows, num_cols, &text_cols, missing, colalign.as_deref(), colglobalalign, num_align, str_align);
+    align_decimals(&mut rows, &aligns);
+    
+    // Markup formats escape cell text before it is measured
+    let escape: Option<fn(&str) -> String> = match fmt_name {
+        "latex" | "latex_booktabs" => Some(latex_escape),
+        "html" => Some(html_escape),
+        "mediawiki" => Some(|cell: &str| cell.replace('|', "&#124;")),
+        "jira" => Some(|cell: &str| cell.replace('|', "\\|")),
+        _ => None,
+    };
+    if let Some(escape) = escape {
+        for cell in header_row.iter_mut().chain(rows.iter_mut().flatten()) {
+            *cell = escape(cell);
         }
     }
     
-    // Calculate column widths
-    let mut widths: Vec<usize> = vec![0; num_cols];
+    // Calculate column widths, widening the table for a long title or footer
+    let mut widths = column_widths(&header_row, &rows, num_cols);
     
-    // Consider headers
-    for (i, h) in header_row.iter().enumerate() {
-        if i < num_cols {
-            widths[i] = widths[i].max(visible_width(h));
+    // Markup formats have their own renderers instead of drawn rules
+    let header_aligns = header_aligns(&aligns, headersglobalalign, headersalign.as_ref());
+    match fmt_name {
+        "latex" | "latex_booktabs" => {
+            let booktabs = fmt_name == "latex_booktabs";
+            return Ok(render_latex(&header_row, &rows, &widths, &aligns, &header_aligns, booktabs));
         }
+        "html" => return Ok(render_html(&header_row, &rows, &widths, &aligns, &header_aligns)),
+        "mediawiki" => return Ok(render_mediawiki(&header_row, &rows, &widths, &aligns, &header_aligns)),
+        "jira" => return Ok(render_jira(&header_row, &rows, &widths, &aligns, &header_aligns)),
+        _ => {}
+    }
+    for caption in title.iter().chain(footer.iter()) {
+        style.fit_caption(&mut widths, caption);
     }
     
-    // Consider data
-    for row in &rows {
-        for (i, cell) in row.iter().enumerate() {
-            if i < num_cols {
-                widths[i] = widths[i].max(visible_width(cell));
-            }
+    // Build output
+    let mut output = style.head(&header_row, &widths, &header_aligns, title);
+    
+    // Data rows
+    for (i, row) in rows.iter().enumerate() {
+        let valign = rowalign.as_ref().map_or('t', |rowalign| rowalign.row(i));
+        output.push(style.row(row, &widths, &aligns, valign));
+        
+        // Row separator (not after last row)
+        if i < rows.len() - 1 {
+            output.extend(style.line(&style.format.line_between_rows, &widths));
         }
     }
     
-    // Build output
-    let mut output = Vec::new();
+    output.extend(style.tail(&widths, !header_row.is_empty(), footer));
     
-    // Top line
-    if let Some(ref line) = format.line_above {
-        output.push(build_line(&widths, line, format.padding));
+    Ok(output.join("\n"))
+}
+
+/// Incremental table builder for rows that arrive over time
+///
+/// Rows are buffered and aligned when `render()` is called. With
+/// `fixed_widths`, column widths are known up front so `add_row` returns
+/// each row's text immediately, and `render()` finishes the table: it
+/// returns what is left to print (the bottom rule, if any) once, after
+/// which further calls return an empty string and `add_row` raises. In
+/// that mode alignment is detected from the first row unless `colalign` is
+/// given, decimal columns fall back to right alignment, longer cells
+/// overflow, as do a `title` or `footer` wider than the table, and with
+/// `showindex` the widths include the index column.
+#[pyclass]
+struct TableWriter {
+    headers: Vec<String>,
+    style: TableStyle,
+    floatfmt: Option<FormatSpecs>,
+    intfmt: Option<FormatSpecs>,
+    numalign: String,
+    stralign: String,
+    missingval: String,
+    infval: String,
+    show_index: ShowIndex,
+    disable_numparse: DisableNumparse,
+    colalign: Option<Vec<String>>,
+    colglobalalign: Option<String>,
+    headersglobalalign: Option<String>,
+    headersalign: Option<HeadersAlign>,
+    maxcolwidths: Option<MaxColWidths>,
+    rowalign: Option<RowAlign>,
+    fixed_widths: Option<Vec<usize>>,
+    title: Option<String>,
+    footer: Option<String>,
+    aligns: Vec<char>,
+    text_cols: Vec<bool>,
+    rows: Vec<Vec<String>>,
+    rows_emitted: usize,
+    finished: bool,
+}
+
+#[pymethods]
+impl TableWriter {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (headers=None, tablefmt=None, floatfmt=None, intfmt=None, numalign=None, stralign=None, missingval="", infval=DEFAULT_INFVAL, showindex=None, disable_numparse=None, colalign=None, colglobalalign=None, headersglobalalign=None, headersalign=None, maxcolwidths=None, rowalign=None, fixed_widths=None, colsep=None, border_color=None, title=None, footer=None))]
+    fn new(
+        headers: Option<&Bound<'_, PyAny>>,
+        tablefmt: Option<&Bound<'_, PyAny>>,
+        floatfmt: Option<FormatSpecs>,
+        intfmt: Option<FormatSpecs>,
+        numalign: Option<&str>,
+        stralign: Option<&str>,
+        missingval: &str,
+        infval: &str,
+        showindex: Option<&Bound<'_, PyAny>>,
+        disable_numparse: Option<DisableNumparse>,
+        colalign: Option<&Bound<'_, PyAny>>,
+        colglobalalign: Option<String>,
+        headersglobalalign: Option<String>,
+        headersalign: Option<HeadersAlign>,
+        maxcolwidths: Option<MaxColWidths>,
+        rowalign: Option<RowAlign>,
+        fixed_widths: Option<Vec<usize>>,
+        colsep: Option<&str>,
+        border_color: Option<&str>,
+        title: Option<String>,
+        footer: Option<String>,
+    ) -> PyResult<Self> {
+        let (fmt_name, style) = TableStyle::from_arg(tablefmt, border_color)?;
+        let style = style.with_colsep(colsep);
+        let (default_num, default_str) = default_aligns(&fmt_name);
+        Ok(Self {
+            headers: headers.map(|headers| extract_strings("headers", headers)).transpose()?.unwrap_or_default(),
+            style,
+            floatfmt,
+            intfmt,
+            numalign: numalign.unwrap_or(default_num).to_string(),
+            stralign: stralign.unwrap_or(default_str).to_string(),
+            missingval: missingval.to_string(),
+            infval: infval.to_string(),
+            show_index: ShowIndex::from_arg(showindex)?,
+            disable_numparse: disable_numparse.unwrap_or_default(),
+            colalign: colalign.map(|colalign| extract_strings("colalign", colalign)).transpose()?,
+            colglobalalign,
+            headersglobalalign,
+            headersalign,
+            maxcolwidths,
+            rowalign,
+            fixed_widths,
+            title,
+            footer,
+            aligns: Vec::new(),
+            text_cols: Vec::new(),
+            rows: Vec::new(),
+            rows_emitted: 0,
+            finished: false,
+        })
     }
     
-    // Header
-    let has_header = !header_row.is_empty();
-    if has_header {
-        let mut padded_headers = header_row.clone();
-        while padded_headers.len() < num_cols {
-            padded_headers.push(String::new());
+    /// Add a row; returns the row's rendered text in `fixed_widths` mode
+    fn add_row(&mut self, row: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        if self.finished {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "rows can't be added once a fixed_widths table is rendered",
+            ));
+        }
+        let row: Vec<String> = row
+            .iter()?
+            .enumerate()
+            .map(|(col, cell)| {
+                // Empty specs format numbers as `str()` does
+                let (float_fmt, int_fmt) = if self.disable_numparse.column(col) {
+                    ("", "")
+                } else {
+                    (column_spec(&self.floatfmt, col, DEFAULT_FLOATFMT), column_spec(&self.intfmt, col, DEFAULT_INTFMT))
+                };
+                cell.map(|cell| format_cell(&cell, &self.missingval, &self.infval, float_fmt, int_fmt))
+            })
+            .collect::<PyResult<_>>()?;
+        
+        let Some(widths) = self.fixed_widths.clone() else {
+            self.rows.push(row);
+            return Ok(None);
+        };
+        
+        let mut row = row;
+        if let Some(label) = self.index_label(self.rows_emitted)? {
+            row.insert(0, label);
         }
-        output.push(build_row(&padded_headers, &widths, &aligns, sep, format.padding, use_borders));
+        fill_row(&mut row, widths.len(), &self.missingval);
         
-        // Header separator
-        if let Some(ref line) = format.header_line {
-            output.push(build_line(&widths, line, format.padding));
+        let mut output = Vec::new();
+        if self.rows_emitted == 0 {
+            self.shift_fixed_columns(widths.len());
+            self.aligns = self.aligns_for(std::slice::from_ref(&row), widths.len());
+            output = self.style.head(&self.headers, &widths, &self.header_aligns(&self.aligns), self.title.as_deref());
+        } else {
+            output.extend(self.style.line(&self.style.format.line_between_rows, &widths));
+        }
+        if let Some(maxcolwidths) = &self.maxcolwidths {
+            maxcolwidths.wrap(std::slice::from_mut(&mut row));
         }
+        let valign = self.rowalign.as_ref().map_or('t', |rowalign| rowalign.row(self.rows_emitted));
+        output.push(self.style.row(&row, &widths, &self.aligns, valign));
+        self.rows_emitted += 1;
+        
+        Ok(Some(output.join("\n")))
     }
     
-    // Data rows
-    for (i, row) in rows.iter().enumerate() {
-        output.push(build_row(row, &widths, &aligns, sep, format.padding, use_borders));
+    /// Add several rows; returns their rendered text in `fixed_widths` mode
+    fn add_rows(&mut self, rows: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        let mut emitted = Vec::new();
+        for row in rows.iter()? {
+            emitted.extend(self.add_row(&row?)?);
+        }
+        Ok(self.fixed_widths.as_ref().map(|_| emitted.join("\n")))
+    }
+    
+    /// Render the buffered table, or finish a `fixed_widths` table by
+    /// returning its remaining lines (only on the first call)
+    fn render(&mut self) -> PyResult<String> {
+        if let Some(widths) = self.fixed_widths.clone() {
+            if std::mem::replace(&mut self.finished, true) {
+                return Ok(String::new());
+            }
+            let mut output = Vec::new();
+            if self.rows_emitted == 0 {
+                self.shift_fixed_columns(widths.len());
+                let aligns = self.aligns_for(&[], widths.len());
+                output = self.style.head(&self.headers, &widths, &self.header_aligns(&aligns), self.title.as_deref());
+            }
+            output.extend(self.style.tail(&widths, !self.headers.is_empty(), self.footer.as_deref()));
+            return Ok(output.join("\n"));
+        }
         
-        // Row separator (not after last row)
-        if i < rows.len() - 1 {
-            if let Some(ref line) = format.line_between_rows {
-                output.push(build_line(&widths, line, format.padding));
+        if self.rows.is_empty() {
+            return Ok(String::new());
+        }
+        
+        // Work on copies, so rendering again after more rows starts afresh
+        let mut rows = self.rows.clone();
+        let mut headers = self.headers.clone();
+        let mut colalign = self.colalign.clone();
+        let mut headersalign = self.headersalign.clone();
+        let mut maxcolwidths = self.maxcolwidths.clone();
+        let data_cols = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        let mut text_cols: Vec<bool> = (0..data_cols).map(|col| self.disable_numparse.column(col)).collect();
+        if let Some(labels) = self.show_index.clone().labels(rows.len(), data_cols)? {
+            let labels_index = matches!(self.show_index, ShowIndex::Always) && headers.len() == data_cols;
+            shift_past_index(data_cols, labels_index, &mut headers, &mut colalign, &mut headersalign, &mut maxcolwidths, &mut text_cols);
+            for (row, label) in rows.iter_mut().zip(labels) {
+                row.insert(0, label);
+            }
+        }
+        
+        let num_cols = rows.iter().map(|r| r.len()).max().unwrap_or(0).max(headers.len());
+        for row in &mut rows {
+            fill_row(row, num_cols, &self.missingval);
+        }
+        if let Some(maxcolwidths) = &maxcolwidths {
+            maxcolwidths.wrap(&mut rows);
+        }
+        
+        let aligns = resolve_aligns(
+            &rows,
+            num_cols,
+            &text_cols,
+            &self.missingval,
+            colalign.as_deref(),
+            self.colglobalalign.as_deref(),
+            &self.numalign,
+            &self.stralign,
+        );
+        align_decimals(&mut rows, &aligns);
+        let mut widths = column_widths(&headers, &rows, num_cols);
+        for caption in self.title.iter().chain(self.footer.iter()) {
+            self.style.fit_caption(&mut widths, caption);
+        }
+        
+        let header_aligns = header_aligns(&aligns, self.headersglobalalign.as_deref(), headersalign.as_ref());
+        let mut output = self.style.head(&headers, &widths, &header_aligns, self.title.as_deref());
+        for (i, row) in rows.iter().enumerate() {
+            let valign = self.rowalign.as_ref().map_or('t', |rowalign| rowalign.row(i));
+            output.push(self.style.row(row, &widths, &aligns, valign));
+            if i < rows.len() - 1 {
+                output.extend(self.style.line(&self.style.format.line_between_rows, &widths));
             }
         }
+        output.extend(self.style.tail(&widths, !headers.is_empty(), self.footer.as_deref()));
+        
+        Ok(output.join("\n"))
     }
     
-    // Bottom line
-    if let Some(ref line) = format.line_below {
-        if !format.with_header_hide || !has_header {
-            // For simple format, only show bottom line if no header
+    fn __len__(&self) -> usize {
+        self.rows.len().max(self.rows_emitted)
+    }
+}
+
+impl TableWriter {
+    /// Index label for the `row`th row, or `None` without an index
+    fn index_label(&self, row: usize) -> PyResult<Option<String>> {
+        match &self.show_index {
+            ShowIndex::Never => Ok(None),
+            ShowIndex::Always => Ok(Some(row.to_string())),
+            ShowIndex::Labels(labels) => labels.get(row).cloned().map(Some).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "index must be as long as the number of data rows ({} labels, but row {} was added)",
+                    labels.len(),
+                    row + 1
+                ))
+            }),
         }
-        output.push(build_line(&widths, line, format.padding));
     }
     
-    Ok(output.join("\n"))
+    /// In `fixed_widths` mode, shift the per-column options past the index
+    /// (which `num_cols` includes) before the first line is drawn
+    fn shift_fixed_columns(&mut self, num_cols: usize) {
+        let data_cols = num_cols.saturating_sub(usize::from(!matches!(self.show_index, ShowIndex::Never)));
+        self.text_cols = (0..data_cols).map(|col| self.disable_numparse.column(col)).collect();
+        if data_cols < num_cols {
+            let labels_index = matches!(self.show_index, ShowIndex::Always) && self.headers.len() == data_cols;
+            shift_past_index(
+                data_cols,
+                labels_index,
+                &mut self.headers,
+                &mut self.colalign,
+                &mut self.headersalign,
+                &mut self.maxcolwidths,
+                &mut self.text_cols,
+            );
+        }
+    }
+    
+    fn aligns_for(&self, rows: &[Vec<String>], num_cols: usize) -> Vec<char> {
+        resolve_aligns(
+            rows,
+            num_cols,
+            &self.text_cols,
+            &self.missingval,
+            self.colalign.as_deref(),
+            self.colglobalalign.as_deref(),
+            &self.numalign,
+            &self.stralign,
+        )
+    }
+    
+    fn header_aligns(&self, aligns: &[char]) -> Vec<char> {
+        header_aligns(aligns, self.headersglobalalign.as_deref(), self.headersalign.as_ref())
+    }
 }
 
 /// Get list of available table formats
@@ -446,7 +1845,14 @@ fn tabulate_formats() -> Vec<&'static str> {
         "rounded_grid",
         "heavy_grid",
         "double_grid",
+        "fancy_grid",
+        "fancy_outline",
         "tsv",
+        "latex",
+        "latex_booktabs",
+        "html",
+        "mediawiki",
+        "jira",
     ]
 }
 
@@ -455,5 +1861,6 @@ fn tabulate_formats() -> Vec<&'static str> {
 fn tabulate_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(tabulate, m)?)?;
     m.add_function(wrap_pyfunction!(tabulate_formats, m)?)?;
+    m.add_class::<TableWriter>()?;
     Ok(())
 }
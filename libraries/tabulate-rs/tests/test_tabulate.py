@@ -0,0 +1,904 @@
+"""
+Tests for tabulate-rs
+
+Verifies API compatibility with the tabulate package
+"""
+
+import pytest
+
+try:
+    from tabulate_rs import tabulate, tabulate_formats, TableWriter
+except ImportError:
+    pytest.skip("tabulate_rs not available", allow_module_level=True)
+
+
+def column(table):
+    """Cell text of a single-column plain table"""
+    return table.split("\n")
+
+
+class TestIterableInput:
+    """Rows and cells may come from any iterable"""
+
+    def test_tuple_of_tuples(self):
+        assert tabulate(((1, 2), (3, 4)), tablefmt="plain") == tabulate([[1, 2], [3, 4]], tablefmt="plain")
+
+    def test_generator(self):
+        table = tabulate(((i, i * i) for i in range(3)), tablefmt="plain")
+        assert [line.split() for line in table.split("\n")] == [["0", "0"], ["1", "1"], ["2", "4"]]
+
+    def test_iterable_cells(self):
+        table = tabulate([range(3), iter("ab")], tablefmt="plain")
+        assert [line.split() for line in table.split("\n")] == [["0", "1", "2"], ["a", "b"]]
+
+    def test_scalar_rows(self):
+        assert column(tabulate([1, "two", 3.5], tablefmt="plain")) == [" 1   ", " two ", " 3.5 "]
+        assert column(tabulate(range(2), tablefmt="plain")) == [" 0 ", " 1 "]
+
+    def test_dict_rows_from_generator(self):
+        table = tabulate(({"a": i} for i in range(2)), headers="keys", tablefmt="plain")
+        assert column(table) == [" a ", " 0 ", " 1 "]
+
+    def test_not_iterable(self):
+        with pytest.raises(TypeError):
+            tabulate(5)
+
+    def test_tuple_headers(self):
+        table = tabulate(((1, "a"), (2, "b")), headers=("x", "y"), tablefmt="plain")
+        assert column(table)[0] == " x    y "
+
+    def test_tuple_colalign(self):
+        table = tabulate([["a", "bbb"], ["ccc", "d"]], colalign=("right", "center"), tablefmt="plain")
+        assert column(table) == ["   a    bbb ", " ccc     d  "]
+
+    def test_generator_headers(self):
+        table = tabulate([[1, 2]], headers=(h for h in "xy"), tablefmt="plain")
+        assert column(table)[0] == " x    y "
+
+    def test_headers_and_colalign_must_be_sequences(self):
+        with pytest.raises(TypeError):
+            tabulate([[1]], headers=5)
+        with pytest.raises(TypeError):
+            tabulate([[1]], colalign="center")
+        with pytest.raises(TypeError):
+            TableWriter(colalign=3)
+
+
+class TestHeaderModes:
+    """The firstrow and keys header modes"""
+
+    def test_firstrow(self):
+        lines = tabulate([["name", "qty"], ["apple", 3], ["pear", 10]], headers="firstrow").split("\n")
+        assert lines[0] == " name     qty "
+        assert lines[2] == " apple      3 "
+        assert len(lines) == 4
+
+    def test_firstrow_empty(self):
+        assert tabulate([], headers="firstrow") == ""
+
+    def test_keys_from_dicts(self):
+        data = [{"name": "apple", "qty": 3}, {"name": "pear", "price": 1.5}]
+        lines = tabulate(data, headers="keys", tablefmt="plain", missingval="-").split("\n")
+        assert lines[0].split() == ["name", "qty", "price"]
+        assert lines[1].split() == ["apple", "3", "-"]
+        assert lines[2].split() == ["pear", "-", "1.5"]
+
+    def test_keys_from_lists(self):
+        lines = tabulate([[1, 2], [3, 4]], headers="keys", tablefmt="plain").split("\n")
+        assert lines[0].split() == ["0", "1"]
+
+    def test_unknown_string(self):
+        with pytest.raises(ValueError):
+            tabulate([[1]], headers="bogus")
+
+
+class TestDictRows:
+    """A list of dicts is laid out by key, not by value order"""
+
+    def test_rows_align_by_key(self):
+        data = [{"a": 1, "b": 2}, {"b": 3, "a": 4}]
+        lines = tabulate(data, tablefmt="plain").split("\n")
+        assert [line.split() for line in lines] == [["1", "2"], ["4", "3"]]
+
+    def test_missing_keys_use_missingval(self):
+        data = [{"a": 1}, {"b": 2}, {"a": 3, "c": 4}]
+        lines = tabulate(data, tablefmt="plain", missingval="?").split("\n")
+        assert [line.split() for line in lines] == [["1", "?", "?"], ["?", "2", "?"], ["3", "?", "4"]]
+
+    def test_floatfmt_applies(self):
+        assert tabulate([{"x": 0.5}], tablefmt="plain").split() == ["0.5"]
+
+
+class TestFloatFmt:
+    """floatfmt specs, globally and per column"""
+
+    @staticmethod
+    def cells(table):
+        return [line.split() for line in table.split("\n")]
+
+    def test_default_general(self):
+        table = tabulate([[3.14159265, 1e-5, 1e20]], tablefmt="plain")
+        assert self.cells(table) == [["3.14159", "1e-05", "1e+20"]]
+
+    def test_fixed_and_scientific(self):
+        assert self.cells(tabulate([[0.125, 1234.5]], tablefmt="plain", floatfmt=".2f")) == [["0.12", "1234.50"]]
+        assert self.cells(tabulate([[1234.5]], tablefmt="plain", floatfmt=".2e")) == [["1.23e+03"]]
+        assert self.cells(tabulate([[0.256]], tablefmt="plain", floatfmt=".1%")) == [["25.6%"]]
+
+    def test_matches_python_format(self):
+        for spec in ["g", ".3g", "G", ".0f", ".4e", "E", "12.3f", "+.2f", ",.1f"]:
+            for value in [0.0, -2.5e-7, 9.9999995, 123456789.0]:
+                assert tabulate([[value]], tablefmt="tsv", floatfmt=spec) == format(value, spec)
+
+    def test_ints_and_bools_unformatted(self):
+        assert self.cells(tabulate([[1, True, 2.0]], tablefmt="plain", floatfmt=".2f")) == [["1", "True", "2.00"]]
+
+    def test_per_column(self):
+        table = tabulate([[1.23456, 1.23456, 1.23456]], tablefmt="plain", floatfmt=[".1f", ".3f"])
+        assert self.cells(table) == [["1.2", "1.235", "1.23456"]]
+
+    def test_per_column_dict_rows(self):
+        table = tabulate([{"a": 1.5, "b": 2.25}, {"b": 0.5}], tablefmt="plain", floatfmt=[".2f", ".1f"])
+        assert self.cells(table) == [["1.50", "2.2"], ["0.5"]]
+
+    def test_width_keeps_numeric_alignment(self):
+        table = tabulate([["a", 1.5], ["bb", 22.25]], tablefmt="plain", floatfmt="8.2f")
+        assert column(table) == [" a         1.50 ", " bb       22.25 "]
+
+    def test_table_writer(self):
+        writer = TableWriter(tablefmt="plain", floatfmt=[".1f"])
+        writer.add_row([1.25, 1.25])
+        assert self.cells(writer.render()) == [["1.2", "1.25"]]
+
+
+class TestDisableNumparse:
+    """disable_numparse keeps numeric-looking columns as text"""
+
+    DATA = [["007", 1.23456789], ["12", 2.0]]
+
+    def test_all_columns(self):
+        table = tabulate(self.DATA, tablefmt="plain", disable_numparse=True)
+        assert column(table) == [" 007    1.23456789 ", " 12     2.0        "]
+
+    def test_selected_columns(self):
+        table = tabulate(self.DATA, tablefmt="plain", disable_numparse=[0])
+        assert column(table) == [" 007    1.23457 ", " 12           2 "]
+
+    def test_colalign_still_applies(self):
+        table = tabulate(self.DATA, tablefmt="plain", disable_numparse=True, colalign=["right"])
+        assert column(table) == [" 007    1.23456789 ", "  12    2.0        "]
+
+    def test_default_parses(self):
+        table = tabulate(self.DATA, tablefmt="plain", disable_numparse=False)
+        assert column(table) == [" 007    1.23457 ", "  12          2 "]
+
+
+class TestShowIndex:
+    """showindex modes and their interplay with headers and colalign"""
+
+    DATA = [["a", 1], ["bb", 22]]
+
+    def test_true(self):
+        table = tabulate(self.DATA, tablefmt="plain", showindex=True)
+        assert column(table) == [" 0    a      1 ", " 1    bb    22 "]
+
+    def test_always_and_never(self):
+        assert tabulate(self.DATA, showindex="always") == tabulate(self.DATA, showindex=True)
+        assert tabulate(self.DATA, showindex="never") == tabulate(self.DATA)
+        assert tabulate(self.DATA, showindex=False) == tabulate(self.DATA)
+
+    def test_custom_labels(self):
+        table = tabulate(self.DATA, tablefmt="plain", showindex=["first", "second"])
+        assert [line.split() for line in table.split("\n")] == [["first", "a", "1"], ["second", "bb", "22"]]
+
+    def test_custom_labels_length_mismatch(self):
+        with pytest.raises(ValueError):
+            tabulate(self.DATA, showindex=["only"])
+
+    def test_header_for_data_columns(self):
+        # One header short of the index-augmented row: the index header is blank
+        lines = tabulate(self.DATA, headers=["x", "y"], showindex=["r1", "r2"]).split("\n")
+        assert lines[0] == "       x      y "
+        assert lines[2] == " r1    a      1 "
+
+    def test_first_header_labels_numbered_index(self):
+        # With showindex="always", one header short labels the index first
+        for showindex in ["always", True]:
+            lines = tabulate(self.DATA, headers=["#", "x"], showindex=showindex, tablefmt="grid").split("\n")
+            assert lines[1] == "| # | x  |    |"
+            assert lines[3] == "| 0 | a  |  1 |"
+
+    def test_header_for_index(self):
+        lines = tabulate(self.DATA, headers=["#", "x", "y"], showindex=True, tablefmt="grid").split("\n")
+        assert lines[1] == "| # | x  |  y |"
+        assert lines[3] == "| 0 | a  |  1 |"
+
+    def test_colalign_skips_index(self):
+        table = tabulate(self.DATA, tablefmt="plain", showindex=["r1", "r2"], colalign=["right", "left"])
+        assert column(table) == [" r1     a    1  ", " r2    bb    22 "]
+
+    def test_unknown_string(self):
+        with pytest.raises(ValueError):
+            tabulate(self.DATA, showindex="sometimes")
+
+    def test_dict_rows_with_keys(self):
+        rows = [{"x": "a", "y": 1}, {"x": "bb", "y": 22}]
+        lines = tabulate(rows, headers="keys", showindex=True, tablefmt="grid").split("\n")
+        assert lines[1] == "|   | x  |  y |"
+        assert lines[3] == "| 0 | a  |  1 |"
+        assert lines[5] == "| 1 | bb | 22 |"
+
+    def test_colalign_for_index(self):
+        table = tabulate(self.DATA, tablefmt="plain", showindex=["r1", "row2"], colalign=["left", "right", "left"])
+        assert column(table) == [" r1       a    1  ", " row2    bb    22 "]
+
+    def test_maxcolwidths_skips_index(self):
+        table = tabulate([["aaa bbb", 1]], tablefmt="plain", showindex=True, maxcolwidths=[3, None])
+        assert column(table) == [" 0    aaa    1 ", "      bbb      "]
+
+    def test_length_mismatch_message(self):
+        with pytest.raises(ValueError, match="prepended as column 0"):
+            tabulate(self.DATA, showindex=["only"])
+
+
+class TestNonFinite:
+    """NaN shows as missingval and infinities as infval"""
+
+    def test_nan_is_missing(self):
+        table = tabulate([[1.5], [float("nan")]], tablefmt="plain", missingval="-")
+        assert column(table) == [" 1.5 ", "   - "]
+
+    def test_nan_default_blank(self):
+        assert column(tabulate([[float("nan")], [2.0]], tablefmt="plain")) == ["   ", " 2 "]
+
+    def test_infinity(self):
+        table = tabulate([[float("inf")], [float("-inf")], [1.0]], tablefmt="plain")
+        assert column(table) == ["  ∞ ", " -∞ ", "  1 "]
+
+    def test_custom_infval(self):
+        table = tabulate([[float("inf"), float("-inf")]], tablefmt="plain", infval="inf")
+        assert table.split() == ["inf", "-inf"]
+
+    def test_table_writer(self):
+        writer = TableWriter(tablefmt="plain", missingval="?")
+        writer.add_row([float("nan"), float("inf")])
+        assert writer.render().split() == ["?", "∞"]
+
+
+class TestIntFmt:
+    """intfmt specs, globally and per column"""
+
+    def test_default_ungrouped(self):
+        assert tabulate([[1000000]], tablefmt="plain") == " 1000000 "
+
+    def test_thousands_separator(self):
+        for value in [0, 123, 1234, -1234, 1000000, 10**20]:
+            assert tabulate([[value]], tablefmt="tsv", intfmt=",") == format(value, ",")
+
+    def test_grouped_column_right_aligned(self):
+        table = tabulate([[1000000], [5]], tablefmt="plain", intfmt=",")
+        assert column(table) == [" 1,000,000 ", "         5 "]
+
+    def test_per_column(self):
+        table = tabulate([[1234, 1234, 1234]], tablefmt="plain", intfmt=["_", "", "x"])
+        assert table.split() == ["1_234", "1234", "4d2"]
+
+    def test_floats_and_bools_untouched(self):
+        assert tabulate([[1234.5, True]], tablefmt="plain", intfmt=",").split() == ["1234.5", "True"]
+
+    def test_table_writer(self):
+        writer = TableWriter(tablefmt="plain", intfmt=",")
+        writer.add_row([12345])
+        assert writer.render() == " 12,345 "
+
+
+class TestRowAlign:
+    """rowalign places short cells within multi-line rows"""
+
+    DATA = [["a\nb\nc", "x"]]
+
+    def lines(self, rowalign):
+        return tabulate(self.DATA, tablefmt="grid", rowalign=rowalign).split("\n")[1:-1]
+
+    def test_top_default(self):
+        assert self.lines("top") == ["| a | x |", "| b |   |", "| c |   |"]
+        assert tabulate(self.DATA, tablefmt="grid") == tabulate(self.DATA, tablefmt="grid", rowalign="top")
+
+    def test_center(self):
+        assert self.lines("center") == ["| a |   |", "| b | x |", "| c |   |"]
+
+    def test_bottom(self):
+        assert self.lines("bottom") == ["| a |   |", "| b |   |", "| c | x |"]
+
+    def test_center_extra_line_below(self):
+        table = tabulate([["a\nb\nc\nd", "x"]], tablefmt="plain", rowalign="center")
+        assert [line.split() for line in table.split("\n")] == [["a"], ["b", "x"], ["c"], ["d"]]
+
+    def test_per_row(self):
+        data = [["a\nb", "x"], ["c\nd", "y"], ["e\nf", "z"]]
+        table = tabulate(data, tablefmt="plain", rowalign=["bottom", "top"])
+        assert [line.split() for line in table.split("\n")] == [
+            ["a"], ["b", "x"], ["c", "y"], ["d"], ["e", "z"], ["f"],
+        ]
+
+
+class TestMaxColWidths:
+    """maxcolwidths wraps long cells onto several lines"""
+
+    def test_grid(self):
+        data = [[1, "The quick brown fox jumps over the lazy dog"], [2, "Supercalifragilisticexpialidocious words"]]
+        table = tabulate(data, headers=["id", "text"], tablefmt="grid", maxcolwidths=[None, 12])
+        assert table.split("\n") == [
+            "+----+--------------+",
+            "| id | text         |",
+            "+====+==============+",
+            "|  1 | The quick    |",
+            "|    | brown fox    |",
+            "|    | jumps over   |",
+            "|    | the lazy dog |",
+            "+----+--------------+",
+            "|  2 | Supercalifra |",
+            "|    | gilisticexpi |",
+            "|    | alidocious   |",
+            "|    | words        |",
+            "+----+--------------+",
+        ]
+
+    def test_single_limit(self):
+        table = tabulate([["abc def", "ghijkl"]], tablefmt="plain", maxcolwidths=3)
+        assert table.split("\n") == [" abc    ghi ", " def    jkl "]
+
+    def test_long_word_starts_on_current_line(self):
+        table = tabulate([["ab cdefghij"]], tablefmt="plain", maxcolwidths=5)
+        assert column(table) == [" ab cd ", " efghi ", " j     "]
+
+    def test_short_cells_untouched(self):
+        assert tabulate([["short"]], tablefmt="plain", maxcolwidths=[10]) == " short "
+
+
+class TestLatex:
+    """The latex and latex_booktabs formats"""
+
+    def test_latex(self):
+        table = tabulate([["a", 1], ["bb", 22]], headers=["x", "y"], tablefmt="latex")
+        assert table.split("\n") == [
+            r"\begin{tabular}{lr}",
+            r"\hline",
+            r" x  &  y \\",
+            r"\hline",
+            r" a  &  1 \\",
+            r" bb & 22 \\",
+            r"\hline",
+            r"\end{tabular}",
+        ]
+
+    def test_booktabs(self):
+        table = tabulate([["a", 1]], headers=["x", "y"], tablefmt="latex_booktabs")
+        assert table.split("\n") == [
+            r"\begin{tabular}{lr}",
+            r"\toprule",
+            r" x & y \\",
+            r"\midrule",
+            r" a & 1 \\",
+            r"\bottomrule",
+            r"\end{tabular}",
+        ]
+
+    def test_escaping(self):
+        table = tabulate([["a_b & c", "50%", "#{1}", "$x$"]], tablefmt="latex")
+        assert table.split("\n")[2] == r" a\_b \& c & 50\% & \#\{1\} & \$x\$ \\"
+
+    def test_colspec_from_aligns(self):
+        table = tabulate([["a", 1, 2.5]], tablefmt="latex", colalign=["center", "left", "decimal"])
+        assert table.split("\n")[0] == r"\begin{tabular}{clr}"
+
+    def test_listed(self):
+        assert {"latex", "latex_booktabs"} <= set(tabulate_formats())
+
+
+class TestHtml:
+    """The html format"""
+
+    def test_with_headers(self):
+        table = tabulate([["a", 1], ["bb", 22]], headers=["x", "y"], tablefmt="html")
+        assert table.split("\n") == [
+            "<table>",
+            "<thead>",
+            '<tr><th>x </th><th style="text-align: right;"> y</th></tr>',
+            "</thead>",
+            "<tbody>",
+            '<tr><td>a </td><td style="text-align: right;"> 1</td></tr>',
+            '<tr><td>bb</td><td style="text-align: right;">22</td></tr>',
+            "</tbody>",
+            "</table>",
+        ]
+
+    def test_without_headers(self):
+        table = tabulate([["a"]], tablefmt="html")
+        assert table == "<table>\n<tbody>\n<tr><td>a</td></tr>\n</tbody>\n</table>"
+
+    def test_escaping(self):
+        table = tabulate([['<a href="x">&</a>']], tablefmt="html")
+        assert "<td>&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;</td>" in table
+
+    def test_center(self):
+        table = tabulate([["a"]], tablefmt="html", colalign=["center"])
+        assert '<td style="text-align: center;">a</td>' in table
+
+    def test_listed(self):
+        assert "html" in tabulate_formats()
+
+
+class TestSimpleBottomRule:
+    """simple drops its bottom rule under a header"""
+
+    def test_with_header(self):
+        table = tabulate([["a", 1]], headers=["x", "y"], tablefmt="simple")
+        assert table == " x    y \n---  ---\n a    1 "
+
+    def test_without_header(self):
+        table = tabulate([["a", 1]], tablefmt="simple")
+        assert table.split("\n") == [" a    1 ", "---  ---"]
+
+    def test_table_writer(self):
+        writer = TableWriter(headers=["x"], fixed_widths=[3])
+        writer.add_row(["a"])
+        assert writer.render() == ""
+
+
+class TestMultilineCells:
+    """Cells with embedded newlines span several lines"""
+
+    def test_grid(self):
+        table = tabulate([["one\ntwo", "x"], ["a", "b"]], headers=["col", "h"], tablefmt="grid")
+        assert table.split("\n") == [
+            "+-----+---+",
+            "| col | h |",
+            "+=====+===+",
+            "| one | x |",
+            "| two |   |",
+            "+-----+---+",
+            "| a   | b |",
+            "+-----+---+",
+        ]
+
+    def test_multiline_header(self):
+        lines = tabulate([["a", 1]], headers=["long\nname", "n"], tablefmt="plain").split("\n")
+        assert lines == [" long    n ", " name      ", " a       1 "]
+
+    def test_width_from_widest_line(self):
+        assert tabulate([["ab\nabcd"]], tablefmt="plain") == " ab   \n abcd "
+
+
+class TestAnsiWidths:
+    """ANSI color codes in cells don't count towards widths"""
+
+    RED, RESET = "\x1b[31m", "\x1b[0m"
+
+    def red(self, text):
+        return f"{self.RED}{text}{self.RESET}"
+
+    def test_colored_cells_align(self):
+        table = tabulate([[self.red("red"), self.red(5)], ["plain", 10]], headers=["name", "n"], tablefmt="grid")
+        assert table.split("\n")[3] == f"| {self.red('red')}   |  {self.red(5)} |"
+        assert table.split("\n")[5] == "| plain | 10 |"
+
+    def test_colored_numbers_right_aligned(self):
+        table = tabulate([[self.red(1)], [22]], tablefmt="plain")
+        assert column(table) == [f"  {self.red(1)} ", " 22 "]
+
+    def test_wrapping_ignores_codes(self):
+        table = tabulate([[self.red("aaaa bbbb")]], tablefmt="plain", maxcolwidths=4)
+        assert column(table) == [f" {self.RED}aaaa ", f" bbbb{self.RESET} "]
+
+
+class TestWikiFormats:
+    """The mediawiki and jira formats"""
+
+    def test_mediawiki(self):
+        table = tabulate([["a", 1], ["bb", 22]], headers=["x", "y"], tablefmt="mediawiki")
+        assert table.split("\n") == [
+            '{| class="wikitable" style="text-align: left;"',
+            "|+ <!-- caption -->",
+            "|-",
+            '! x  !! align="right"|  y',
+            "|-",
+            '| a  || align="right"|  1',
+            "|-",
+            '| bb || align="right"| 22',
+            "|}",
+        ]
+
+    def test_jira(self):
+        table = tabulate([["a", 1], ["bb", 22]], headers=["x", "y"], tablefmt="jira")
+        assert table.split("\n") == ["|| x  ||  y ||", "| a  |  1 |", "| bb | 22 |"]
+
+    def test_pipes_escaped(self):
+        assert "| a&#124;b" in tabulate([["a|b"]], tablefmt="mediawiki")
+        assert tabulate([["a|b"]], tablefmt="jira") == "| a\\|b |"
+
+    def test_listed(self):
+        assert {"mediawiki", "jira"} <= set(tabulate_formats())
+
+
+class TestFancyFormats:
+    """The fancy_grid and fancy_outline formats"""
+
+    DATA = [["a", 1], ["bb", 22]]
+
+    def test_fancy_grid(self):
+        table = tabulate(self.DATA, headers=["x", "y"], tablefmt="fancy_grid")
+        assert table.split("\n") == [
+            "╒════╤════╕",
+            "│ x  │  y │",
+            "╞════╪════╡",
+            "│ a  │  1 │",
+            "├────┼────┤",
+            "│ bb │ 22 │",
+            "╘════╧════╛",
+        ]
+
+    def test_fancy_outline(self):
+        table = tabulate(self.DATA, headers=["x", "y"], tablefmt="fancy_outline")
+        assert table.split("\n") == [
+            "╒════╤════╕",
+            "│ x  │  y │",
+            "╞════╪════╡",
+            "│ a  │  1 │",
+            "│ bb │ 22 │",
+            "╘════╧════╛",
+        ]
+
+    def test_listed(self):
+        assert {"fancy_grid", "fancy_outline"} <= set(tabulate_formats())
+
+
+class TestHeadersAlign:
+    """headersalign aligns headers apart from their columns"""
+
+    DATA = [["apple", 1], ["kiwi", 22222]]
+
+    def header(self, **kwargs):
+        return tabulate(self.DATA, headers=["a", "n"], tablefmt="grid", **kwargs).split("\n")[1]
+
+    def test_single_string(self):
+        assert self.header(headersalign="center") == "|   a   |   n   |"
+
+    def test_shorter_list_falls_back_to_column(self):
+        assert self.header(headersalign=["right"]) == "|     a |     n |"
+
+    def test_same_and_precedence(self):
+        assert self.header(headersalign=["same", "left"], headersglobalalign="center") == "|   a   | n     |"
+
+    def test_data_unchanged(self):
+        with_headersalign = tabulate(self.DATA, headers=["a", "n"], headersalign="center").split("\n")[2:]
+        assert with_headersalign == tabulate(self.DATA, headers=["a", "n"]).split("\n")[2:]
+
+    def test_index_offset(self):
+        table = tabulate(self.DATA, headers=["a", "n"], tablefmt="grid", headersalign=["center"], showindex=range(len(self.DATA)))
+        assert table.split("\n")[1] == "|   |   a   |     n |"
+
+    def test_table_writer(self):
+        writer = TableWriter(headers=["a", "n"], tablefmt="grid", headersalign="center")
+        writer.add_rows(self.DATA)
+        assert writer.render().split("\n")[1] == "|   a   |   n   |"
+
+
+class TestColsep:
+    """colsep overrides the separator of borderless formats"""
+
+    DATA = [["a", 1], ["bb", 22]]
+
+    def test_plain(self):
+        table = tabulate(self.DATA, tablefmt="plain", colsep=" | ")
+        assert column(table) == [" a   |   1 ", " bb  |  22 "]
+
+    def test_rules_stay_aligned(self):
+        lines = tabulate(self.DATA, headers=["x", "y"], colsep=" | ").split("\n")
+        assert lines == [" x   |   y ", "----   ----", " a   |   1 ", " bb  |  22 "]
+
+    def test_junction_glyph_extended(self):
+        lines = tabulate(self.DATA, headers=["x", "y"], tablefmt="psql", colsep=" | ").split("\n")
+        assert lines[1] == "-----+-----"
+
+    def test_narrower_separator(self):
+        table = tabulate(self.DATA, tablefmt="tsv", colsep=",")
+        assert column(table) == ["a , 1", "bb,22"]
+
+    def test_ignored_for_bordered_formats(self):
+        for fmt in ["grid", "github", "fancy_grid"]:
+            assert tabulate(self.DATA, tablefmt=fmt, colsep=" ; ") == tabulate(self.DATA, tablefmt=fmt)
+
+    def test_table_writer(self):
+        writer = TableWriter(tablefmt="plain", colsep=" | ")
+        for row in self.DATA:
+            writer.add_row(row)
+        assert writer.render() == tabulate(self.DATA, tablefmt="plain", colsep=" | ")
+
+
+class TestCustomFormat:
+    """A dict passed as tablefmt"""
+
+    DATA = [["a", 1], ["bb", 22]]
+    BOXED = {
+        "line_above": ("+", "-", "+", "+"),
+        "line_below": ("+", "-", "+", "+"),
+        "line_between_rows": None,
+        "header_line": ("+", "=", "+", "+"),
+        "datarow": ("|", "|", "|"),
+    }
+
+    def test_boxed(self):
+        table = tabulate(self.DATA, headers=["x", "y"], tablefmt=self.BOXED)
+        assert table.split("\n") == [
+            "+----+----+",
+            "| x  |  y |",
+            "+====+====+",
+            "| a  |  1 |",
+            "| bb | 22 |",
+            "+----+----+",
+        ]
+
+    def test_asymmetric_edges_and_padding(self):
+        fmt = {"header_line": ("", "~", " ", ""), "padding": 0, "datarow": ("<", " ", ">")}
+        table = tabulate(self.DATA, headers=["x", "y"], tablefmt=fmt)
+        assert table.split("\n") == ["<x   y>", "~~ ~~", "<a   1>", "<bb 22>"]
+
+    def test_with_header_hide(self):
+        fmt = {"header_line": ("", "-", "  ", ""), "line_below": ("", "-", "  ", ""), "with_header_hide": True}
+        assert tabulate(self.DATA, headers=["x", "y"], tablefmt=fmt) == tabulate(self.DATA, headers=["x", "y"])
+
+    def test_empty_dict_defaults(self):
+        assert tabulate(self.DATA, tablefmt={}) == tabulate(self.DATA, tablefmt="plain")
+
+    def test_table_writer(self):
+        writer = TableWriter(headers=["x", "y"], tablefmt=self.BOXED)
+        writer.add_rows(self.DATA)
+        assert writer.render() == tabulate(self.DATA, headers=["x", "y"], tablefmt=self.BOXED)
+
+    @pytest.mark.parametrize("fmt", [
+        {"line_above": ("+", "-")},
+        {"header_line": ("", "", "", "")},
+        {"bogus": 1},
+        {"padding": -1},
+        {"with_header_hide": "yes"},
+        {"datarow": "|"},
+        5,
+    ])
+    def test_invalid(self, fmt):
+        with pytest.raises(ValueError):
+            tabulate(self.DATA, tablefmt=fmt)
+
+
+class TestDecimalAlign:
+    """numalign="decimal" lines up decimal points"""
+
+    def test_mixed_column(self):
+        table = tabulate([[3], [3.14], ["1.2e5"], [-0.5]], tablefmt="plain", numalign="decimal")
+        assert column(table) == [
+            "  3     ",
+            "  3.14  ",
+            "  1.2e5 ",
+            " -0.5   ",
+        ]
+
+    def test_exponent_without_point(self):
+        table = tabulate([["1e5"], [2.25]], tablefmt="plain", numalign="decimal")
+        assert column(table) == [" 1e5  ", " 2.25 "]
+
+    def test_missing_values_right_aligned(self):
+        table = tabulate([[1.5], [None], [12]], tablefmt="plain", numalign="decimal")
+        assert column(table) == ["  1.5 ", "      ", " 12   "]
+
+    def test_colalign(self):
+        table = tabulate([["a", 10], ["b", 2.5]], tablefmt="plain", colalign=["left", "decimal"])
+        assert column(table) == [" a    10   ", " b     2.5 "]
+
+    def test_infinity(self):
+        table = tabulate([[1.5], [float("-inf")], [22.25]], tablefmt="plain", numalign="decimal")
+        assert column(table) == ["  1.5  ", " -∞    ", " 22.25 "]
+
+    def test_grouped_integers(self):
+        table = tabulate([[1234567], [2.5], [-0.125]], tablefmt="plain", numalign="decimal", intfmt=",")
+        assert column(table) == [" 1,234,567     ", "         2.5   ", "        -0.125 "]
+
+    def test_padded_floatfmt(self):
+        table = tabulate([[1.5], [22.25]], tablefmt="plain", numalign="decimal", floatfmt="8.2f")
+        assert column(table) == ["  1.50 ", " 22.25 "]
+
+    def test_custom_missingval(self):
+        table = tabulate([[1.5], [None], [10]], tablefmt="plain", numalign="decimal", missingval="-")
+        assert column(table) == ["  1.5 ", "    - ", " 10   "]
+
+    def test_table_writer(self):
+        writer = TableWriter(tablefmt="plain", numalign="decimal")
+        writer.add_rows([[12], [0.125]])
+        assert column(writer.render()) == [" 12     ", "  0.125 "]
+
+
+class TestGlobalAlign:
+    """colglobalalign / headersglobalalign precedence"""
+
+    DATA = [["ab", 1], ["c", 22]]
+
+    def test_auto_detected(self):
+        assert column(tabulate(self.DATA, tablefmt="plain")) == [" ab     1 ", " c     22 "]
+
+    def test_global_overrides_auto(self):
+        table = tabulate(self.DATA, tablefmt="plain", colglobalalign="left")
+        assert column(table) == [" ab    1  ", " c     22 "]
+
+    def test_colalign_overrides_global(self):
+        table = tabulate(self.DATA, tablefmt="plain", colglobalalign="left", colalign=["right"])
+        assert column(table) == [" ab    1  ", "  c    22 "]
+
+    def test_global_entry_in_colalign(self):
+        table = tabulate(
+            self.DATA, tablefmt="plain", colglobalalign="right", colalign=["global", "left"]
+        )
+        assert column(table) == [" ab    1  ", "  c    22 "]
+
+    def test_colalign_leaves_other_columns_auto(self):
+        table = tabulate(self.DATA, tablefmt="plain", colalign=["right"])
+        assert column(table) == [" ab     1 ", "  c    22 "]
+
+    def test_headers_follow_columns(self):
+        lines = column(tabulate(self.DATA, headers=["h", "n"], tablefmt="plain"))
+        assert lines[0] == " h      n "
+
+    def test_headersglobalalign(self):
+        lines = column(
+            tabulate(self.DATA, headers=["h", "n"], tablefmt="plain", headersglobalalign="center")
+        )
+        assert lines[0] == " h     n  "
+        assert lines[1] == " ab     1 "
+
+    def test_table_writer(self):
+        writer = TableWriter(tablefmt="plain", colglobalalign="center")
+        writer.add_rows(self.DATA)
+        assert column(writer.render()) == [" ab    1  ", " c     22 "]
+
+
+class TestPretty:
+    """pretty centers columns by default, like PrettyTable"""
+
+    DATA = [["spam", 41.9999], ["eggs", "451.0"]]
+
+    def test_golden(self):
+        expected = "\n".join([
+            "+---------+---------+",
+            "| strings | numbers |",
+            "+---------+---------+",
+            "|  spam   | 41.9999 |",
+            "|  eggs   |  451.0  |",
+            "+---------+---------+",
+        ])
+        assert tabulate(self.DATA, headers=["strings", "numbers"], tablefmt="pretty") == expected
+
+    def test_separators_line_up_with_rules(self):
+        table = tabulate([["a", 1], ["bbbbbb", 22]], headers=["name", "n"], tablefmt="pretty")
+        lines = table.splitlines()
+        rule = [i for i, ch in enumerate(lines[0]) if ch == "+"]
+        for line in lines:
+            assert len(line) == len(lines[0])
+            if line.startswith("|"):
+                assert [i for i, ch in enumerate(line) if ch == "|"] == rule
+
+    def test_explicit_alignment_wins(self):
+        table = tabulate(self.DATA, headers=["strings", "numbers"], tablefmt="pretty", stralign="left")
+        assert table.splitlines()[3] == "| spam    | 41.9999 |"
+
+    def test_table_writer(self):
+        writer = TableWriter(headers=["strings", "numbers"], tablefmt="pretty")
+        writer.add_rows(self.DATA)
+        assert writer.render() == tabulate(self.DATA, headers=["strings", "numbers"], tablefmt="pretty")
+
+
+class TestCaptions:
+    """title and footer span the rendered table"""
+
+    DATA = [["a", 1], ["bb", 22]]
+
+    def test_boxed_in_grid(self):
+        lines = tabulate(
+            self.DATA, headers=["x", "y"], tablefmt="grid", title="Report", footer="2 rows"
+        ).split("\n")
+        assert lines[:3] == ["+---------+", "| Report  |", "+----+----+"]
+        assert lines[-3:] == ["+----+----+", "| 2 rows  |", "+---------+"]
+        assert len({len(line) for line in lines}) == 1
+
+    def test_rounded_grid_junctions(self):
+        lines = tabulate(
+            self.DATA, tablefmt="rounded_grid", title="Report", footer="2 rows"
+        ).split("\n")
+        assert lines[0] == "╭─────────╮"
+        assert lines[2] == "├────┬────┤"
+        assert lines[-3] == "├────┴────┤"
+        assert lines[-1] == "╰─────────╯"
+
+    def test_plain_line_in_simple(self):
+        lines = tabulate(self.DATA, headers=["x", "y"], title="Report", footer="2 rows").split("\n")
+        assert lines[0] == "  Report"
+        assert lines[1] == " x      y "
+        assert lines[-1] == "  2 rows"
+
+    def test_long_title_widens_table(self):
+        title = "A much longer report title"
+        lines = tabulate(self.DATA, headers=["x", "y"], tablefmt="grid", title=title).split("\n")
+        assert lines[1] == "| " + title + " |"
+        assert len({len(line) for line in lines}) == 1
+
+    def test_table_writer(self):
+        writer = TableWriter(tablefmt="grid", title="T", footer="F")
+        writer.add_rows(self.DATA)
+        lines = writer.render().split("\n")
+        assert lines[1] == "|    T    |"
+        assert lines[-2] == "|    F    |"
+
+
+class TestTableWriter:
+    """TableWriter in buffered and fixed_widths modes"""
+
+    DATA = [["a", 1.5], ["bb", 22]]
+
+    def test_buffered_matches_tabulate(self):
+        writer = TableWriter(headers=["n", "v"], tablefmt="grid")
+        for row in self.DATA:
+            writer.add_row(row)
+        assert len(writer) == 2
+        assert writer.render() == tabulate(self.DATA, headers=["n", "v"], tablefmt="grid")
+
+    def test_buffered_render_repeatable(self):
+        writer = TableWriter(tablefmt="grid")
+        writer.add_rows(self.DATA)
+        first = writer.render()
+        assert writer.render() == first
+        writer.add_row(["c", 3])
+        assert writer.render() == tabulate(self.DATA + [["c", 3]], tablefmt="grid")
+
+    def test_buffered_empty(self):
+        assert TableWriter().render() == ""
+
+    def test_buffered_tabulate_options(self):
+        data = [["a", "1.50", "x y"], ["bb", "22", "z"]]
+        options = dict(
+            headers=["n", "v", "t"], tablefmt="grid", showindex=True, disable_numparse=[1],
+            maxcolwidths=[None, None, 1], rowalign="bottom",
+        )
+        writer = TableWriter(**options)
+        writer.add_rows(data)
+        assert writer.render() == tabulate(data, **options)
+
+    def test_fixed_rows_returned_immediately(self):
+        writer = TableWriter(headers=["n", "v"], tablefmt="grid", fixed_widths=[2, 4])
+        assert writer.add_row(["a", 1]).split("\n") == [
+            "+----+------+",
+            "| n  |    v |",
+            "+====+======+",
+            "| a  |    1 |",
+        ]
+        assert writer.add_row(["bb", 22]).split("\n") == ["+----+------+", "| bb |   22 |"]
+
+    def test_fixed_tail_emitted_once(self):
+        writer = TableWriter(tablefmt="grid", fixed_widths=[2, 4])
+        writer.add_row(["a", 1])
+        assert writer.render() == "+----+------+"
+        assert writer.render() == ""
+        with pytest.raises(ValueError):
+            writer.add_row(["b", 2])
+
+    def test_fixed_without_rows(self):
+        writer = TableWriter(headers=["n"], tablefmt="grid", fixed_widths=[3])
+        assert writer.render().split("\n") == ["+-----+", "|   n |", "+=====+", "+-----+"]
+        assert writer.render() == ""
+
+    def test_fixed_showindex(self):
+        writer = TableWriter(headers=["n", "v"], tablefmt="grid", showindex=["r1", "r2"], fixed_widths=[2, 2, 3])
+        assert writer.add_row(["a", 1]).split("\n")[1:] == ["|    | n  |   v |", "+====+====+=====+", "| r1 | a  |   1 |"]
+        assert writer.add_row(["b", 2]).split("\n")[1] == "| r2 | b  |   2 |"
+        with pytest.raises(ValueError):
+            writer.add_row(["c", 3])
+
+    def test_fixed_rowalign_and_maxcolwidths(self):
+        writer = TableWriter(tablefmt="plain", fixed_widths=[3, 1], maxcolwidths=[3, None], rowalign="bottom")
+        assert writer.add_row(["abc def", 1]).split("\n") == [" abc      ", " def    1 "]
@@ -1,109 +1,625 @@
+#![allow(non_local_definitions)]
+
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString};
-use pyo3::exceptions::PyValueError;
-use std::io::Read;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyDict, PyList};
+use pyo3::exceptions::{PyAttributeError, PyKeyError, PyTypeError, PyValueError};
+use std::ops::Range;
+
+// Subclasses ValueError, as tomli's does, so existing handlers keep working
+pyo3::create_exception!(tomli_rs, TOMLDecodeError, PyValueError);
+
+// Raised by `loads(..., schema=...)` for valid TOML that doesn't match
+pyo3::create_exception!(tomli_rs, TOMLSchemaError, PyValueError);
+
+/// Build a `TOMLDecodeError` locating `span` within `doc`
+///
+/// `pos`/`end_pos` are byte offsets and `lineno`/`colno` are 1-based; all
+/// four are None when the parser reported no position. A zero-width span
+/// (a single position) has `end_pos == pos`.
+fn decode_error(
+    py: Python<'_>,
+    doc: &str,
+    message: &str,
+    display: String,
+    span: Option<Range<usize>>,
+) -> PyErr {
+    let err = TOMLDecodeError::new_err(format!("TOML parse error: {}", display));
+    let value = err.value(py);
+    let (pos, end_pos, lineno, colno) = match span {
+        Some(span) => {
+            let start = span.start.min(doc.len());
+            let line_start = doc[..start].rfind('\n').map_or(0, |i| i + 1);
+            let lineno = doc[..start].matches('\n').count() + 1;
+            let colno = doc[line_start..start].chars().count() + 1;
+            (Some(start), Some(span.end.max(start)), Some(lineno), Some(colno))
+        }
+        None => (None, None, None, None),
+    };
+    let attrs = [
+        ("msg", message.into_py(py)),
+        ("pos", pos.into_py(py)),
+        ("end_pos", end_pos.into_py(py)),
+        ("lineno", lineno.into_py(py)),
+        ("colno", colno.into_py(py)),
+    ];
+    for (name, attr) in attrs {
+        if let Err(e) = value.setattr(name, attr) {
+            return e;
+        }
+    }
+    err
+}
+
+/// Parsed table whose keys are also readable as attributes
+///
+/// `config.server.port` and `config["server"]["port"]` are equivalent;
+/// keys that aren't valid identifiers (or that clash with a method name
+/// such as `to_dict`) are only reachable through item access.
+#[pyclass(mapping)]
+struct Namespace {
+    data: Py<PyDict>,
+}
+
+#[pymethods]
+impl Namespace {
+    fn __getattr__(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        match self.data.as_ref(py).get_item(name)? {
+            Some(value) => Ok(value.into()),
+            None => Err(PyAttributeError::new_err(format!(
+                "'Namespace' object has no attribute '{}'",
+                name
+            ))),
+        }
+    }
+
+    fn __getitem__(&self, py: Python<'_>, key: &str) -> PyResult<PyObject> {
+        match self.data.as_ref(py).get_item(key)? {
+            Some(value) => Ok(value.into()),
+            None => Err(PyKeyError::new_err(key.to_string())),
+        }
+    }
+
+    fn __contains__(&self, py: Python<'_>, key: &str) -> PyResult<bool> {
+        self.data.as_ref(py).contains(key)
+    }
+
+    fn __len__(&self, py: Python<'_>) -> usize {
+        self.data.as_ref(py).len()
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(PyAny::iter(self.data.as_ref(py))?.into())
+    }
+
+    fn __dir__(&self, py: Python<'_>) -> PyObject {
+        self.data.as_ref(py).keys().into()
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!("Namespace({})", self.data.as_ref(py).repr()?))
+    }
+
+    /// Convert back to plain nested dicts
+    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        namespace_to_py(py, self.data.as_ref(py))
+    }
+}
+
+/// Recursively replace Namespace objects with dicts
+fn namespace_to_py(py: Python<'_>, value: &PyAny) -> PyResult<PyObject> {
+    if let Ok(ns) = value.extract::<PyRef<Namespace>>() {
+        return namespace_to_py(py, ns.data.as_ref(py));
+    }
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        let result = PyDict::new(py);
+        for (key, item) in dict {
+            result.set_item(key, namespace_to_py(py, item)?)?;
+        }
+        return Ok(result.into());
+    }
+    if let Ok(list) = value.downcast::<PyList>() {
+        let result = PyList::empty(py);
+        for item in list {
+            result.append(namespace_to_py(py, item)?)?;
+        }
+        return Ok(result.into());
+    }
+    Ok(value.into())
+}
+
+/// How parsed tables are returned to Python
+#[derive(Clone, Copy)]
+enum Access {
+    /// Plain dicts (the tomli behaviour)
+    Dict,
+    /// Namespace objects supporting attribute access
+    Attr,
+}
+
+impl Access {
+    fn from_name(name: &str) -> PyResult<Self> {
+        match name {
+            "dict" => Ok(Access::Dict),
+            "attr" => Ok(Access::Attr),
+            _ => Err(PyValueError::new_err(format!(
+                "access must be 'dict' or 'attr', not '{}'",
+                name
+            ))),
+        }
+    }
+}
+
+/// A scalar parsed with `preserve_source=True`: the Python value plus the
+/// exact token it was written as, e.g. `255` from `0xFF` or `1000` from
+/// `1_000`
+///
+/// Comparisons, hashing, arithmetic, conversions and attribute lookups all
+/// go to `value`, so it can stand in for the plain scalar; only `str()`
+/// gives the source text.
+#[pyclass(frozen)]
+struct SourceValue {
+    #[pyo3(get)]
+    value: PyObject,
+    #[pyo3(get)]
+    source: String,
+}
+
+#[pymethods]
+impl SourceValue {
+    fn __str__(&self) -> String {
+        self.source.clone()
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let source = self.source.to_object(py);
+        Ok(format!("SourceValue({}, {})", self.value.as_ref(py).repr()?, source.as_ref(py).repr()?))
+    }
+
+    fn __getattr__(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).getattr(name)?.into())
+    }
+
+    fn __format__(&self, py: Python<'_>, spec: &str) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).call_method1("__format__", (spec,))?.into())
+    }
+
+    fn __richcmp__(&self, py: Python<'_>, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).rich_compare(plain_value(other), op)?.into())
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        self.value.as_ref(py).hash()
+    }
+
+    fn __bool__(&self, py: Python<'_>) -> PyResult<bool> {
+        self.value.as_ref(py).is_true()
+    }
+
+    fn __int__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).call_method0("__int__")?.into())
+    }
+
+    fn __float__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).call_method0("__float__")?.into())
+    }
+
+    fn __index__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).call_method0("__index__")?.into())
+    }
+
+    fn __neg__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).call_method0("__neg__")?.into())
+    }
+
+    fn __pos__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).call_method0("__pos__")?.into())
+    }
+
+    fn __abs__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).call_method0("__abs__")?.into())
+    }
+
+    fn __add__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "add", self.value.as_ref(py), plain_value(other))
+    }
+
+    fn __radd__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "add", other, self.value.as_ref(py))
+    }
+
+    fn __sub__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "sub", self.value.as_ref(py), plain_value(other))
+    }
+
+    fn __rsub__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "sub", other, self.value.as_ref(py))
+    }
+
+    fn __mul__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "mul", self.value.as_ref(py), plain_value(other))
+    }
+
+    fn __rmul__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "mul", other, self.value.as_ref(py))
+    }
+
+    fn __truediv__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "truediv", self.value.as_ref(py), plain_value(other))
+    }
+
+    fn __rtruediv__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "truediv", other, self.value.as_ref(py))
+    }
+
+    fn __floordiv__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "floordiv", self.value.as_ref(py), plain_value(other))
+    }
+
+    fn __rfloordiv__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "floordiv", other, self.value.as_ref(py))
+    }
+
+    fn __mod__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "mod", self.value.as_ref(py), plain_value(other))
+    }
+
+    fn __rmod__(&self, py: Python<'_>, other: &PyAny) -> PyResult<PyObject> {
+        binary_op(py, "mod", other, self.value.as_ref(py))
+    }
+
+    fn __pow__(&self, py: Python<'_>, other: &PyAny, modulo: Option<&PyAny>) -> PyResult<PyObject> {
+        let pow = py.import("builtins")?.getattr("pow")?;
+        Ok(pow.call1((self.value.as_ref(py), plain_value(other), modulo.map(plain_value)))?.into())
+    }
+
+    fn __rpow__(&self, py: Python<'_>, other: &PyAny, modulo: Option<&PyAny>) -> PyResult<PyObject> {
+        let pow = py.import("builtins")?.getattr("pow")?;
+        Ok(pow.call1((other, self.value.as_ref(py), modulo.map(plain_value)))?.into())
+    }
+}
+
+/// Apply the `operator` module function `name`, which falls back to the
+/// reflected method exactly as the Python operator would
+fn binary_op(py: Python<'_>, name: &str, lhs: &PyAny, rhs: &PyAny) -> PyResult<PyObject> {
+    Ok(py.import("operator")?.getattr(name)?.call1((lhs, rhs))?.into())
+}
+
+/// Unwrap a `SourceValue` operand to its value; anything else is returned as is
+fn plain_value(obj: &PyAny) -> &PyAny {
+    match obj.downcast::<PyCell<SourceValue>>() {
+        Ok(cell) => cell.get().value.as_ref(obj.py()),
+        Err(_) => obj,
+    }
+}
+
+/// Convert a `toml_edit` value, wrapping numbers and datetimes in
+/// `SourceValue` so their original spelling survives
+fn edit_value_to_py(py: Python<'_>, value: &toml_edit::Value, access: Access) -> PyResult<PyObject> {
+    let scalar = match value {
+        toml_edit::Value::String(s) => return Ok(s.value().into_py(py)),
+        toml_edit::Value::Boolean(b) => return Ok(b.value().into_py(py)),
+        toml_edit::Value::Integer(i) => (toml::Value::Integer(*i.value()), i.as_repr()),
+        toml_edit::Value::Float(f) => (toml::Value::Float(*f.value()), f.as_repr()),
+        toml_edit::Value::Datetime(dt) => (toml::Value::Datetime(*dt.value()), dt.as_repr()),
+        toml_edit::Value::Array(arr) => {
+            let list = PyList::empty(py);
+            for item in arr.iter() {
+                list.append(edit_value_to_py(py, item, access)?)?;
+            }
+            return Ok(list.into());
+        }
+        toml_edit::Value::InlineTable(table) => {
+            let dict = PyDict::new(py);
+            for (key, value) in table.iter() {
+                dict.set_item(key, edit_value_to_py(py, value, access)?)?;
+            }
+            return wrap_table(py, dict, access);
+        }
+    };
+
+    let (plain, repr) = scalar;
+    let value = toml_value_to_py(py, &plain, access)?;
+    let source = repr
+        .and_then(|repr| repr.as_raw().as_str())
+        .map(str::to_string)
+        .unwrap_or_else(|| plain.to_string());
+    Ok(Py::new(py, SourceValue { value, source })?.into_py(py))
+}
+
+/// Convert a `toml_edit` item (tables, arrays of tables or values)
+fn edit_item_to_py(py: Python<'_>, item: &toml_edit::Item, access: Access) -> PyResult<PyObject> {
+    match item {
+        toml_edit::Item::None => Ok(py.None()),
+        toml_edit::Item::Value(value) => edit_value_to_py(py, value, access),
+        toml_edit::Item::Table(table) => {
+            let dict = PyDict::new(py);
+            for (key, item) in table.iter() {
+                dict.set_item(key, edit_item_to_py(py, item, access)?)?;
+            }
+            wrap_table(py, dict, access)
+        }
+        toml_edit::Item::ArrayOfTables(tables) => {
+            let list = PyList::empty(py);
+            for table in tables.iter() {
+                let dict = PyDict::new(py);
+                for (key, item) in table.iter() {
+                    dict.set_item(key, edit_item_to_py(py, item, access)?)?;
+                }
+                list.append(wrap_table(py, dict, access)?)?;
+            }
+            Ok(list.into())
+        }
+    }
+}
+
+/// Return a converted table as a dict or Namespace depending on `access`
+fn wrap_table(py: Python<'_>, dict: &PyDict, access: Access) -> PyResult<PyObject> {
+    match access {
+        Access::Dict => Ok(dict.into()),
+        Access::Attr => Ok(Py::new(py, Namespace { data: dict.into() })?.into_py(py)),
+    }
+}
+
+/// Convert a TOML date, time or datetime to its `datetime` counterpart
+///
+/// Offset datetimes are aware, local datetimes naive. Fractional seconds
+/// are truncated to microseconds, the finest `datetime` supports.
+fn datetime_to_py(py: Python<'_>, dt: &toml::value::Datetime) -> PyResult<PyObject> {
+    use toml::value::Offset;
+
+    let datetime = py.import("datetime")?;
+    let tzinfo = match dt.offset {
+        None => py.None(),
+        Some(Offset::Z) => datetime.getattr("timezone")?.getattr("utc")?.into(),
+        Some(Offset::Custom { minutes }) => {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("minutes", minutes)?;
+            let delta = datetime.getattr("timedelta")?.call((), Some(kwargs))?;
+            datetime.getattr("timezone")?.call1((delta,))?.into()
+        }
+    };
+
+    match (dt.date, dt.time) {
+        (Some(date), Some(time)) => {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("tzinfo", tzinfo)?;
+            let args = (
+                date.year,
+                date.month,
+                date.day,
+                time.hour,
+                time.minute,
+                time.second,
+                time.nanosecond / 1000,
+            );
+            Ok(datetime.getattr("datetime")?.call(args, Some(kwargs))?.into())
+        }
+        (Some(date), None) => Ok(datetime
+            .getattr("date")?
+            .call1((date.year, date.month, date.day))?
+            .into()),
+        (None, Some(time)) => Ok(datetime
+            .getattr("time")?
+            .call1((time.hour, time.minute, time.second, time.nanosecond / 1000))?
+            .into()),
+        (None, None) => Ok(dt.to_string().into_py(py)),
+    }
+}
 
 /// Convert TOML value to Python object
-fn toml_value_to_py(py: Python<'_>, value: &toml::Value) -> PyResult<PyObject> {
+fn toml_value_to_py(py: Python<'_>, value: &toml::Value, access: Access) -> PyResult<PyObject> {
     match value {
         toml::Value::String(s) => Ok(s.clone().into_py(py)),
         toml::Value::Integer(i) => Ok(i.into_py(py)),
         toml::Value::Float(f) => Ok(f.into_py(py)),
         toml::Value::Boolean(b) => Ok(b.into_py(py)),
-        toml::Value::Datetime(dt) => {
-            // Convert TOML datetime to Python datetime
-            let dt_str = dt.to_string();
-            
-            // Import datetime module
-            let datetime = py.import("datetime")?;
-            
-            // Parse different datetime formats
-            if dt_str.contains('T') || dt_str.contains(' ') {
-                // Full datetime with optional time
-                if dt_str.contains('+') || dt_str.ends_with('Z') {
-                    // With timezone
-                    datetime.call_method1("fromisoformat", (dt_str.replace('Z', "+00:00"),))?.extract()
-                } else {
-                    // Without timezone (local)
-                    let date_str = if dt_str.contains(' ') {
-                        dt_str.replace(' ', "T")
-                    } else {
-                        dt_str
-                    };
-                    datetime.call_method1("fromisoformat", (date_str,))?.extract()
-                }
-            } else if dt_str.contains(':') {
-                // Time only
-                let time_cls = datetime.getattr("time")?;
-                let parts: Vec<&str> = dt_str.split(':').collect();
-                if parts.len() >= 2 {
-                    let hour: u32 = parts[0].parse().unwrap_or(0);
-                    let minute: u32 = parts[1].parse().unwrap_or(0);
-                    let second: u32 = if parts.len() > 2 {
-                        parts[2].split('.').next().unwrap_or("0").parse().unwrap_or(0)
-                    } else {
-                        0
-                    };
-                    time_cls.call1((hour, minute, second))?.extract()
-                } else {
-                    Ok(dt_str.into_py(py))
-                }
-            } else {
-                // Date only
-                let date_cls = datetime.getattr("date")?;
-                date_cls.call_method1("fromisoformat", (dt_str,))?.extract()
-            }
-        }
+        toml::Value::Datetime(dt) => datetime_to_py(py, dt),
         toml::Value::Array(arr) => {
             let list = PyList::empty(py);
             for item in arr {
-                list.append(toml_value_to_py(py, item)?)?;
+                list.append(toml_value_to_py(py, item, access)?)?;
             }
             Ok(list.into())
         }
         toml::Value::Table(table) => {
             let dict = PyDict::new(py);
             for (key, value) in table {
-                dict.set_item(key, toml_value_to_py(py, value)?)?;
+                dict.set_item(key, toml_value_to_py(py, value, access)?)?;
+            }
+            wrap_table(py, dict, access)
+        }
+    }
+}
+
+/// The JSON Schema type name for a TOML value; datetimes are "datetime"
+fn schema_type_name(value: &toml::Value) -> &'static str {
+    match value {
+        toml::Value::String(_) => "string",
+        toml::Value::Integer(_) => "integer",
+        toml::Value::Float(_) => "number",
+        toml::Value::Boolean(_) => "boolean",
+        toml::Value::Datetime(_) => "datetime",
+        toml::Value::Array(_) => "array",
+        toml::Value::Table(_) => "object",
+    }
+}
+
+/// `path.key`, or just `key` at the top level
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Check `value` against the structural part of a JSON Schema: `type`,
+/// `enum`, `required`, `properties`, `additionalProperties` and `items`.
+/// Other keywords are ignored. Each mismatch is added to `errors` as
+/// "path: message".
+fn check_schema(
+    py: Python<'_>,
+    value: &toml::Value,
+    schema: &PyAny,
+    path: &str,
+    errors: &mut Vec<String>,
+) -> PyResult<()> {
+    let schema: &PyDict = schema
+        .downcast()
+        .map_err(|_| PyTypeError::new_err(format!("schema for '{}' must be a dict", path)))?;
+    let at = if path.is_empty() { "<root>" } else { path };
+
+    if let Some(types) = schema.get_item("type")? {
+        let types: Vec<String> = match types.extract::<String>() {
+            Ok(name) => vec![name],
+            Err(_) => types.extract()?,
+        };
+        let actual = schema_type_name(value);
+        // Integers are numbers too, as in JSON Schema
+        if !types.iter().any(|t| t == actual || (t == "number" && actual == "integer")) {
+            errors.push(format!("{}: expected {}, got {}", at, types.join(" or "), actual));
+            return Ok(());
+        }
+    }
+
+    if let Some(choices) = schema.get_item("enum")? {
+        let py_value = toml_value_to_py(py, value, Access::Dict)?;
+        if !choices.contains(&py_value)? {
+            errors.push(format!(
+                "{}: {} is not one of {}",
+                at,
+                py_value.as_ref(py).repr()?,
+                choices.repr()?
+            ));
+        }
+    }
+
+    match value {
+        toml::Value::Table(table) => {
+            if let Some(required) = schema.get_item("required")? {
+                for key in required.extract::<Vec<String>>()? {
+                    if !table.contains_key(&key) {
+                        errors.push(format!("{}: missing required key '{}'", at, key));
+                    }
+                }
+            }
+            let properties: Option<&PyDict> = match schema.get_item("properties")? {
+                Some(properties) => Some(properties.downcast()?),
+                None => None,
+            };
+            let additional = schema.get_item("additionalProperties")?;
+            for (key, item) in table {
+                let item_path = child_path(path, key);
+                let property = match properties {
+                    Some(properties) => properties.get_item(key)?,
+                    None => None,
+                };
+                match (property, additional) {
+                    (Some(subschema), _) => check_schema(py, item, subschema, &item_path, errors)?,
+                    (None, Some(additional)) => match additional.extract::<bool>() {
+                        Ok(true) => {}
+                        Ok(false) => errors.push(format!("{}: unexpected key", item_path)),
+                        Err(_) => check_schema(py, item, additional, &item_path, errors)?,
+                    },
+                    (None, None) => {}
+                }
+            }
+        }
+        toml::Value::Array(items) => {
+            if let Some(subschema) = schema.get_item("items")? {
+                for (i, item) in items.iter().enumerate() {
+                    check_schema(py, item, subschema, &format!("{}[{}]", path, i), errors)?;
+                }
             }
-            Ok(dict.into())
         }
+        _ => {}
     }
+    Ok(())
+}
+
+/// Raise `TOMLSchemaError` listing every mismatch between `doc` and `schema`
+fn validate_schema(py: Python<'_>, doc: &str, schema: &PyAny) -> PyResult<()> {
+    let value: toml::Value = doc.parse().map_err(|e: toml::de::Error| {
+        decode_error(py, doc, e.message(), e.to_string(), e.span())
+    })?;
+    let mut errors = Vec::new();
+    check_schema(py, &value, schema, "", &mut errors)?;
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let err = TOMLSchemaError::new_err(format!(
+        "TOML does not match schema: {}",
+        errors.join("; ")
+    ));
+    err.value(py).setattr("errors", errors)?;
+    Err(err)
 }
 
 /// Parse a TOML string and return a Python dict
 /// 
 /// Args:
 ///     s (str): TOML string to parse
+///     access (str): "dict" for plain dicts (default) or "attr" for
+///         Namespace objects allowing `config.server.port`
+///     preserve_source (bool): wrap integers, floats and datetimes in
+///         SourceValue objects carrying the original token text
+///     schema (dict): optional JSON Schema the data must match; `type`,
+///         `enum`, `required`, `properties`, `additionalProperties` and
+///         `items` are checked, with "datetime" as the type of TOML dates
+///         and times
 ///
 /// Returns:
 ///     dict: Parsed TOML data as Python dictionary
 ///
 /// Raises:
 ///     TOMLDecodeError: If the TOML is invalid
+///     TOMLSchemaError: If the data doesn't match `schema`; its `errors`
+///         attribute lists every mismatch
 #[pyfunction]
-fn loads(py: Python<'_>, s: &str) -> PyResult<PyObject> {
+#[pyo3(signature = (s, *, access="dict", preserve_source=false, schema=None))]
+fn loads(py: Python<'_>, s: &str, access: &str, preserve_source: bool, schema: Option<&PyAny>) -> PyResult<PyObject> {
+    let access = Access::from_name(access)?;
+    
+    if let Some(schema) = schema {
+        validate_schema(py, s, schema)?;
+    }
+    
+    // The toml crate normalizes literals away, so keep them via toml_edit
+    if preserve_source {
+        let document: toml_edit::DocumentMut = s.parse().map_err(|e: toml_edit::TomlError| {
+            decode_error(py, s, e.message(), e.to_string(), e.span())
+        })?;
+        return edit_item_to_py(py, document.as_item(), access);
+    }
+    
     // Parse TOML
-    let value: toml::Value = s.parse().map_err(|e| {
-        PyValueError::new_err(format!("TOML parse error: {}", e))
+    let value: toml::Value = s.parse().map_err(|e: toml::de::Error| {
+        decode_error(py, s, e.message(), e.to_string(), e.span())
     })?;
     
     // Convert to Python dict
-    toml_value_to_py(py, &value)
+    toml_value_to_py(py, &value, access)
 }
 
 /// Load and parse TOML from a binary file object
 ///
 /// Args:
 ///     fp: A binary file object (must have .read() method)
+///     access (str): "dict" (default) or "attr", as for `loads`
+///     preserve_source (bool): as for `loads`
+///     schema (dict): as for `loads`
 ///
 /// Returns:
 ///     dict: Parsed TOML data as Python dictionary
 ///
 /// Raises:
 ///     TOMLDecodeError: If the TOML is invalid
+///     TOMLSchemaError: If the data doesn't match `schema`
 #[pyfunction]
-fn load(py: Python<'_>, fp: &PyAny) -> PyResult<PyObject> {
+#[pyo3(signature = (fp, *, access="dict", preserve_source=false, schema=None))]
+fn load(py: Python<'_>, fp: &PyAny, access: &str, preserve_source: bool, schema: Option<&PyAny>) -> PyResult<PyObject> {
     // Read from file object
     let content = if let Ok(read_method) = fp.getattr("read") {
         let bytes = read_method.call0()?;
@@ -123,7 +639,7 @@ fn load(py: Python<'_>, fp: &PyAny) -> PyResult<PyObject> {
     };
     
     // Parse and return
-    loads(py, &content)
+    loads(py, &content, access, preserve_source, schema)
 }
 
 /// tomli-rs: High-performance TOML parser for Python
@@ -135,6 +651,10 @@ fn load(py: Python<'_>, fp: &PyAny) -> PyResult<PyObject> {
 ///     loads(s: str) -> dict: Parse a TOML string
 ///     load(fp: BinaryIO) -> dict: Load and parse TOML from a file
 ///
+/// Both accept `access="attr"` to return Namespace objects instead of dicts,
+/// `preserve_source=True` to keep the original spelling of scalars, and
+/// `schema={...}` to check the data's structure before it is returned.
+///
 /// Example:
 ///     ```python
 ///     import tomli_rs
@@ -150,13 +670,14 @@ fn load(py: Python<'_>, fp: &PyAny) -> PyResult<PyObject> {
 fn tomli_rs(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(loads, m)?)?;
     m.add_function(wrap_pyfunction!(load, m)?)?;
+    m.add_class::<Namespace>()?;
+    m.add_class::<SourceValue>()?;
     
     // Add version
     m.add("__version__", "0.1.0")?;
     
-    // Create TOMLDecodeError exception class (alias to ValueError for compatibility)
-    let decode_error = _py.get_type::<PyValueError>();
-    m.add("TOMLDecodeError", decode_error)?;
+    m.add("TOMLDecodeError", _py.get_type::<TOMLDecodeError>())?;
+    m.add("TOMLSchemaError", _py.get_type::<TOMLSchemaError>())?;
     
     Ok(())
 }
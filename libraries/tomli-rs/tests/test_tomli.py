@@ -6,6 +6,7 @@ Verifies API compatibility with original tomli.
 
 import pytest
 import io
+from datetime import date, datetime, time, timedelta, timezone
 
 try:
     import tomli_rs
@@ -202,6 +203,60 @@ time = 10:30:00
         assert "time" in result
 
 
+class TestSpecEdgeCases:
+    """Bare, quoted and dotted keys; local times and fractional seconds."""
+
+    def test_bare_keys(self):
+        result = tomli_rs.loads("bare-key_1 = 1\n1234 = 2")
+        assert result == {"bare-key_1": 1, "1234": 2}
+
+    def test_quoted_keys(self):
+        toml = """
+"key with spaces" = 1
+'quoted.dot' = 2
+site."google.com" = true
+"""
+        result = tomli_rs.loads(toml)
+        assert result == {
+            "key with spaces": 1,
+            "quoted.dot": 2,
+            "site": {"google.com": True},
+        }
+
+    def test_dotted_keys_create_tables(self):
+        toml = """
+a.b.c = 1
+a.b.d = 2
+a.e = 3
+"""
+        assert tomli_rs.loads(toml) == {"a": {"b": {"c": 1, "d": 2}, "e": 3}}
+
+    def test_local_time(self):
+        assert tomli_rs.loads("t = 07:32:00")["t"] == time(7, 32)
+
+    def test_local_time_fractional_seconds(self):
+        result = tomli_rs.loads("a = 00:32:00.5\nb = 00:32:00.999999\nc = 00:32:00.123456789")
+        assert result["a"] == time(0, 32, 0, 500000)
+        assert result["b"] == time(0, 32, 0, 999999)
+        # Truncated to the microseconds datetime can hold
+        assert result["c"] == time(0, 32, 0, 123456)
+
+    def test_datetimes(self):
+        result = tomli_rs.loads(
+            "odt = 1979-05-27T00:32:00.999999-07:00\n"
+            "utc = 1979-05-27T07:32:00Z\n"
+            "ldt = 1979-05-27T07:32:00.25\n"
+            "ld = 1979-05-27"
+        )
+        assert result["odt"] == datetime(
+            1979, 5, 27, 0, 32, 0, 999999, tzinfo=timezone(timedelta(hours=-7))
+        )
+        assert result["utc"] == datetime(1979, 5, 27, 7, 32, tzinfo=timezone.utc)
+        assert result["ldt"] == datetime(1979, 5, 27, 7, 32, 0, 250000)
+        assert result["ldt"].tzinfo is None
+        assert result["ld"] == date(1979, 5, 27)
+
+
 class TestRealWorld:
     """Test real-world TOML files."""
     
@@ -282,5 +337,257 @@ class TestEdgeCases:
         assert "\\" in result["path"]
 
 
+class TestAttrAccess:
+    """Test access="attr" namespaces"""
+    
+    def test_nested_tables(self):
+        toml = """
+[server]
+host = "localhost"
+port = 8080
+
+[server.tls]
+enabled = true
+"""
+        config = tomli_rs.loads(toml, access="attr")
+        assert config.server.host == "localhost"
+        assert config.server.port == 8080
+        assert config.server.tls.enabled is True
+    
+    def test_array_of_tables(self):
+        toml = """
+[[products]]
+name = "Hammer"
+
+[[products]]
+name = "Nail"
+"""
+        config = tomli_rs.loads(toml, access="attr")
+        assert [p.name for p in config.products] == ["Hammer", "Nail"]
+    
+    def test_item_access(self):
+        config = tomli_rs.loads('"my-key" = 1\nother = 2', access="attr")
+        assert config["my-key"] == 1
+        assert config["other"] == config.other
+        assert "my-key" in config
+        assert len(config) == 2
+    
+    def test_missing_key(self):
+        config = tomli_rs.loads("a = 1", access="attr")
+        with pytest.raises(AttributeError):
+            config.b
+        with pytest.raises(KeyError):
+            config["b"]
+    
+    def test_to_dict(self):
+        toml = """
+[a]
+b = [1, 2]
+
+[[c]]
+d = 3
+"""
+        config = tomli_rs.loads(toml, access="attr")
+        assert config.to_dict() == tomli_rs.loads(toml)
+    
+    def test_load(self):
+        fp = io.BytesIO(b"[x]\ny = 1")
+        assert tomli_rs.load(fp, access="attr").x.y == 1
+    
+    def test_invalid_access(self):
+        with pytest.raises(ValueError):
+            tomli_rs.loads("a = 1", access="object")
+
+
+class TestPreserveSource:
+    """Test preserve_source=True"""
+    
+    def test_integer_literals(self):
+        result = tomli_rs.loads("a = 1_000\nb = 0xFF\nc = 0o17\nd = 0b11", preserve_source=True)
+        assert (result["a"].value, result["a"].source) == (1000, "1_000")
+        assert (result["b"].value, result["b"].source) == (255, "0xFF")
+        assert (result["c"].value, result["c"].source) == (15, "0o17")
+        assert (result["d"].value, result["d"].source) == (3, "0b11")
+    
+    def test_floats(self):
+        result = tomli_rs.loads("a = 1e3\nb = +inf", preserve_source=True)
+        assert (result["a"].value, result["a"].source) == (1000.0, "1e3")
+        assert result["b"].source == "+inf"
+    
+    def test_datetime(self):
+        result = tomli_rs.loads("d = 1979-05-27 07:32:00Z", preserve_source=True)
+        assert result["d"].value == datetime(1979, 5, 27, 7, 32, tzinfo=timezone.utc)
+        assert str(result["d"]) == "1979-05-27 07:32:00Z"
+    
+    def test_strings_and_bools_unwrapped(self):
+        result = tomli_rs.loads('s = "x"\nb = true', preserve_source=True)
+        assert result == {"s": "x", "b": True}
+    
+    def test_nested(self):
+        toml = """
+arr = [1_0, 0x10]
+inline = { x = 0o7 }
+
+[[tables]]
+n = 1_0
+"""
+        result = tomli_rs.loads(toml, preserve_source=True)
+        assert [v.source for v in result["arr"]] == ["1_0", "0x10"]
+        assert result["inline"]["x"].value == 7
+        assert result["tables"][0]["n"].source == "1_0"
+    
+    def test_attr_access(self):
+        config = tomli_rs.loads("[server]\nport = 8_080", access="attr", preserve_source=True)
+        assert config.server.port.value == 8080
+    
+    def test_invalid(self):
+        with pytest.raises(ValueError):
+            tomli_rs.loads("a = ", preserve_source=True)
+    
+    def test_compares_as_value(self):
+        result = tomli_rs.loads("a = 0xFF\nb = 1_000\nc = 255", preserve_source=True)
+        assert result["a"] == 255
+        assert 255 == result["a"]
+        assert result["a"] == result["c"]
+        assert result["a"] < result["b"]
+        assert result["b"] >= 1000
+        assert result["a"] != "0xFF"
+    
+    def test_hash_matches_value(self):
+        result = tomli_rs.loads("a = 0xFF", preserve_source=True)
+        assert hash(result["a"]) == hash(255)
+        assert {255: "x"}[result["a"]] == "x"
+    
+    def test_arithmetic(self):
+        result = tomli_rs.loads("a = 1_000\nb = 2.5\nc = 0b11", preserve_source=True)
+        assert result["a"] + 1 == 1001
+        assert 1 + result["a"] == 1001
+        assert result["a"] - result["c"] == 997
+        assert 10 - result["c"] == 7
+        assert result["b"] * 2 == 5.0
+        assert result["a"] / 8 == 125.0
+        assert result["a"] // 3 == 333
+        assert result["a"] % 3 == 1
+        assert result["c"] ** 2 == 9
+        assert pow(result["c"], 2, 5) == 4
+        assert 2 ** result["c"] == 8
+        assert -result["a"] == -1000
+        assert abs(-result["b"]) == 2.5
+        with pytest.raises(TypeError):
+            result["a"] + "x"
+    
+    def test_conversions(self):
+        result = tomli_rs.loads("a = 0x10\nb = 2.5\ni = 0b1\nz = 0", preserve_source=True)
+        assert int(result["b"]) == 2
+        assert float(result["a"]) == 16.0
+        assert [0, 1, 2][result["i"]] == 1
+        assert ["x"] * result["z"] == []
+        assert bool(result["a"]) and not bool(result["z"])
+        assert f"{result['b']:.2f}" == "2.50"
+        assert str(result["a"]) == "0x10"
+    
+    def test_attributes_delegate(self):
+        result = tomli_rs.loads("d = 1979-05-27T07:32:00Z\nf = 2.0", preserve_source=True)
+        assert result["d"].year == 1979
+        assert result["f"].is_integer()
+        assert result["d"] - timedelta(days=1) == datetime(1979, 5, 26, 7, 32, tzinfo=timezone.utc)
+        with pytest.raises(AttributeError):
+            result["f"].missing
+
+
+class TestDecodeError:
+    """Position data on TOMLDecodeError."""
+
+    def test_is_value_error(self):
+        assert issubclass(tomli_rs.TOMLDecodeError, ValueError)
+
+    def test_position(self):
+        with pytest.raises(tomli_rs.TOMLDecodeError) as info:
+            tomli_rs.loads("a = 1\nb = = 2\n")
+        err = info.value
+        assert (err.lineno, err.colno) == (2, 5)
+        assert err.pos == 10
+        assert err.end_pos >= err.pos
+        assert err.msg
+
+    def test_byte_offsets_with_multibyte_text(self):
+        with pytest.raises(tomli_rs.TOMLDecodeError) as info:
+            tomli_rs.loads('x = "é" "\n')
+        err = info.value
+        assert err.pos == 9
+        assert err.colno == 9
+
+    def test_duplicate_key(self):
+        with pytest.raises(tomli_rs.TOMLDecodeError) as info:
+            tomli_rs.loads("a = 1\na = 2")
+        assert info.value.lineno == 2
+        assert info.value.colno == 1
+
+    def test_preserve_source_mode(self):
+        with pytest.raises(tomli_rs.TOMLDecodeError) as info:
+            tomli_rs.loads("a = 1\nb = = 2\n", preserve_source=True)
+        assert (info.value.lineno, info.value.colno) == (2, 5)
+
+
+class TestSchema:
+    """Structural validation with schema=."""
+
+    SCHEMA = {
+        "type": "object",
+        "required": ["project"],
+        "properties": {
+            "project": {
+                "type": "object",
+                "required": ["name", "version"],
+                "properties": {
+                    "name": {"type": "string"},
+                    "version": {"type": "string"},
+                    "license": {"enum": ["MIT", "Apache-2.0"]},
+                    "authors": {"type": "array", "items": {"type": "object", "required": ["name"]}},
+                },
+                "additionalProperties": False,
+            },
+            "released": {"type": "datetime"},
+            "ratio": {"type": "number"},
+        },
+    }
+
+    def test_valid(self):
+        doc = 'ratio = 2\nreleased = 2024-01-01\n[project]\nname = "x"\nversion = "1.0"\n'
+        data = tomli_rs.loads(doc, schema=self.SCHEMA)
+        assert data["project"] == {"name": "x", "version": "1.0"}
+
+    def test_default_unchanged(self):
+        assert tomli_rs.loads("a = 1") == {"a": 1}
+
+    def test_collects_all_errors(self):
+        doc = (
+            '[project]\nversion = 1\nlicense = "GPL"\nextra = true\n'
+            '[[project.authors]]\nemail = "a@example.com"\n'
+        )
+        with pytest.raises(tomli_rs.TOMLSchemaError) as info:
+            tomli_rs.loads(doc, schema=self.SCHEMA)
+        assert set(info.value.errors) == {
+            "project: missing required key 'name'",
+            "project.authors[0]: missing required key 'name'",
+            "project.extra: unexpected key",
+            "project.license: 'GPL' is not one of ['MIT', 'Apache-2.0']",
+            "project.version: expected string, got integer",
+        }
+
+    def test_missing_root_key(self):
+        with pytest.raises(tomli_rs.TOMLSchemaError) as info:
+            tomli_rs.loads("a = 1", schema=self.SCHEMA)
+        assert info.value.errors == ["<root>: missing required key 'project'"]
+
+    def test_is_value_error(self):
+        assert issubclass(tomli_rs.TOMLSchemaError, ValueError)
+
+    def test_load(self):
+        fp = io.BytesIO(b'[project]\nname = "x"\n')
+        with pytest.raises(tomli_rs.TOMLSchemaError):
+            tomli_rs.load(fp, schema=self.SCHEMA)
+
+
 if __name__ == "__main__":
     pytest.main([__file__, "-v"])
@@ -1,7 +1,14 @@
+// pyo3 0.22's `#[pyfunction]` expansion trips this lint on `PyResult` returns
+#![allow(clippy::useless_conversion)]
+
 use once_cell::sync::Lazy;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use regex::Regex;
+use pyo3::types::PyDict;
+use regex::{Regex, RegexBuilder};
+use std::collections::HashMap;
 use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::Mutex;
 
 // Pre-compiled regex patterns for performance
 static EMAIL_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -15,9 +22,12 @@ static SLUG_REGEX: Lazy<Regex> = Lazy::new(|| {
 });
 
 static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[1-5][0-9a-fA-F]{3}-[89abAB][0-9a-fA-F]{3}-[0-9a-fA-F]{12}$").unwrap()
+    Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[1-8][0-9a-fA-F]{3}-[89abAB][0-9a-fA-F]{3}-[0-9a-fA-F]{12}$").unwrap()
 });
 
+const NIL_UUID: &str = "00000000-0000-0000-0000-000000000000";
+const MAX_UUID: &str = "ffffffff-ffff-ffff-ffff-ffffffffffff";
+
 static MD5_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"^[a-fA-F0-9]{32}$").unwrap()
 });
@@ -38,14 +48,128 @@ static MAC_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"^([0-9A-Fa-f]{2}[:-]){5}([0-9A-Fa-f]{2})$").unwrap()
 });
 
+// User patterns passed to `regex`, keyed by (pattern, flags)
+static PATTERN_CACHE: Lazy<Mutex<HashMap<(String, u32), Regex>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+const PATTERN_CACHE_SIZE: usize = 256;
+
+// Python `re` flag values understood by `regex`
+const RE_IGNORECASE: u32 = 2;
+const RE_MULTILINE: u32 = 8;
+const RE_DOTALL: u32 = 16;
+const RE_VERBOSE: u32 = 64;
+
+/// Falsy result returned by a validator that rejected its input
+/// validators.length("abc", min=8) -> ValidationFailure(func=length, ...)
+#[pyclass(frozen)]
+pub struct ValidationFailure {
+    #[pyo3(get)]
+    func: String,
+    #[pyo3(get)]
+    args: Py<PyDict>,
+    #[pyo3(get)]
+    reason: String,
+}
+
+#[pymethods]
+impl ValidationFailure {
+    fn __bool__(&self) -> bool {
+        false
+    }
+
+    fn __str__(&self) -> String {
+        self.reason.clone()
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "ValidationFailure(func={}, args={})",
+            self.func,
+            self.args.bind(py).repr()?
+        ))
+    }
+}
+
+/// Build a `ValidationFailure` for `func` from its keyword arguments
+fn failure(
+    py: Python<'_>,
+    func: &str,
+    args: &[(&str, PyObject)],
+    reason: String,
+) -> PyResult<PyObject> {
+    let dict = PyDict::new_bound(py);
+    for (key, value) in args {
+        dict.set_item(key, value)?;
+    }
+    let failure = ValidationFailure {
+        func: func.to_string(),
+        args: dict.unbind(),
+        reason,
+    };
+    Ok(Py::new(py, failure)?.into_any())
+}
+
 /// Validate an email address
 /// validators.email("test@example.com") -> True
+///
+/// As with `domain`, the top-level domain must be alphabetic or punycode.
+///
+/// With `check_deliverability`, the address is also checked structurally
+/// (no DNS lookup): the local part must be at most 64 characters with no
+/// leading, trailing or consecutive dots, and the domain must pass `domain`.
+///
+/// With `normalize`, surrounding whitespace is stripped and the domain
+/// lowercased (the local part is left as is), and the cleaned address is
+/// returned instead of True, or a `ValidationFailure`.
 #[pyfunction]
-fn email(value: &str) -> bool {
+#[pyo3(signature = (value, check_deliverability=false, *, normalize=false))]
+fn email(py: Python<'_>, value: &str, check_deliverability: bool, normalize: bool) -> PyResult<PyObject> {
+    if !normalize {
+        return Ok(valid_email(value, check_deliverability).into_py(py));
+    }
+
+    let value = value.trim();
+    let cleaned = match value.rsplit_once('@') {
+        Some((local, domain_part)) => format!("{}@{}", local, domain_part.to_lowercase()),
+        None => value.to_string(),
+    };
+    if valid_email(&cleaned, check_deliverability) {
+        return Ok(cleaned.into_py(py));
+    }
+
+    failure(
+        py,
+        "email",
+        &[("value", value.into_py(py))],
+        format!("{:?} is not a valid email address", value),
+    )
+}
+
+fn valid_email(value: &str, check_deliverability: bool) -> bool {
     if value.is_empty() || value.len() > 254 {
         return false;
     }
-    EMAIL_REGEX.is_match(value)
+    if !EMAIL_REGEX.is_match(value) {
+        return false;
+    }
+    
+    let Some((local, domain_part)) = value.rsplit_once('@') else {
+        return false;
+    };
+    // Numeric TLDs are rejected as in `domain`, even without deliverability
+    // checks; a bare host such as "localhost" is still accepted
+    let tld = domain_part.rsplit('.').next().unwrap_or(domain_part);
+    if !valid_tld(tld) {
+        return false;
+    }
+    if !check_deliverability {
+        return true;
+    }
+    if local.len() > 64 || local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return false;
+    }
+    domain(domain_part)
 }
 
 static URL_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -54,13 +178,178 @@ static URL_REGEX: Lazy<Regex> = Lazy::new(|| {
     ).unwrap()
 });
 
+// `scheme://authority...` for any scheme, used when `schemes` is given
+static AUTHORITY_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*://[^\s/$.?#].[^\s]*$").unwrap()
+});
+
+// `type/subtype` plus `;key=value` parameters, optionally ending in `;base64`
+static DATA_MEDIA_TYPE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(?:[a-zA-Z0-9!#$&^_.+-]+/[a-zA-Z0-9!#$&^_.+-]+)?(?:;[a-zA-Z0-9!#$&^_.+-]+=[^;,]+)*(?:;base64)?$"
+    ).unwrap()
+});
+
+/// Whether `s` uses only URI characters, with every `%` starting a valid
+/// escape and at most one `#`
+fn valid_uri_encoding(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let escape = bytes.get(i + 1..i + 3);
+                if !escape.is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)) {
+                    return false;
+                }
+                i += 3;
+                continue;
+            }
+            b if b.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=".contains(&b) => {}
+            _ => return false,
+        }
+        i += 1;
+    }
+    s.matches('#').count() <= 1
+}
+
+/// Whether `s` is padded standard base64
+// `usize::is_multiple_of` needs a newer toolchain than we support
+#[allow(clippy::manual_is_multiple_of)]
+fn valid_base64(s: &str) -> bool {
+    let data = s.trim_end_matches('=');
+    s.len() % 4 == 0
+        && s.len() - data.len() <= 2
+        && data
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'/')
+}
+
+/// Validate a `mailto:` body: comma-separated addresses, optional `?headers`
+fn valid_mailto(rest: &str, check_encoding: bool) -> bool {
+    let (addresses, headers) = rest.split_once('?').unwrap_or((rest, ""));
+    if check_encoding && !valid_uri_encoding(headers) {
+        return false;
+    }
+    !addresses.is_empty() && addresses.split(',').all(|address| valid_email(address, false))
+}
+
+/// Validate a `data:` body: `[mediatype][;base64],payload`
+fn valid_data(rest: &str, check_encoding: bool) -> bool {
+    let Some((media_type, payload)) = rest.split_once(',') else {
+        return false;
+    };
+    if !DATA_MEDIA_TYPE_REGEX.is_match(media_type) {
+        return false;
+    }
+    if media_type.ends_with(";base64") {
+        valid_base64(payload)
+    } else {
+        !check_encoding || valid_uri_encoding(payload)
+    }
+}
+
 /// Validate a URL
 /// validators.url("https://example.com") -> True
+///
+/// Both checks are opt-in. `schemes` whitelists the accepted schemes (any
+/// `scheme://` URL otherwise passes the same structural check as http);
+/// `mailto:` validates each address with `email` and `data:` its media
+/// type and base64 payload. `check_encoding` requires the path, query and
+/// fragment to use only URI characters with well-formed `%XX` escapes.
+///
+/// With `normalize`, surrounding whitespace is stripped, `default_scheme`
+/// is added to a URL without one ("example.com/a" -> "https://example.com/a")
+/// and the scheme and host are lowercased; the cleaned URL is returned
+/// instead of True, or a `ValidationFailure`.
 #[pyfunction]
-#[pyo3(signature = (value, public=false))]
-fn url(value: &str, public: bool) -> bool {
-    if !URL_REGEX.is_match(value) {
-        return false;
+#[pyo3(signature = (value, public=false, *, schemes=None, check_encoding=false, normalize=false, default_scheme="https"))]
+fn url(
+    py: Python<'_>,
+    value: &str,
+    public: bool,
+    schemes: Option<Vec<String>>,
+    check_encoding: bool,
+    normalize: bool,
+    default_scheme: &str,
+) -> PyResult<PyObject> {
+    let schemes = schemes.as_deref();
+    if !normalize {
+        return Ok(valid_url(value, public, schemes, check_encoding).into_py(py));
+    }
+
+    let value = value.trim();
+    let cleaned = normalize_url(value, schemes, default_scheme);
+    if valid_url(&cleaned, public, schemes, check_encoding) {
+        return Ok(cleaned.into_py(py));
+    }
+
+    failure(
+        py,
+        "url",
+        &[("value", value.into_py(py))],
+        format!("{:?} is not a valid URL", value),
+    )
+}
+
+/// Add `default_scheme` when `value` has none and lowercase the scheme and
+/// host, leaving any userinfo, port and path untouched
+fn normalize_url(value: &str, schemes: Option<&[String]>, default_scheme: &str) -> String {
+    if let Some((scheme, rest)) = value.split_once("://") {
+        let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let (authority, tail) = rest.split_at(end);
+        let authority = match authority.rsplit_once('@') {
+            Some((userinfo, host)) => format!("{}@{}", userinfo, host.to_lowercase()),
+            None => authority.to_lowercase(),
+        };
+        return format!("{}://{}{}", scheme.to_lowercase(), authority, tail);
+    }
+
+    // A whitelisted `scheme:` such as mailto: already has its scheme
+    if let (Some((scheme, rest)), Some(schemes)) = (value.split_once(':'), schemes) {
+        if schemes.iter().any(|allowed| allowed.eq_ignore_ascii_case(scheme)) {
+            return format!("{}:{}", scheme.to_lowercase(), rest);
+        }
+    }
+
+    normalize_url(&format!("{}://{}", default_scheme, value), schemes, default_scheme)
+}
+
+fn valid_url(value: &str, public: bool, schemes: Option<&[String]>, check_encoding: bool) -> bool {
+    match schemes {
+        None => {
+            if !URL_REGEX.is_match(value) {
+                return false;
+            }
+        }
+        Some(schemes) => {
+            let Some((scheme, rest)) = value.split_once(':') else {
+                return false;
+            };
+            if !schemes.iter().any(|allowed| allowed.eq_ignore_ascii_case(scheme)) {
+                return false;
+            }
+            match scheme.to_ascii_lowercase().as_str() {
+                "mailto" => return valid_mailto(rest, check_encoding),
+                "data" => return valid_data(rest, check_encoding),
+                _ => {
+                    if !AUTHORITY_URL_REGEX.is_match(value) {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+
+    if check_encoding {
+        // Everything after the authority: path, query and fragment
+        let after_scheme = value.split_once("://").map_or("", |(_, rest)| rest);
+        let tail = after_scheme
+            .find(['/', '?', '#'])
+            .map_or("", |i| &after_scheme[i..]);
+        if !valid_uri_encoding(tail) {
+            return false;
+        }
     }
     
     if public {
@@ -103,51 +392,239 @@ fn domain(value: &str) -> bool {
         }
     }
     
-    // TLD must be alphabetic
-    let tld = parts.last().unwrap();
-    if !tld.chars().all(|c| c.is_ascii_alphabetic()) {
-        return false;
+    valid_tld(parts.last().unwrap())
+}
+
+/// Whether `tld` is alphabetic, or a punycode label such as "xn--p1ai"
+fn valid_tld(tld: &str) -> bool {
+    let punycode = tld.len() > 4 && tld[..4].eq_ignore_ascii_case("xn--");
+    punycode || tld.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// Split an optional `/prefix` off an address, rejecting prefixes above `max`
+/// or written with leading zeros
+fn split_prefix(value: &str, cidr: bool, max: u8) -> Option<&str> {
+    match value.split_once('/') {
+        None => Some(value),
+        Some((addr, prefix)) if cidr => {
+            let valid_digits = !prefix.is_empty()
+                && prefix.len() <= 3
+                && prefix.bytes().all(|b| b.is_ascii_digit())
+                && (prefix == "0" || !prefix.starts_with('0'));
+            match prefix.parse::<u8>() {
+                Ok(bits) if valid_digits && bits <= max => Some(addr),
+                _ => None,
+            }
+        }
+        Some(_) => None,
     }
-    
-    true
+}
+
+/// Parse dotted-quad IPv4, allowing leading-zero octets (read as decimal)
+/// unless `strict`
+fn parse_ipv4(value: &str, strict: bool) -> Option<Ipv4Addr> {
+    if strict {
+        return value.parse().ok();
+    }
+    let octets: Vec<u8> = value
+        .split('.')
+        .map(|octet| {
+            if octet.is_empty() || octet.len() > 3 || !octet.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            octet.parse().ok()
+        })
+        .collect::<Option<_>>()?;
+    let octets: [u8; 4] = octets.try_into().ok()?;
+    Some(Ipv4Addr::from(octets))
+}
+
+/// Parse IPv6, allowing a trailing `%zone` scope ID such as `%eth0` only
+/// with `allow_zone`
+fn parse_ipv6(value: &str, allow_zone: bool) -> Option<Ipv6Addr> {
+    let addr = match value.split_once('%') {
+        Some(_) if !allow_zone => return None,
+        Some((addr, zone)) => {
+            let valid_zone = !zone.is_empty()
+                && zone.bytes().all(|b| b.is_ascii_alphanumeric() || b"-_.".contains(&b));
+            if !valid_zone {
+                return None;
+            }
+            addr
+        }
+        None => value,
+    };
+    addr.parse().ok()
 }
 
 /// Validate an IPv4 address
 /// validators.ipv4("192.168.1.1") -> True
+///
+/// With `cidr`, a `/0`-`/32` prefix is also accepted. Octets with leading
+/// zeros ("010.0.0.1") are rejected unless `strict=False`, since some
+/// parsers read them as octal; when allowed they are read as decimal.
 #[pyfunction]
-fn ipv4(value: &str) -> bool {
-    value.parse::<Ipv4Addr>().is_ok()
+#[pyo3(signature = (value, cidr=false, strict=true))]
+fn ipv4(value: &str, cidr: bool, strict: bool) -> bool {
+    split_prefix(value, cidr, 32).and_then(|addr| parse_ipv4(addr, strict)).is_some()
 }
 
 /// Validate an IPv6 address
 /// validators.ipv6("::1") -> True
+///
+/// With `cidr`, a `/0`-`/128` prefix is also accepted. IPv4-mapped
+/// addresses ("::ffff:192.168.0.1") are valid; an embedded IPv4 part
+/// follows the strict IPv4 rules. Zone IDs ("fe80::1%eth0") are rejected
+/// unless `allow_zone=True`, which accepts them after the address part is
+/// validated.
+#[pyfunction]
+#[pyo3(signature = (value, cidr=false, *, allow_zone=false))]
+fn ipv6(value: &str, cidr: bool, allow_zone: bool) -> bool {
+    split_prefix(value, cidr, 128)
+        .and_then(|addr| parse_ipv6(addr, allow_zone))
+        .is_some()
+}
+
+/// Validate an IPv4 or IPv6 network such as "10.0.0.0/8" or "2001:db8::/64"
+/// validators.ip_network("192.168.0.0/24") -> True
+///
+/// A bare address is a single-host network, as in `ipaddress.ip_network`.
+/// With `strict`, the host bits below the prefix must be zero, so
+/// "192.168.0.1/24" is rejected. Zone IDs aren't allowed.
 #[pyfunction]
-fn ipv6(value: &str) -> bool {
-    value.parse::<Ipv6Addr>().is_ok()
+#[pyo3(signature = (value, strict=true))]
+fn ip_network(value: &str, strict: bool) -> bool {
+    let (addr, prefix) = value.split_once('/').unwrap_or((value, ""));
+    let (bits, max) = if let Some(v4) = parse_ipv4(addr, true) {
+        (u128::from(u32::from(v4)), 32)
+    } else if let Ok(v6) = addr.parse::<Ipv6Addr>() {
+        (u128::from(v6), 128)
+    } else {
+        return false;
+    };
+
+    if split_prefix(value, true, max).is_none() {
+        return false;
+    }
+    let prefix_len: u8 = if prefix.is_empty() { max } else { prefix.parse().unwrap_or(max) };
+
+    // Mask of the host bits within the address width; none for a full prefix
+    let host_mask = u128::MAX.checked_shr(u32::from(128 - max + prefix_len)).unwrap_or(0);
+    !strict || bits & host_mask == 0
 }
 
 /// Validate an IP address (v4 or v6)
 /// validators.ip_address("192.168.1.1") -> True
 #[pyfunction]
 fn ip_address(value: &str) -> bool {
-    ipv4(value) || ipv6(value)
+    ipv4(value, false, true) || ipv6(value, false, false)
+}
+
+fn is_private_ipv4(addr: Ipv4Addr) -> bool {
+    addr.is_private() || addr.is_loopback() || addr.is_link_local() || addr.is_unspecified()
+}
+
+/// Whether an IP address is private rather than publicly routable
+/// validators.is_private_ip("10.0.0.1") -> True
+///
+/// Private means RFC 1918, loopback, link-local or unspecified for IPv4,
+/// and loopback, unspecified, unique-local (fc00::/7) or link-local
+/// (fe80::/10) for IPv6. IPv4-mapped IPv6 addresses are classified by
+/// their IPv4 part, and a zone ID is ignored. Raises ValueError if `value`
+/// isn't an IP address.
+#[pyfunction]
+fn is_private_ip(value: &str) -> PyResult<bool> {
+    if let Ok(addr) = value.parse::<Ipv4Addr>() {
+        return Ok(is_private_ipv4(addr));
+    }
+    let addr = parse_ipv6(value, true).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "'{}' does not appear to be an IPv4 or IPv6 address",
+            value
+        ))
+    })?;
+    if let Some(mapped) = addr.to_ipv4_mapped() {
+        return Ok(is_private_ipv4(mapped));
+    }
+    let first = addr.segments()[0];
+    Ok(addr.is_loopback()
+        || addr.is_unspecified()
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
 }
 
 /// Validate a slug
 /// validators.slug("my-slug-123") -> True
+///
+/// With `unicode`, any Unicode letter or digit is allowed alongside `-` and
+/// `_` ("café-münchen"). `min_len`/`max_len` bound the length in characters.
+/// With `strict`, separators may not lead, trail or repeat ("--bad--").
 #[pyfunction]
-fn slug(value: &str) -> bool {
+#[pyo3(signature = (value, unicode=false, min_len=None, max_len=None, strict=false))]
+fn slug(
+    value: &str,
+    unicode: bool,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    strict: bool,
+) -> bool {
     if value.is_empty() {
         return false;
     }
-    SLUG_REGEX.is_match(value)
+
+    let valid_chars = if unicode {
+        value.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    } else {
+        SLUG_REGEX.is_match(value)
+    };
+    if !valid_chars {
+        return false;
+    }
+
+    let len = value.chars().count();
+    if min_len.is_some_and(|min| len < min) || max_len.is_some_and(|max| len > max) {
+        return false;
+    }
+
+    let is_sep = |c: char| c == '-' || c == '_';
+    if strict {
+        let chars: Vec<char> = value.chars().collect();
+        let bad_edges = is_sep(chars[0]) || is_sep(chars[chars.len() - 1]);
+        let repeated = chars.windows(2).any(|pair| is_sep(pair[0]) && is_sep(pair[1]));
+        if bad_edges || repeated {
+            return false;
+        }
+    }
+
+    true
 }
 
 /// Validate a UUID
 /// validators.uuid("550e8400-e29b-41d4-a716-446655440000") -> True
+///
+/// Accepts RFC 9562 versions 1-8 and the nil and max UUIDs. With
+/// `version`, only UUIDs of exactly that version pass.
 #[pyfunction]
-fn uuid(value: &str) -> bool {
-    UUID_REGEX.is_match(value)
+#[pyo3(signature = (value, version=None))]
+fn uuid(value: &str, version: Option<u8>) -> PyResult<bool> {
+    match version {
+        Some(1..=8) | None => {}
+        Some(v) => {
+            return Err(PyValueError::new_err(format!(
+                "invalid UUID version {} (must be 1-8)",
+                v
+            )))
+        }
+    }
+    if !UUID_REGEX.is_match(value) {
+        let special = value == NIL_UUID || value.eq_ignore_ascii_case(MAX_UUID);
+        return Ok(special && version.is_none());
+    }
+    // The version is the first digit of the third group
+    Ok(match version {
+        Some(v) => value.as_bytes()[14] == b'0' + v,
+        None => true,
+    })
 }
 
 /// Validate an MD5 hash
@@ -184,39 +661,225 @@ fn mac_address(value: &str) -> bool {
 
 /// Validate a value is between min and max
 /// validators.between(5, min=1, max=10) -> True
+///
+/// On failure the `ValidationFailure` args name the violated `bound` and
+/// the `actual` value.
 #[pyfunction]
 #[pyo3(signature = (value, min=None, max=None))]
-fn between(value: f64, min: Option<f64>, max: Option<f64>) -> bool {
-    if let Some(min_val) = min {
-        if value < min_val {
-            return false;
-        }
-    }
-    if let Some(max_val) = max {
-        if value > max_val {
-            return false;
-        }
+fn between(
+    py: Python<'_>,
+    value: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> PyResult<PyObject> {
+    let violated = match (min, max) {
+        (Some(min_val), _) if value < min_val => Some(("min", min_val, "at least")),
+        (_, Some(max_val)) if value > max_val => Some(("max", max_val, "at most")),
+        _ => None,
+    };
+
+    match violated {
+        Some((bound, limit, relation)) => failure(
+            py,
+            "between",
+            &[
+                ("value", value.into_py(py)),
+                ("min", min.into_py(py)),
+                ("max", max.into_py(py)),
+                ("bound", bound.into_py(py)),
+                ("actual", value.into_py(py)),
+            ],
+            format!("must be {} {}, got {}", relation, limit, value),
+        ),
+        None => Ok(true.into_py(py)),
     }
-    true
 }
 
-/// Validate string length
+/// Validate string length (in characters)
 /// validators.length("hello", min=1, max=10) -> True
+///
+/// On failure the `ValidationFailure` args name the violated `bound` and
+/// the `actual` length.
 #[pyfunction]
 #[pyo3(signature = (value, min=None, max=None))]
-fn length(value: &str, min: Option<usize>, max: Option<usize>) -> bool {
-    let len = value.len();
-    if let Some(min_val) = min {
-        if len < min_val {
-            return false;
-        }
+fn length(
+    py: Python<'_>,
+    value: &str,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> PyResult<PyObject> {
+    let len = value.chars().count();
+    let violated = match (min, max) {
+        (Some(min_val), _) if len < min_val => Some(("min", min_val, "at least")),
+        (_, Some(max_val)) if len > max_val => Some(("max", max_val, "at most")),
+        _ => None,
+    };
+
+    match violated {
+        Some((bound, limit, relation)) => failure(
+            py,
+            "length",
+            &[
+                ("value", value.into_py(py)),
+                ("min", min.into_py(py)),
+                ("max", max.into_py(py)),
+                ("bound", bound.into_py(py)),
+                ("actual", len.into_py(py)),
+            ],
+            format!("must be {} {} characters, got {}", relation, limit, len),
+        ),
+        None => Ok(true.into_py(py)),
     }
-    if let Some(max_val) = max {
-        if len > max_val {
-            return false;
+}
+
+/// Validate a value is an integer multiple of base
+/// validators.multiple_of(0.3, base=0.1) -> True
+///
+/// Floats are compared with a relative tolerance, so rounding error in
+/// the quotient does not cause a spurious failure.
+#[pyfunction]
+fn multiple_of(py: Python<'_>, value: f64, base: f64) -> PyResult<PyObject> {
+    if base == 0.0 || !base.is_finite() {
+        return Err(PyValueError::new_err("base must be a finite, non-zero number"));
+    }
+
+    let quotient = value / base;
+    let tolerance = 1e-9 * quotient.abs().max(1.0);
+    if quotient.is_finite() && (quotient - quotient.round()).abs() <= tolerance {
+        return Ok(true.into_py(py));
+    }
+
+    failure(
+        py,
+        "multiple_of",
+        &[("value", value.into_py(py)), ("base", base.into_py(py))],
+        format!("must be a multiple of {}, got {}", base, value),
+    )
+}
+
+/// Validate a value is one of the given choices
+/// validators.one_of("red", ["red", "green"]) -> True
+///
+/// Membership uses Python's `in`, so any container works as `choices`.
+#[pyfunction]
+fn one_of(
+    py: Python<'_>,
+    value: &Bound<'_, PyAny>,
+    choices: &Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    if choices.contains(value)? {
+        return Ok(true.into_py(py));
+    }
+
+    failure(
+        py,
+        "one_of",
+        &[
+            ("value", value.clone().unbind()),
+            ("choices", choices.clone().unbind()),
+        ],
+        format!("must be one of {}, got {}", choices.repr()?, value.repr()?),
+    )
+}
+
+/// Compile `pattern` with Python `re` flags, reusing earlier compilations
+fn compile_pattern(pattern: &str, flags: u32) -> PyResult<Regex> {
+    let key = (pattern.to_string(), flags);
+    let mut cache = PATTERN_CACHE.lock().unwrap();
+    if let Some(compiled) = cache.get(&key) {
+        return Ok(compiled.clone());
+    }
+
+    let compiled = RegexBuilder::new(pattern)
+        .case_insensitive(flags & RE_IGNORECASE != 0)
+        .multi_line(flags & RE_MULTILINE != 0)
+        .dot_matches_new_line(flags & RE_DOTALL != 0)
+        .ignore_whitespace(flags & RE_VERBOSE != 0)
+        .build()
+        .map_err(|e| PyValueError::new_err(format!("invalid pattern {:?}: {}", pattern, e)))?;
+
+    if cache.len() >= PATTERN_CACHE_SIZE {
+        cache.clear();
+    }
+    cache.insert(key, compiled.clone());
+    Ok(compiled)
+}
+
+/// Validate a string matches a pattern
+/// validators.regex("abc-123", r"^[a-z]+-\d+$") -> True
+///
+/// Like `re.search`, the pattern may match anywhere unless anchored.
+/// `flags` accepts `re.IGNORECASE`, `re.MULTILINE`, `re.DOTALL` and
+/// `re.VERBOSE`. An invalid pattern raises `ValueError`.
+#[pyfunction]
+#[pyo3(name = "regex", signature = (value, pattern, flags=0))]
+fn matches_pattern(py: Python<'_>, value: &str, pattern: &str, flags: u32) -> PyResult<PyObject> {
+    if compile_pattern(pattern, flags)?.is_match(value) {
+        return Ok(true.into_py(py));
+    }
+    pattern_failure(py, value, pattern, flags)
+}
+
+/// The `ValidationFailure` for a value that doesn't match `pattern`
+fn pattern_failure(py: Python<'_>, value: &str, pattern: &str, flags: u32) -> PyResult<PyObject> {
+    failure(
+        py,
+        "regex",
+        &[
+            ("value", value.into_py(py)),
+            ("pattern", pattern.into_py(py)),
+            ("flags", flags.into_py(py)),
+        ],
+        format!("must match {:?}", pattern),
+    )
+}
+
+/// A reusable pattern validator returned by `compile`
+/// v = validators.compile(r"^[A-Z]{3}-\d{4}$"); v("ABC-1234") -> True
+///
+/// The pattern is compiled once, so calling the validator in a loop costs
+/// only the match. Results are the same as `validators.regex`.
+#[pyclass(frozen)]
+pub struct CompiledValidator {
+    #[pyo3(get)]
+    pattern: String,
+    #[pyo3(get)]
+    flags: u32,
+    regex: Regex,
+}
+
+#[pymethods]
+impl CompiledValidator {
+    /// True if `value` matches, else a `ValidationFailure`
+    fn validate(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+        if self.regex.is_match(value) {
+            return Ok(true.into_py(py));
         }
+        pattern_failure(py, value, &self.pattern, self.flags)
     }
-    true
+
+    fn __call__(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+        self.validate(py, value)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CompiledValidator(pattern={:?}, flags={})", self.pattern, self.flags)
+    }
+}
+
+/// Compile a pattern into a reusable validator
+/// validators.compile(r"^\d+$")("123") -> True
+///
+/// Takes the same `flags` as `validators.regex`; an invalid pattern raises
+/// `ValueError` here rather than on first use.
+#[pyfunction]
+#[pyo3(name = "compile", signature = (pattern, flags=0))]
+fn compile_validator(pattern: &str, flags: u32) -> PyResult<CompiledValidator> {
+    Ok(CompiledValidator {
+        pattern: pattern.to_string(),
+        flags,
+        regex: compile_pattern(pattern, flags)?,
+    })
 }
 
 /// Validate a credit card number using Luhn algorithm
@@ -288,12 +951,15 @@ fn iban(value: &str) -> bool {
 /// A Python module implemented in Rust
 #[pymodule]
 fn validators_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<ValidationFailure>()?;
     m.add_function(wrap_pyfunction!(email, m)?)?;
     m.add_function(wrap_pyfunction!(url, m)?)?;
     m.add_function(wrap_pyfunction!(domain, m)?)?;
     m.add_function(wrap_pyfunction!(ipv4, m)?)?;
     m.add_function(wrap_pyfunction!(ipv6, m)?)?;
     m.add_function(wrap_pyfunction!(ip_address, m)?)?;
+    m.add_function(wrap_pyfunction!(ip_network, m)?)?;
+    m.add_function(wrap_pyfunction!(is_private_ip, m)?)?;
     m.add_function(wrap_pyfunction!(slug, m)?)?;
     m.add_function(wrap_pyfunction!(uuid, m)?)?;
     m.add_function(wrap_pyfunction!(md5, m)?)?;
@@ -303,6 +969,11 @@ fn validators_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(mac_address, m)?)?;
     m.add_function(wrap_pyfunction!(between, m)?)?;
     m.add_function(wrap_pyfunction!(length, m)?)?;
+    m.add_function(wrap_pyfunction!(multiple_of, m)?)?;
+    m.add_function(wrap_pyfunction!(one_of, m)?)?;
+    m.add_function(wrap_pyfunction!(matches_pattern, m)?)?;
+    m.add_class::<CompiledValidator>()?;
+    m.add_function(wrap_pyfunction!(compile_validator, m)?)?;
     m.add_function(wrap_pyfunction!(card_number, m)?)?;
     m.add_function(wrap_pyfunction!(iban, m)?)?;
     Ok(())
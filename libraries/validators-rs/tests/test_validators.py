@@ -0,0 +1,440 @@
+"""
+Tests for validators-rs
+
+Verifies API compatibility with the validators package
+"""
+
+import re
+
+import pytest
+
+try:
+    import validators_rs as validators
+except ImportError:
+    pytest.skip("validators_rs not available", allow_module_level=True)
+
+
+class TestDomain:
+    """TLD rules for domain and email"""
+
+    def test_alphabetic_tld(self):
+        assert validators.domain("example.com")
+
+    def test_punycode_tld(self):
+        assert validators.domain("example.xn--p1ai")
+        assert validators.domain("xn--e1afmkfd.xn--p1ai")
+        assert not validators.domain("example.xn--")
+
+    def test_numeric_tld_rejected(self):
+        assert not validators.domain("example.123")
+        assert not validators.domain("192.168.0.1")
+
+    def test_email(self):
+        assert validators.email("user@example.xn--p1ai", check_deliverability=True)
+        assert validators.email("user@example.com", check_deliverability=True)
+        assert not validators.email("user@example.123", check_deliverability=True)
+
+    def test_email_default_rejects_numeric_tld(self):
+        assert not validators.email("user@example.123")
+        assert not validators.email("user@123")
+        assert validators.email("user@example.xn--p1ai")
+        assert validators.email("user@localhost")
+        assert not validators.email("user@example.123", normalize=True)
+
+
+class TestEmailDeliverability:
+    """Structural checks enabled by check_deliverability"""
+
+    def test_consecutive_dots_in_domain(self):
+        assert not validators.email("a@b..c")
+        assert not validators.email("a@b..c", check_deliverability=True)
+        assert not validators.email("user@example..com", check_deliverability=True)
+
+    def test_local_part_dots(self):
+        for value in (".user@example.com", "user.@example.com", "us..er@example.com"):
+            assert validators.email(value)
+            assert not validators.email(value, check_deliverability=True)
+        assert validators.email("first.last@example.com", check_deliverability=True)
+
+    def test_local_part_length(self):
+        assert validators.email("a" * 64 + "@example.com", check_deliverability=True)
+        assert validators.email("a" * 65 + "@example.com")
+        assert not validators.email("a" * 65 + "@example.com", check_deliverability=True)
+
+    def test_address_length(self):
+        # 254 characters is the RFC 5321 limit for a whole address
+        domain = ".".join(["a" * 63, "b" * 63, "c" * 63, "d" * 56]) + ".com"
+        assert len("u@" + domain) == 254
+        assert validators.email("u@" + domain, check_deliverability=True)
+        assert not validators.email("uu@" + domain)
+        assert not validators.email("uu@" + domain, check_deliverability=True)
+
+    def test_domain_label_length(self):
+        assert validators.email("u@" + "a" * 63 + ".com", check_deliverability=True)
+        assert not validators.email("u@" + "a" * 64 + ".com", check_deliverability=True)
+
+    def test_domain_needs_tld(self):
+        assert validators.email("user@localhost")
+        assert not validators.email("user@localhost", check_deliverability=True)
+
+    def test_normalize(self):
+        assert validators.email(" A.B@Example.COM ", check_deliverability=True, normalize=True) == "A.B@example.com"
+        assert not validators.email("a..b@example.com", check_deliverability=True, normalize=True)
+
+
+class TestURL:
+    """Opt-in scheme whitelist and encoding checks"""
+
+    def test_default_unchanged(self):
+        assert validators.url("https://example.com/a%2Gb")
+        assert not validators.url("mailto:user@example.com")
+
+    def test_check_encoding(self):
+        assert validators.url("https://example.com/a%20b?q=1#top", check_encoding=True)
+        assert not validators.url("https://example.com/a%2Gb", check_encoding=True)
+        assert not validators.url("https://example.com/café", check_encoding=True)
+        assert not validators.url("https://example.com/#a#b", check_encoding=True)
+
+    def test_schemes(self):
+        assert validators.url("ssh://host.example.com", schemes=["ssh"])
+        assert validators.url("HTTPS://example.com", schemes=["https"])
+        assert not validators.url("ftp://example.com", schemes=["https"])
+
+    def test_mailto(self):
+        schemes = ["mailto"]
+        assert validators.url("mailto:user@example.com", schemes=schemes)
+        assert validators.url("mailto:a@example.com,b@example.org?subject=hi", schemes=schemes)
+        assert not validators.url("mailto:not-an-address", schemes=schemes)
+
+    def test_data(self):
+        schemes = ["data"]
+        assert validators.url("data:text/plain;base64,SGVsbG8=", schemes=schemes)
+        assert validators.url("data:text/plain;charset=utf-8,hi", schemes=schemes)
+        assert validators.url("data:,Hello%2C%20World", schemes=schemes, check_encoding=True)
+        assert not validators.url("data:text/plain;base64,SGVsbG8", schemes=schemes)
+        assert not validators.url("data:bad type,x", schemes=schemes)
+        assert not validators.url("data:text/plain", schemes=schemes)
+
+
+class TestIPv4:
+    """ipv4 with cidr and strict options"""
+
+    def test_valid(self):
+        assert validators.ipv4("192.168.1.1")
+        assert validators.ipv4("0.0.0.0")
+        assert validators.ipv4("255.255.255.255")
+
+    def test_invalid(self):
+        assert not validators.ipv4("256.1.1.1")
+        assert not validators.ipv4("1.2.3")
+        assert not validators.ipv4("1.2.3.4.5")
+
+    def test_leading_zeros_rejected_by_default(self):
+        assert not validators.ipv4("010.0.0.1")
+        assert not validators.ipv4("192.168.01.1")
+
+    def test_leading_zeros_allowed_when_not_strict(self):
+        assert validators.ipv4("010.0.0.1", strict=False)
+        assert not validators.ipv4("0100.0.0.1", strict=False)
+
+    def test_cidr(self):
+        assert not validators.ipv4("10.0.0.0/8")
+        assert validators.ipv4("10.0.0.0/8", cidr=True)
+        assert validators.ipv4("10.0.0.0/0", cidr=True)
+        assert not validators.ipv4("10.0.0.0/33", cidr=True)
+        assert not validators.ipv4("10.0.0.0/08", cidr=True)
+        assert not validators.ipv4("10.0.0.0/", cidr=True)
+
+
+class TestIPv6:
+    """ipv6 with mapped addresses, zone IDs and cidr"""
+
+    def test_valid(self):
+        assert validators.ipv6("::1")
+        assert validators.ipv6("2001:db8::8a2e:370:7334")
+
+    def test_invalid(self):
+        assert not validators.ipv6("2001:db8::g")
+        assert not validators.ipv6("1:2:3:4:5:6:7:8:9")
+
+    def test_ipv4_mapped(self):
+        assert validators.ipv6("::ffff:192.168.0.1")
+        assert not validators.ipv6("::ffff:192.168.00.1")
+
+    def test_zone_id(self):
+        assert validators.ipv6("fe80::1%eth0", allow_zone=True)
+        assert validators.ipv6("fe80::1%3", allow_zone=True)
+        assert not validators.ipv6("fe80::1%", allow_zone=True)
+
+    def test_zone_id_disallowed(self):
+        assert not validators.ipv6("fe80::1%eth0")
+        assert not validators.ipv6("fe80::1%eth0", allow_zone=False)
+        assert not validators.ip_address("fe80::1%eth0")
+        assert validators.ipv6("fe80::1", allow_zone=False)
+        assert not validators.ipv6("fe80::g%eth0", allow_zone=True)
+
+    def test_cidr(self):
+        assert not validators.ipv6("2001:db8::/32")
+        assert validators.ipv6("2001:db8::/32", cidr=True)
+        assert not validators.ipv6("2001:db8::/129", cidr=True)
+
+
+class TestIPNetwork:
+    """ip_network for IPv4 and IPv6 CIDR networks"""
+
+    def test_valid(self):
+        assert validators.ip_network("192.168.0.0/24")
+        assert validators.ip_network("2001:db8:abcd:12::/64")
+        assert validators.ip_network("0.0.0.0/0")
+        assert validators.ip_network("::/0")
+        assert validators.ip_network("2001:db8::1/128")
+        assert validators.ip_network("10.1.2.3")
+
+    def test_host_bits(self):
+        assert not validators.ip_network("192.168.0.1/24")
+        assert not validators.ip_network("2001:db8::1/64")
+        assert validators.ip_network("192.168.0.1/24", strict=False)
+        assert validators.ip_network("2001:db8::1/64", strict=False)
+        assert not validators.ip_network("0.0.0.1/0")
+        assert not validators.ip_network("::1/0")
+        assert validators.ip_network("::1/0", strict=False)
+
+    def test_invalid(self):
+        assert not validators.ip_network("192.168.0.0/33")
+        assert not validators.ip_network("2001:db8::/129")
+        assert not validators.ip_network("192.168.0.0/")
+        assert not validators.ip_network("192.168.0.0/024")
+        assert not validators.ip_network("fe80::%eth0/64")
+        assert not validators.ip_network("not a network")
+
+
+class TestIsPrivateIP:
+    """is_private_ip classification"""
+
+    @pytest.mark.parametrize("value", [
+        "10.0.0.1", "172.16.5.4", "192.168.1.1", "127.0.0.1", "169.254.0.1",
+        "::1", "fd12:3456::1", "fe80::1%eth0", "::ffff:10.0.0.1",
+    ])
+    def test_private(self, value):
+        assert validators.is_private_ip(value) is True
+
+    @pytest.mark.parametrize("value", ["8.8.8.8", "2001:4860:4860::8888", "::ffff:8.8.8.8"])
+    def test_public(self, value):
+        assert validators.is_private_ip(value) is False
+
+    def test_invalid(self):
+        with pytest.raises(ValueError):
+            validators.is_private_ip("not an ip")
+
+
+class TestSlug:
+    """slug with unicode, length bounds and strict separators"""
+
+    def test_ascii(self):
+        assert validators.slug("my-slug_123")
+        assert not validators.slug("my slug")
+        assert not validators.slug("")
+
+    def test_unicode(self):
+        assert not validators.slug("café-münchen")
+        assert validators.slug("café-münchen", unicode=True)
+        assert validators.slug("東京-2024", unicode=True)
+        assert not validators.slug("café münchen", unicode=True)
+
+    def test_length_bounds(self):
+        assert validators.slug("abc", min_len=3, max_len=5)
+        assert not validators.slug("ab", min_len=3)
+        assert not validators.slug("abcdef", max_len=5)
+        assert validators.slug("münch", unicode=True, max_len=5)
+
+    def test_strict(self):
+        assert validators.slug("--bad--")
+        assert not validators.slug("--bad--", strict=True)
+        assert not validators.slug("bad_", strict=True)
+        assert not validators.slug("very--bad", strict=True)
+        assert validators.slug("good-slug_1", strict=True)
+
+
+class TestMultipleOf:
+    """Integer multiples with float tolerance"""
+
+    def test_integers(self):
+        assert validators.multiple_of(10, 5)
+        assert not validators.multiple_of(7, 2)
+
+    def test_float_tolerance(self):
+        assert validators.multiple_of(0.3, 0.1)
+        assert validators.multiple_of(1.1 * 3, 1.1)
+        assert validators.multiple_of(-0.6, 0.2)
+        assert not validators.multiple_of(0.35, 0.1)
+
+    def test_failure_args(self):
+        result = validators.multiple_of(7, 2)
+        assert result.args == {"value": 7.0, "base": 2.0}
+
+    def test_zero_base(self):
+        with pytest.raises(ValueError):
+            validators.multiple_of(1, 0)
+
+
+class TestLength:
+    """length counts characters and reports the violated bound"""
+
+    def test_bounds(self):
+        assert validators.length("hello", min=1, max=10)
+        assert validators.length("hello", min=5, max=5)
+        assert not validators.length("hi", min=3)
+        assert not validators.length("hello", max=4)
+
+    def test_counts_characters_not_bytes(self):
+        assert validators.length("münchen", max=7)
+        assert validators.length("日本語", min=3, max=3)
+        assert not validators.length("日本語", min=4)
+
+    def test_min_failure_args(self):
+        result = validators.length("pass", min=8, max=20)
+        assert result.func == "length"
+        assert result.args == {"value": "pass", "min": 8, "max": 20, "bound": "min", "actual": 4}
+        assert result.reason == "must be at least 8 characters, got 4"
+
+    def test_max_failure_args(self):
+        result = validators.length("日本語テキスト", max=5)
+        assert result.args["bound"] == "max"
+        assert result.args["actual"] == 7
+        assert result.args["min"] is None
+        assert result.reason == "must be at most 5 characters, got 7"
+
+
+class TestBetween:
+    """between reports the violated bound and the actual value"""
+
+    def test_bounds(self):
+        assert validators.between(5, min=1, max=10)
+        assert validators.between(1, min=1, max=10)
+        assert validators.between(10, min=1, max=10)
+        assert validators.between(-100, max=0)
+
+    def test_min_failure_args(self):
+        result = validators.between(0, min=1, max=10)
+        assert not result
+        assert result.func == "between"
+        assert result.args == {"value": 0.0, "min": 1.0, "max": 10.0, "bound": "min", "actual": 0.0}
+        assert result.reason == "must be at least 1, got 0"
+
+    def test_max_failure_args(self):
+        result = validators.between(10.5, max=10)
+        assert result.args["bound"] == "max"
+        assert result.args["actual"] == 10.5
+        assert result.args["min"] is None
+        assert result.reason == "must be at most 10, got 10.5"
+
+
+class TestOneOf:
+    """Membership in a list of choices"""
+
+    def test_member(self):
+        assert validators.one_of("red", ["red", "green"])
+        assert not validators.one_of("blue", ["red", "green"])
+
+    def test_failure_args(self):
+        result = validators.one_of(3, (1, 2))
+        assert result.func == "one_of"
+        assert result.args["choices"] == (1, 2)
+
+
+class TestRegex:
+    """User-supplied patterns"""
+
+    def test_match(self):
+        assert validators.regex("abc-123", r"^[a-z]+-\d+$")
+        assert not validators.regex("abc", r"^\d+$")
+
+    def test_flags(self):
+        assert not validators.regex("ABC", "^abc$")
+        assert validators.regex("ABC", "^abc$", re.IGNORECASE)
+        assert validators.regex("a\nb", "^b$", re.MULTILINE)
+
+    def test_invalid_pattern(self):
+        with pytest.raises(ValueError, match="invalid pattern"):
+            validators.regex("x", "(")
+
+
+class TestCompile:
+    """Reusable validators compiled from a pattern"""
+
+    def test_call_and_validate(self):
+        check = validators.compile(r"^[A-Z]{3}-\d{4}$")
+        assert check("ABC-1234") is True
+        assert check.validate("ABC-1234") is True
+        assert not check("abc-1234")
+        assert not check.validate("ABC-12")
+
+    def test_failure_matches_regex(self):
+        check = validators.compile(r"^\d+$", re.IGNORECASE)
+        result = check("x")
+        assert isinstance(result, validators.ValidationFailure)
+        assert result.func == "regex"
+        assert result.args == {"value": "x", "pattern": r"^\d+$", "flags": re.IGNORECASE}
+        assert str(result) == str(validators.regex("x", r"^\d+$", re.IGNORECASE))
+
+    def test_attributes(self):
+        check = validators.compile("^abc$", re.IGNORECASE)
+        assert (check.pattern, check.flags) == ("^abc$", re.IGNORECASE)
+        assert check("ABC")
+
+    def test_invalid_pattern(self):
+        with pytest.raises(ValueError, match="invalid pattern"):
+            validators.compile("(")
+
+
+class TestUUID:
+    """Versions 1-8, nil/max and the version option"""
+
+    V4 = "550e8400-e29b-41d4-a716-446655440000"
+    V7 = "018f6d2e-8c3a-7b4e-9f1a-2c3d4e5f6a7b"
+
+    def test_versions(self):
+        assert validators.uuid(self.V4)
+        assert validators.uuid(self.V7)
+        assert validators.uuid("1ec9414c-232a-6b00-b3c8-9e6bdeced846")  # v6
+        assert not validators.uuid("550e8400-e29b-91d4-a716-446655440000")
+
+    def test_nil_and_max(self):
+        assert validators.uuid("00000000-0000-0000-0000-000000000000")
+        assert validators.uuid("FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF")
+        assert not validators.uuid("00000000-0000-0000-0000-000000000000", version=4)
+
+    def test_version_option(self):
+        assert validators.uuid(self.V4, version=4)
+        assert validators.uuid(self.V7, version=7)
+        assert not validators.uuid(self.V7, version=4)
+
+    def test_invalid_version(self):
+        with pytest.raises(ValueError):
+            validators.uuid(self.V4, version=9)
+
+
+class TestNormalize:
+    """normalize=True returns the cleaned value or a ValidationFailure"""
+
+    def test_email(self):
+        assert validators.email("  John.Doe@Example.COM ", normalize=True) == "John.Doe@example.com"
+        assert validators.email("user@example.com") is True
+
+    def test_email_failure(self):
+        result = validators.email("not an email", normalize=True)
+        assert not result
+        assert result.func == "email"
+
+    def test_url_default_scheme(self):
+        assert validators.url("Example.COM/Path?Q=1", normalize=True) == "https://example.com/Path?Q=1"
+        assert validators.url("example.com", normalize=True, default_scheme="ftp") == "ftp://example.com"
+
+    def test_url_lowercases_scheme_and_host(self):
+        assert validators.url(" HTTP://User@Example.com:8080/A ", normalize=True) == "http://User@example.com:8080/A"
+        assert validators.url("MAILTO:a@example.com", schemes=["mailto"], normalize=True) == "mailto:a@example.com"
+
+    def test_url_failure(self):
+        result = validators.url("not a url", normalize=True)
+        assert not result
+        assert result.func == "url"